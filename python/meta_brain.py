@@ -10,6 +10,7 @@
 #  *
 #  * @notes
 #  * - Writes updates to config/strategies.json atomically with version bumps.
+#  * - The terminal's in-process `meta_brain` task ports this loop; run only one of them.
 #  */
 from __future__ import annotations
 
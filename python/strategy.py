@@ -1,21 +1,27 @@
 #!/usr/bin/env python3
 # /**
 #  * @description
-#  * Core strategy math helpers for Kelly sizing, signal validation, and staleness.
+#  * Core strategy math helpers for Kelly sizing, true probability, signal validation, and staleness.
 #  *
 #  * @dependencies
 #  * - json: inference payload parsing
 #  *
 #  * @notes
 #  * - Functions are pure and deterministic for easy unit testing.
+#  * - `true_probability` and `calculate_kelly` are called live by the Rust PythonHost backend,
+#  *   which passes `execution.probability_scale` and `execution.probability_max_offset`.
 #  */
 from __future__ import annotations
 
 import json
+import math
 from dataclasses import dataclass
 
 
 MIN_ALIGNMENT_PCT = 0.001
+SQRT_5 = math.sqrt(5.0)
+DEFAULT_PROBABILITY_SCALE = 1.0
+DEFAULT_PROBABILITY_MAX_OFFSET = 0.35
 
 
 @dataclass(frozen=True)
@@ -70,6 +76,34 @@ def calculate_kelly(win_prob: float, odds: float) -> float:
     return max(0.0, min(kelly, 1.0))
 
 
+def true_probability(
+    implied_up: float,
+    start_price: float,
+    current_price: float,
+    inference: float,
+    volatility_1m: float,
+    alignment: float,
+    probability_scale: float = DEFAULT_PROBABILITY_SCALE,
+    probability_max_offset: float = DEFAULT_PROBABILITY_MAX_OFFSET,
+) -> float:
+    """
+    Fair probability that the window closes above its start price.
+
+    Mirrors the Rust v1 model: tanh of the 5m z-score of the inference vs. the
+    start price, scaled by alignment around 0.5. `implied_up` and `current_price`
+    are passed so alternative models can use them; the scale and max offset come
+    from the execution config.
+    """
+    del implied_up, current_price
+    if start_price <= 0.0:
+        return 0.5
+    delta = (inference - start_price) / start_price
+    volatility_5m = max(volatility_1m * SQRT_5, 1e-9)
+    z_score = delta / volatility_5m
+    offset = math.tanh(z_score * probability_scale) * probability_max_offset * alignment
+    return max(0.01, min(0.5 + offset, 0.99))
+
+
 def validate_signal(inference_json: str, current_price: float) -> bool:
     """
     Validate that Allora inference aligns with price direction.
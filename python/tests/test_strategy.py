@@ -1,6 +1,6 @@
 # /**
 #  * @description
-#  * Unit tests for strategy helpers (Kelly sizing, signal validation, staleness).
+#  * Unit tests for strategy helpers (Kelly sizing, true probability, signal validation, staleness).
 #  *
 #  * @dependencies
 #  * - pytest: test runner
@@ -20,7 +20,7 @@ import pytest
 STRATEGY_DIR = pathlib.Path(__file__).resolve().parents[1]
 sys.path.insert(0, str(STRATEGY_DIR))
 
-from strategy import calculate_kelly, calculate_staleness, validate_signal
+from strategy import calculate_kelly, calculate_staleness, true_probability, validate_signal
 
 
 def test_calculate_kelly_even_odds() -> None:
@@ -39,6 +39,18 @@ def test_calculate_kelly_fractional_position() -> None:
     assert kelly == pytest.approx(0.05)
 
 
+def test_true_probability_follows_inference_direction() -> None:
+    up = true_probability(0.5, 100.0, 100.0, 100.2, 0.001, 1.0)
+    down = true_probability(0.5, 100.0, 100.0, 99.8, 0.001, 1.0)
+    assert up > 0.5 > down
+    assert true_probability(0.5, 100.0, 100.0, 100.2, 0.001, 0.0) == pytest.approx(0.5)
+
+
+def test_true_probability_uses_configured_offset() -> None:
+    capped = true_probability(0.5, 100.0, 100.0, 110.0, 0.001, 1.0, 1.0, 0.2)
+    assert capped == pytest.approx(0.7)
+
+
 def test_validate_signal_threshold() -> None:
     payload = {"combined_value": 101.0}
     assert validate_signal(json.dumps(payload), 100.0) is True
@@ -18,6 +18,7 @@ use crate::error::{BankaiError, Result};
 use crate::execution::signer::Eip712Signer;
 use crate::security::Secrets;
 use crate::storage::redis::RedisManager;
+use crate::telemetry::metrics;
 
 const DEFAULT_CHAIN_ID: u64 = 137;
 const DEFAULT_COLLATERAL_DECIMALS: u32 = 6;
@@ -98,6 +99,7 @@ impl BankrollRefresher {
         let balance = self.fetch_collateral_balance().await?;
         let scaled = scale_u256(balance, self.collateral_decimals)?;
         self.redis.set_float(BANKROLL_REDIS_KEY, scaled).await?;
+        metrics::record_bankroll_usdc("live", scaled);
         Ok(())
     }
 
@@ -0,0 +1,428 @@
+/**
+ * @purpose
+ * In-process meta-brain: tunes strategy parameters from realized trade performance.
+ *
+ * @dependencies
+ * - storage::database: `trade_logs` samples (optional)
+ * - accounting::no_money: settled paper trade samples
+ * - config::ConfigManager: strategy override writes
+ *
+ * @notes
+ * - Port of python/meta_brain.py with per-cycle step limits from `meta_brain` config.
+ * - Every applied change is stored under `meta_brain:change:{version}` with the metrics that
+ *   drove it; the metrics of the first full lookback window after it are filled in later.
+ * - No new change is proposed until the previous one has its after-metrics.
+ */
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::task::JoinHandle;
+
+use crate::accounting::no_money::paper_results_since;
+use crate::config::{ConfigManager, MetaBrainConfig, StrategyConfig};
+use crate::error::Result;
+use crate::storage::database::DatabaseManager;
+use crate::storage::redis::RedisManager;
+
+const CHANGES_ZSET_KEY: &str = "meta_brain:changes";
+const CHANGE_KEY_PREFIX: &str = "meta_brain:change:";
+const ACTIVITY_LOG_LIMIT: usize = 200;
+const SNIPE_MODE: &str = "SNIPE";
+
+#[derive(Debug, Clone)]
+pub struct TradeSample {
+    pub mode: String,
+    pub expected_ev: f64,
+    pub net_pnl: f64,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PerformanceStats {
+    pub count: usize,
+    pub expected_total: f64,
+    pub net_total: f64,
+    pub regret_total: f64,
+}
+
+impl PerformanceStats {
+    pub fn add(&mut self, expected_ev: f64, net_pnl: f64) {
+        self.count += 1;
+        self.expected_total += expected_ev;
+        self.net_total += net_pnl;
+        self.regret_total += expected_ev - net_pnl;
+    }
+
+    pub fn avg_expected(&self) -> f64 {
+        self.average(self.expected_total)
+    }
+
+    pub fn avg_net(&self) -> f64 {
+        self.average(self.net_total)
+    }
+
+    pub fn avg_regret(&self) -> f64 {
+        self.average(self.regret_total)
+    }
+
+    /// Average regret relative to the magnitude of average expected EV.
+    pub fn regret_ratio(&self) -> f64 {
+        self.avg_regret() / self.avg_expected().abs().max(1e-6)
+    }
+
+    fn average(&self, total: f64) -> f64 {
+        if self.count == 0 {
+            0.0
+        } else {
+            total / self.count as f64
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SampleStats {
+    pub by_mode: HashMap<String, PerformanceStats>,
+    pub overall: PerformanceStats,
+}
+
+impl SampleStats {
+    pub fn from_samples(samples: &[TradeSample]) -> Self {
+        let mut stats = Self::default();
+        for sample in samples {
+            stats
+                .by_mode
+                .entry(sample.mode.trim().to_ascii_uppercase())
+                .or_default()
+                .add(sample.expected_ev, sample.net_pnl);
+            stats.overall.add(sample.expected_ev, sample.net_pnl);
+        }
+        stats
+    }
+
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        let snipe = self.by_mode.get(SNIPE_MODE).copied().unwrap_or_default();
+        MetricsSnapshot {
+            count: self.overall.count,
+            avg_expected_ev: self.overall.avg_expected(),
+            avg_net_pnl: self.overall.avg_net(),
+            regret_ratio: self.overall.regret_ratio(),
+            snipe_count: snipe.count,
+            snipe_regret_ratio: snipe.regret_ratio(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct MetricsSnapshot {
+    pub count: usize,
+    pub avg_expected_ev: f64,
+    pub avg_net_pnl: f64,
+    pub regret_ratio: f64,
+    pub snipe_count: usize,
+    pub snipe_regret_ratio: f64,
+}
+
+/// Audit record for one applied strategy change.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategyChange {
+    pub version: u64,
+    pub changed_at_ms: u64,
+    pub before: StrategyConfig,
+    pub after: StrategyConfig,
+    pub metrics_before: MetricsSnapshot,
+    #[serde(default)]
+    pub metrics_after: Option<MetricsSnapshot>,
+    #[serde(default)]
+    pub metrics_after_at_ms: Option<u64>,
+}
+
+/// Proposes the next strategy from sample stats. Raw adjustments follow the Python
+/// meta-brain; each is then limited to the configured step and absolute bounds.
+pub fn propose_strategy(
+    current: &StrategyConfig,
+    stats: &SampleStats,
+    config: &MetaBrainConfig,
+) -> StrategyConfig {
+    let mut snipe_delta: f64 = 0.0;
+    let mut kelly_delta: f64 = 0.0;
+
+    if let Some(snipe) = stats.by_mode.get(SNIPE_MODE) {
+        if snipe.count >= config.min_samples {
+            let ratio = snipe.regret_ratio();
+            if ratio > 0.1 {
+                snipe_delta = (10.0 + ratio * 20.0).min(50.0);
+            } else if ratio < -0.1 {
+                snipe_delta = -(5.0 + ratio.abs() * 10.0).min(25.0);
+            }
+        }
+    }
+
+    let overall = &stats.overall;
+    if overall.count >= config.min_samples {
+        let ratio = overall.regret_ratio();
+        if overall.avg_net() < 0.0 || ratio > 0.1 {
+            kelly_delta = if ratio <= 0.25 { -0.02 } else { -0.05 };
+        } else if overall.avg_net() > 0.0 && ratio < -0.1 {
+            kelly_delta = 0.02;
+        }
+    }
+
+    let max_kelly_step = config.max_kelly_step.abs();
+    let max_snipe_step = config.max_snipe_step_bps.abs();
+    StrategyConfig {
+        kelly_fraction: (current.kelly_fraction
+            + kelly_delta.clamp(-max_kelly_step, max_kelly_step))
+        .clamp(config.min_kelly_fraction, config.max_kelly_fraction),
+        snipe_min_edge_bps: (current.snipe_min_edge_bps
+            + snipe_delta.clamp(-max_snipe_step, max_snipe_step))
+        .clamp(config.min_snipe_edge_bps, config.max_snipe_edge_bps),
+        spread_offset_bps: current.spread_offset_bps,
+    }
+}
+
+pub struct MetaBrain {
+    config_manager: ConfigManager,
+    redis: RedisManager,
+    database: Option<DatabaseManager>,
+}
+
+impl MetaBrain {
+    pub fn new(
+        config_manager: ConfigManager,
+        redis: RedisManager,
+        database: Option<DatabaseManager>,
+    ) -> Self {
+        Self {
+            config_manager,
+            redis,
+            database,
+        }
+    }
+
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            if let Err(error) = self.run().await {
+                tracing::error!(?error, "meta-brain stopped");
+            }
+        })
+    }
+
+    async fn run(self) -> Result<()> {
+        loop {
+            let interval_secs = self.config_manager.current().meta_brain.interval_secs;
+            if let Err(error) = self.run_once().await {
+                tracing::warn!(?error, "meta-brain cycle failed");
+            }
+            tokio::time::sleep(Duration::from_secs(interval_secs.max(60))).await;
+        }
+    }
+
+    pub async fn run_once(&self) -> Result<()> {
+        let config = self.config_manager.current();
+        let settings = &config.meta_brain;
+        if !settings.enabled {
+            return Ok(());
+        }
+        let now = now_ms()?;
+        let lookback_ms = settings.lookback_hours.max(1) * 60 * 60 * 1000;
+        let since_ms = now.saturating_sub(lookback_ms);
+        let samples = self.load_samples(since_ms, settings.include_paper).await?;
+        let stats = SampleStats::from_samples(&samples);
+
+        if let Some(mut last) = self.latest_change().await? {
+            if last.metrics_after.is_none() {
+                if last.changed_at_ms > since_ms {
+                    tracing::info!(
+                        version = last.version,
+                        "meta-brain waiting for a full window after the last change"
+                    );
+                    return Ok(());
+                }
+                last.metrics_after = Some(stats.snapshot());
+                last.metrics_after_at_ms = Some(now);
+                self.store_change(&last).await?;
+                self.log_activity(&format!(
+                    "[META] v{} after: n={} avg_net={:.4} regret_ratio={:.3}",
+                    last.version,
+                    stats.overall.count,
+                    stats.overall.avg_net(),
+                    stats.overall.regret_ratio()
+                ))
+                .await;
+            }
+        }
+
+        if stats.overall.count < settings.min_samples {
+            tracing::info!(
+                count = stats.overall.count,
+                min_samples = settings.min_samples,
+                "meta-brain has insufficient samples"
+            );
+            return Ok(());
+        }
+
+        let before = config.strategy.clone();
+        let after = propose_strategy(&before, &stats, settings);
+        if after.kelly_fraction == before.kelly_fraction
+            && after.snipe_min_edge_bps == before.snipe_min_edge_bps
+        {
+            tracing::info!(
+                count = stats.overall.count,
+                "meta-brain: no strategy change"
+            );
+            return Ok(());
+        }
+
+        let version = self.config_manager.write_strategy_override(&after)?;
+        let change = StrategyChange {
+            version,
+            changed_at_ms: now,
+            before: before.clone(),
+            after: after.clone(),
+            metrics_before: stats.snapshot(),
+            metrics_after: None,
+            metrics_after_at_ms: None,
+        };
+        self.store_change(&change).await?;
+        tracing::info!(
+            version,
+            kelly_before = before.kelly_fraction,
+            kelly_after = after.kelly_fraction,
+            snipe_before = before.snipe_min_edge_bps,
+            snipe_after = after.snipe_min_edge_bps,
+            samples = stats.overall.count,
+            "meta-brain updated strategy"
+        );
+        self.log_activity(&format!(
+            "[META] v{} kelly {:.3}->{:.3} snipe_bps {:.1}->{:.1} n={} regret_ratio={:.3}",
+            version,
+            before.kelly_fraction,
+            after.kelly_fraction,
+            before.snipe_min_edge_bps,
+            after.snipe_min_edge_bps,
+            stats.overall.count,
+            stats.overall.regret_ratio()
+        ))
+        .await;
+        Ok(())
+    }
+
+    async fn load_samples(&self, since_ms: u64, include_paper: bool) -> Result<Vec<TradeSample>> {
+        let mut samples = Vec::new();
+        if let Some(database) = self.database.as_ref() {
+            samples.extend(fetch_trade_log_samples(database, since_ms).await?);
+        }
+        if include_paper {
+            samples.extend(
+                paper_results_since(&self.redis, since_ms)
+                    .await?
+                    .into_iter()
+                    .map(|result| TradeSample {
+                        mode: result.mode,
+                        expected_ev: result.expected_ev,
+                        net_pnl: result.net_pnl,
+                    }),
+            );
+        }
+        Ok(samples)
+    }
+
+    async fn latest_change(&self) -> Result<Option<StrategyChange>> {
+        let latest = self
+            .redis
+            .zrevrange_with_scores(CHANGES_ZSET_KEY, 0, 0)
+            .await?;
+        let Some((key, _)) = latest.into_iter().next() else {
+            return Ok(None);
+        };
+        let Some(raw) = self.redis.get_string(&key).await? else {
+            return Ok(None);
+        };
+        Ok(Some(serde_json::from_str(&raw)?))
+    }
+
+    async fn store_change(&self, change: &StrategyChange) -> Result<()> {
+        let key = format!("{CHANGE_KEY_PREFIX}{}", change.version);
+        self.redis
+            .set_string(&key, &serde_json::to_string(change)?)
+            .await?;
+        self.redis
+            .zadd(CHANGES_ZSET_KEY, change.changed_at_ms as f64, &key)
+            .await
+    }
+
+    async fn log_activity(&self, message: &str) {
+        let _ = self
+            .redis
+            .push_activity_log(message, ACTIVITY_LOG_LIMIT)
+            .await;
+    }
+}
+
+async fn fetch_trade_log_samples(
+    database: &DatabaseManager,
+    since_ms: u64,
+) -> Result<Vec<TradeSample>> {
+    let rows: Vec<(String, f64, f64, f64)> = sqlx::query_as(
+        r#"
+        SELECT mode, expected_ev::float8, actual_pnl::float8, fees_paid::float8
+        FROM trade_logs
+        WHERE time >= to_timestamp($1::float8 / 1000.0)
+          AND actual_pnl IS NOT NULL
+        "#,
+    )
+    .bind(since_ms as f64)
+    .fetch_all(database.pool())
+    .await?;
+    Ok(rows
+        .into_iter()
+        .map(|(mode, expected_ev, actual_pnl, fees_paid)| TradeSample {
+            mode,
+            expected_ev,
+            net_pnl: actual_pnl - fees_paid,
+        })
+        .collect())
+}
+
+fn now_ms() -> Result<u64> {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
+    Ok(now.as_millis() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(mode: &str, expected_ev: f64, net_pnl: f64) -> TradeSample {
+        TradeSample {
+            mode: mode.to_string(),
+            expected_ev,
+            net_pnl,
+        }
+    }
+
+    #[test]
+    fn losing_samples_tighten_strategy_within_step_limits() {
+        let current = StrategyConfig {
+            kelly_fraction: 0.25,
+            snipe_min_edge_bps: 200.0,
+            spread_offset_bps: 15.0,
+        };
+        let samples: Vec<TradeSample> = (0..12).map(|_| sample("snipe", 1.0, -2.0)).collect();
+        let stats = SampleStats::from_samples(&samples);
+        let config = MetaBrainConfig {
+            max_kelly_step: 0.01,
+            max_snipe_step_bps: 20.0,
+            ..Default::default()
+        };
+
+        let proposed = propose_strategy(&current, &stats, &config);
+        assert!((proposed.kelly_fraction - 0.24).abs() < 1e-9);
+        assert!((proposed.snipe_min_edge_bps - 220.0).abs() < 1e-9);
+        assert_eq!(proposed.spread_offset_bps, 15.0);
+
+        let few = SampleStats::from_samples(&samples[..3]);
+        let unchanged = propose_strategy(&current, &few, &config);
+        assert_eq!(unchanged.kelly_fraction, current.kelly_fraction);
+        assert_eq!(unchanged.snipe_min_edge_bps, current.snipe_min_edge_bps);
+    }
+}
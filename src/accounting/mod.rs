@@ -1,5 +1,6 @@
 pub mod bankroll_refresh;
 pub mod keys;
+pub mod meta_brain;
 pub mod no_money;
 pub mod open_orders_refresh;
 /**
@@ -12,10 +13,13 @@ pub mod open_orders_refresh;
  * @notes
  * - Redemption handles post-resolution capital recycling.
  * - Recovery rehydrates balances and open orders on startup.
+ * - Meta-brain retunes strategy parameters from realized performance.
+ * - Regret scores taken and blocker-suppressed intents into `regret_metrics`.
  */
 pub mod pnl;
 pub mod reconcile;
 pub mod recovery;
 pub mod redemption;
+pub mod regret;
 pub mod trade_events;
 pub mod utils;
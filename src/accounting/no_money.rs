@@ -5,6 +5,8 @@
  * @notes
  * - Records intents without placing orders.
  * - Scores them using start/end prices from Chainlink RTDS.
+ * - Also scores per-window predictions from the active and shadow probability models.
+ * - Settled trades are kept for a week in `paper:results` as samples for the meta-brain.
  */
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
@@ -12,10 +14,12 @@ use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tokio::task::JoinHandle;
 
 use crate::config::Config;
+use crate::engine::analysis::calculate_kelly;
 use crate::engine::types::{TradeIntent, TradeMode, TradeSide};
 use crate::error::{BankaiError, Result};
 use crate::storage::orderbook::{BookSide, OrderBookStore};
 use crate::storage::redis::{MarketMetadata, RedisManager};
+use crate::telemetry::metrics;
 use arc_swap::ArcSwap;
 use chrono::{TimeZone, Utc};
 use chrono_tz::America::New_York;
@@ -29,6 +33,11 @@ const PAPER_STATS_MISSED: &str = "paper:stats:missed";
 const PAPER_STATS_MISSED_REASON: &str = "paper:stats:missed_reason";
 const PAPER_BANKROLL_KEY: &str = "paper:bankroll:usdc";
 const PAPER_BANKROLL_START_KEY: &str = "paper:bankroll:start_usdc";
+const PAPER_MODEL_ZSET_KEY: &str = "paper:models:predictions";
+const PAPER_MODEL_SET_KEY: &str = "paper:models";
+const PAPER_MODEL_STATS_PREFIX: &str = "paper:models:stats:";
+const PAPER_RESULTS_ZSET_KEY: &str = "paper:results";
+const PAPER_RESULTS_RETENTION_MS: u64 = 7 * 24 * 60 * 60 * 1000;
 const PAPER_LOG_LIMIT: usize = 200;
 const PAPER_VWAP_LEVELS: usize = 50;
 const PAPER_SETTLE_GRACE_MS: u64 = 120_000;
@@ -43,6 +52,8 @@ pub struct PaperSimConfig {
     pub slippage_bps: f64,
     pub latency_ms: u64,
     pub taker_fee_bps: f64,
+    /// Catalogue assets searched when mapping a market back to its asset.
+    pub assets: Vec<String>,
 }
 
 impl PaperSimConfig {
@@ -56,6 +67,7 @@ impl PaperSimConfig {
             slippage_bps: config.execution.paper_slippage_bps,
             latency_ms: config.execution.paper_latency_ms,
             taker_fee_bps: config.fees.taker_fee_bps,
+            assets: config.polymarket.market_assets(),
         }
     }
 }
@@ -91,6 +103,42 @@ pub struct PaperIntent {
     pub orderbook_age_ms: Option<u64>,
 }
 
+/// A model's fair UP probability for one market window, scored once the window settles.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelPrediction {
+    pub model: String,
+    pub active: bool,
+    pub asset: String,
+    pub market_id: String,
+    pub true_up: f64,
+    pub implied_up: f64,
+    pub z_score: f64,
+    pub start_time_ms: u64,
+    pub end_time_ms: u64,
+    pub predicted_at_ms: u64,
+}
+
+/// Outcome of one settled paper trade, in the same terms as a `trade_logs` row.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaperResult {
+    pub id: String,
+    pub mode: String,
+    pub expected_ev: f64,
+    pub net_pnl: f64,
+    pub settled_at_ms: u64,
+}
+
+/// Settled paper trades with `settled_at_ms >= since_ms`, oldest first.
+pub async fn paper_results_since(redis: &RedisManager, since_ms: u64) -> Result<Vec<PaperResult>> {
+    let members = redis
+        .zrangebyscore(PAPER_RESULTS_ZSET_KEY, since_ms as f64, f64::INFINITY)
+        .await?;
+    Ok(members
+        .iter()
+        .filter_map(|member| serde_json::from_str(member).ok())
+        .collect())
+}
+
 pub fn spawn_no_money_tracker(config: Arc<ArcSwap<Config>>, redis: RedisManager) -> JoinHandle<()> {
     tokio::spawn(async move {
         if let Err(error) = run_tracker(config, redis).await {
@@ -107,7 +155,7 @@ pub async fn record_no_money_intent(
     let window = intent.market_window.ok_or_else(|| {
         BankaiError::InvalidArgument("paper intent missing market window".to_string())
     })?;
-    let asset = resolve_asset_for_market(redis, &intent.market_id).await?;
+    let asset = resolve_asset_for_market(redis, &sim.assets, &intent.market_id).await?;
     let predicted = resolve_prediction(redis, &intent.market_id, &intent.asset_id).await?;
     if predicted == "UNKNOWN" {
         return Ok(());
@@ -150,6 +198,27 @@ pub async fn record_no_money_intent(
     Ok(())
 }
 
+/// Records the first prediction a model makes for a window; later ones are ignored.
+pub async fn record_model_prediction(
+    redis: &RedisManager,
+    prediction: &ModelPrediction,
+) -> Result<()> {
+    let id = format!(
+        "paper:model:{}:{}:{}",
+        prediction.model, prediction.asset, prediction.start_time_ms
+    );
+    if redis.get_string(&id).await?.is_some() {
+        return Ok(());
+    }
+    let payload = serde_json::to_string(prediction)?;
+    redis.set_string(&id, &payload).await?;
+    redis
+        .zadd(PAPER_MODEL_ZSET_KEY, prediction.end_time_ms as f64, &id)
+        .await?;
+    redis.sadd(PAPER_MODEL_SET_KEY, &prediction.model).await?;
+    Ok(())
+}
+
 async fn run_tracker(config: Arc<ArcSwap<Config>>, redis: RedisManager) -> Result<()> {
     let mut tick = tokio::time::interval(Duration::from_secs(1));
     let mut reset_done = false;
@@ -264,6 +333,7 @@ async fn run_tracker(config: Arc<ArcSwap<Config>>, redis: RedisManager) -> Resul
                 .unwrap_or(cfg.execution.paper_start_bankroll_usdc);
             let new_bankroll = (bankroll + pnl).max(0.0);
             let _ = redis.set_float(PAPER_BANKROLL_KEY, new_bankroll).await;
+            metrics::record_bankroll_usdc("paper", new_bankroll);
 
             if correct {
                 let _ = redis.incr_float(PAPER_STATS_WINS, 1.0).await;
@@ -281,6 +351,7 @@ async fn run_tracker(config: Arc<ArcSwap<Config>>, redis: RedisManager) -> Resul
                 0.0
             };
             let _ = redis.set_float(PAPER_STATS_ACCURACY, accuracy).await;
+            metrics::record_paper_win_rate_pct(accuracy);
 
             let message = format!(
                 "[PAPER] asset={} market={} predicted={} actual={} entry={:.4} size={:.2} pnl={:.4} bankroll={:.4} ok={} accuracy={:.2}%",
@@ -297,12 +368,116 @@ async fn run_tracker(config: Arc<ArcSwap<Config>>, redis: RedisManager) -> Resul
             );
             let _ = redis.push_activity_log(&message, PAPER_LOG_LIMIT).await;
 
+            let expected_ev = size * record.true_prob - notional - fees_paid;
+            let result = PaperResult {
+                id: record.id.clone(),
+                mode: record.mode.clone(),
+                expected_ev,
+                net_pnl: pnl,
+                settled_at_ms: now,
+            };
+            if let Err(error) = record_paper_result(&redis, &result).await {
+                tracing::warn!(?error, "failed to record paper result");
+            }
+
             let _ = redis.zrem(PAPER_ZSET_KEY, &key).await;
             let _ = redis.del(&key).await;
         }
+
+        settle_model_predictions(&redis, now).await?;
     }
 }
 
+async fn settle_model_predictions(redis: &RedisManager, now: u64) -> Result<()> {
+    let pending = redis
+        .zrange_with_scores(PAPER_MODEL_ZSET_KEY, 0, -1)
+        .await?;
+    for (key, score) in pending {
+        if score as u64 > now {
+            break;
+        }
+        let prediction: Option<ModelPrediction> = redis
+            .get_string(&key)
+            .await?
+            .and_then(|payload| serde_json::from_str(&payload).ok());
+        let Some(prediction) = prediction else {
+            let _ = redis.zrem(PAPER_MODEL_ZSET_KEY, &key).await;
+            let _ = redis.del(&key).await;
+            continue;
+        };
+        let start = redis
+            .get_asset_start_price_window(&prediction.asset, prediction.start_time_ms)
+            .await?;
+        let end = redis
+            .get_asset_end_price_window(&prediction.asset, prediction.end_time_ms)
+            .await?;
+        let (Some((_, start_price)), Some((_, end_price))) = (start, end) else {
+            if now.saturating_sub(prediction.end_time_ms) > PAPER_SETTLE_GRACE_MS {
+                let _ = redis.zrem(PAPER_MODEL_ZSET_KEY, &key).await;
+                let _ = redis.del(&key).await;
+            }
+            continue;
+        };
+
+        let outcome_up = end_price >= start_price;
+        let predicted_up = prediction.true_up >= 0.5;
+        let correct = predicted_up == outcome_up;
+        let outcome = if outcome_up { 1.0 } else { 0.0 };
+        let brier = (prediction.true_up - outcome).powi(2);
+
+        let stats_key = format!("{PAPER_MODEL_STATS_PREFIX}{}", prediction.model);
+        let total = redis.hget_float(&stats_key, "total").await?.unwrap_or(0.0) + 1.0;
+        let wins = redis.hget_float(&stats_key, "wins").await?.unwrap_or(0.0)
+            + if correct { 1.0 } else { 0.0 };
+        let brier_sum = redis
+            .hget_float(&stats_key, "brier_sum")
+            .await?
+            .unwrap_or(0.0)
+            + brier;
+        let accuracy = (wins / total) * 100.0;
+        let brier_avg = brier_sum / total;
+        let _ = redis.hset_float(&stats_key, "total", total).await;
+        let _ = redis.hset_float(&stats_key, "wins", wins).await;
+        let _ = redis.hset_float(&stats_key, "brier_sum", brier_sum).await;
+        let _ = redis.hset_float(&stats_key, "accuracy_pct", accuracy).await;
+        let _ = redis.hset_float(&stats_key, "brier_avg", brier_avg).await;
+
+        let message = format!(
+            "[PAPER] model={}{} asset={} market={} p_up={:.4} implied_up={:.4} actual={} ok={} brier={:.4} accuracy={:.2}% brier_avg={:.4}",
+            prediction.model,
+            if prediction.active { "*" } else { "" },
+            prediction.asset,
+            prediction.market_id,
+            prediction.true_up,
+            prediction.implied_up,
+            if outcome_up { "UP" } else { "DOWN" },
+            correct,
+            brier,
+            accuracy,
+            brier_avg
+        );
+        let _ = redis.push_activity_log(&message, PAPER_LOG_LIMIT).await;
+
+        let _ = redis.zrem(PAPER_MODEL_ZSET_KEY, &key).await;
+        let _ = redis.del(&key).await;
+    }
+    Ok(())
+}
+
+async fn record_paper_result(redis: &RedisManager, result: &PaperResult) -> Result<()> {
+    let member = serde_json::to_string(result)?;
+    redis
+        .zadd(PAPER_RESULTS_ZSET_KEY, result.settled_at_ms as f64, &member)
+        .await?;
+    let cutoff = result
+        .settled_at_ms
+        .saturating_sub(PAPER_RESULTS_RETENTION_MS);
+    redis
+        .zremrangebyscore(PAPER_RESULTS_ZSET_KEY, f64::NEG_INFINITY, cutoff as f64)
+        .await?;
+    Ok(())
+}
+
 async fn reset_paper_state(redis: &RedisManager) -> Result<()> {
     let pending = redis.zrange_with_scores(PAPER_ZSET_KEY, 0, -1).await?;
     for (key, _) in pending {
@@ -317,6 +492,20 @@ async fn reset_paper_state(redis: &RedisManager) -> Result<()> {
     let _ = redis.del(PAPER_STATS_MISSED_REASON).await;
     let _ = redis.del(PAPER_BANKROLL_KEY).await;
     let _ = redis.del(PAPER_BANKROLL_START_KEY).await;
+    let predictions = redis
+        .zrange_with_scores(PAPER_MODEL_ZSET_KEY, 0, -1)
+        .await?;
+    for (key, _) in predictions {
+        let _ = redis.del(&key).await;
+    }
+    let _ = redis.del(PAPER_MODEL_ZSET_KEY).await;
+    for model in redis.smembers(PAPER_MODEL_SET_KEY).await? {
+        let _ = redis
+            .del(&format!("{PAPER_MODEL_STATS_PREFIX}{model}"))
+            .await;
+    }
+    let _ = redis.del(PAPER_MODEL_SET_KEY).await;
+    let _ = redis.del(PAPER_RESULTS_ZSET_KEY).await;
     Ok(())
 }
 
@@ -409,18 +598,18 @@ async fn simulate_entry(
 
     let last_update = orderbook.last_update_ms(&intent.asset_id).await?;
     let orderbook_age = last_update.map(|ts| now_ms.saturating_sub(ts));
-    if sim.latency_ms > 0 {
-        if last_update.is_none() || orderbook_age.unwrap_or(u64::MAX) > sim.latency_ms {
-            return Ok((
-                None,
-                Some(size),
-                Some(notional),
-                None,
-                false,
-                Some("latency".to_string()),
-                orderbook_age,
-            ));
-        }
+    if sim.latency_ms > 0
+        && (last_update.is_none() || orderbook_age.unwrap_or(u64::MAX) > sim.latency_ms)
+    {
+        return Ok((
+            None,
+            Some(size),
+            Some(notional),
+            None,
+            false,
+            Some("latency".to_string()),
+            orderbook_age,
+        ));
     }
 
     let side = match intent.side {
@@ -470,22 +659,13 @@ async fn simulate_entry(
     ))
 }
 
-fn calculate_kelly(win_prob: f64, odds: f64) -> f64 {
-    if win_prob <= 0.0 || win_prob >= 1.0 {
-        return 0.0;
-    }
-    if odds <= 1.0 {
-        return 0.0;
-    }
-    let payout = odds - 1.0;
-    let loss_prob = 1.0 - win_prob;
-    let kelly = (win_prob * payout - loss_prob) / payout;
-    kelly.clamp(0.0, 1.0)
-}
-
-async fn resolve_asset_for_market(redis: &RedisManager, market_id: &str) -> Result<String> {
-    for asset in ["BTC", "ETH", "SOL"] {
-        if let Some(window) = redis.get_asset_window(&asset).await? {
+async fn resolve_asset_for_market(
+    redis: &RedisManager,
+    assets: &[String],
+    market_id: &str,
+) -> Result<String> {
+    for asset in assets {
+        if let Some(window) = redis.get_asset_window(asset).await? {
             if window.market_id == market_id {
                 return Ok(asset.to_string());
             }
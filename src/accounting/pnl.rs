@@ -16,6 +16,7 @@ use crate::accounting::keys::{PNL_24H_KEY, REALIZED_PNL_24H_KEY, UNREALIZED_PNL_
 use crate::error::Result;
 use crate::storage::orderbook::OrderBookStore;
 use crate::storage::redis::RedisManager;
+use crate::telemetry::metrics;
 
 pub struct PnlMonitor {
     redis: RedisManager,
@@ -77,6 +78,7 @@ impl PnlMonitor {
         }
 
         let _ = self.redis.set_float(UNREALIZED_PNL_KEY, unrealized).await;
+        metrics::record_unrealized_pnl_usdc(unrealized);
         let realized_24h = self
             .redis
             .get_float(REALIZED_PNL_24H_KEY)
@@ -3,21 +3,14 @@
  * Reconcile confirmed trades from the Polymarket CLOB API into Redis state.
  *
  * @dependencies
- * - reqwest: REST calls
- * - hmac/sha2: CLOB auth headers
+ * - execution::clob: authenticated /data/trades requests
  * - redis: position updates
  *
  * @notes
  * - Uses /data/trades with L2 headers.
  * - De-duplicates trade ids in Redis to avoid double counting.
  */
-use base64::engine::general_purpose;
-use base64::Engine as _;
 use chrono::DateTime;
-use hmac::{Hmac, Mac};
-use reqwest::header::{HeaderMap, HeaderValue};
-use reqwest::{Client, Url};
-use secrecy::ExposeSecret;
 use serde::Deserialize;
 use std::collections::HashSet;
 use std::time::Duration;
@@ -26,32 +19,20 @@ use crate::accounting::keys::REALIZED_PNL_KEY;
 use crate::accounting::trade_events::{is_seen_trade, mark_seen_trade, record_realized_pnl_event};
 use crate::config::Config;
 use crate::error::{BankaiError, Result};
+use crate::execution::clob::ClobClient;
 use crate::execution::signer::Eip712Signer;
 use crate::security::Secrets;
 use crate::storage::redis::RedisManager;
 
-type HmacSha256 = Hmac<sha2::Sha256>;
-
 const DEFAULT_CHAIN_ID: u64 = 137;
-const DEFAULT_REQUEST_TIMEOUT_MS: u64 = 4_000;
-const DEFAULT_TRADES_PATH: &str = "/data/trades";
 const DEFAULT_TRADES_LIMIT: usize = 200;
 const DEFAULT_LOOKBACK_SECS: u64 = 3600;
-const HEADER_POLY_ADDRESS: &str = "POLY_ADDRESS";
-const HEADER_POLY_API_KEY: &str = "POLY_API_KEY";
-const HEADER_POLY_PASSPHRASE: &str = "POLY_PASSPHRASE";
-const HEADER_POLY_SIGNATURE: &str = "POLY_SIGNATURE";
-const HEADER_POLY_TIMESTAMP: &str = "POLY_TIMESTAMP";
 
 const ENV_CHAIN_ID: &str = "POLYGON_CHAIN_ID";
-const ENV_TRADES_PATH: &str = "POLYMARKET_TRADES_PATH";
 const ENV_TRADES_LIMIT: &str = "POLYMARKET_TRADES_LIMIT";
 
 pub struct TradeReconciler {
-    base_url: String,
-    trades_path: String,
-    client: Client,
-    auth: ClobAuth,
+    clob: ClobClient,
     wallet_key: String,
     interval: Duration,
     limit: usize,
@@ -63,55 +44,21 @@ impl TradeReconciler {
         config: &Config,
         secrets: &Secrets,
         redis: RedisManager,
+        clob: &ClobClient,
     ) -> Result<Option<Self>> {
-        let api_key = secrets
-            .polymarket_api_key
-            .as_ref()
-            .map(|value| value.expose_secret().to_string());
-        let api_secret = secrets
-            .polymarket_api_secret
-            .as_ref()
-            .map(|value| value.expose_secret().to_string());
-        let api_passphrase = secrets
-            .polymarket_api_passphrase
-            .as_ref()
-            .map(|value| value.expose_secret().to_string());
-
-        let Some(api_key) = api_key else {
-            tracing::warn!("polymarket api key missing; trade reconciler disabled");
-            return Ok(None);
-        };
-        let Some(api_secret) = api_secret else {
-            tracing::warn!("polymarket api secret missing; trade reconciler disabled");
+        if !clob.has_credentials() {
+            tracing::warn!("polymarket api credentials missing; trade reconciler disabled");
             return Ok(None);
-        };
-        let Some(api_passphrase) = api_passphrase else {
-            tracing::warn!("polymarket api passphrase missing; trade reconciler disabled");
-            return Ok(None);
-        };
+        }
 
         let chain_id = read_env_u64(ENV_CHAIN_ID)?.unwrap_or(DEFAULT_CHAIN_ID);
         let signer = Eip712Signer::from_secrets(secrets, chain_id)?;
         let wallet_key = format!("{}", signer.address()).to_ascii_lowercase();
 
-        let trades_path =
-            read_env_value(ENV_TRADES_PATH).unwrap_or_else(|| DEFAULT_TRADES_PATH.to_string());
         let limit = read_env_usize(ENV_TRADES_LIMIT)?.unwrap_or(DEFAULT_TRADES_LIMIT);
 
-        let client = Client::builder()
-            .timeout(Duration::from_millis(DEFAULT_REQUEST_TIMEOUT_MS))
-            .build()?;
-
         Ok(Some(Self {
-            base_url: config.endpoints.relayer_http.clone(),
-            trades_path,
-            client,
-            auth: ClobAuth {
-                address: wallet_key.clone(),
-                api_key,
-                api_passphrase,
-                api_secret,
-            },
+            clob: clob.clone(),
             wallet_key,
             interval: Duration::from_secs(config.execution.trade_reconcile_interval_secs.max(3)),
             limit,
@@ -298,50 +245,10 @@ impl TradeReconciler {
         taker: Option<&str>,
         after: u64,
     ) -> Result<Vec<TradeSnapshot>> {
-        let mut url = Url::parse(self.base_url.trim_end_matches('/'))
-            .map_err(|_| BankaiError::InvalidArgument("clob base url is invalid".to_string()))?;
-        url.set_path(self.trades_path.trim_start_matches('/'));
-        {
-            let mut pairs = url.query_pairs_mut();
-            if let Some(maker) = maker {
-                if !maker.trim().is_empty() {
-                    pairs.append_pair("maker", maker.trim());
-                }
-            }
-            if let Some(taker) = taker {
-                if !taker.trim().is_empty() {
-                    pairs.append_pair("taker", taker.trim());
-                }
-            }
-            if after > 0 {
-                pairs.append_pair("after", &after.to_string());
-            }
-            if self.limit > 0 {
-                pairs.append_pair("limit", &self.limit.to_string());
-            }
-        }
-        let request_path = build_request_path(&url);
-        let headers = build_clob_headers(&self.auth, "GET", &request_path, "")?;
-        let response = self
-            .client
-            .get(url)
-            .headers(headers)
-            .send()
-            .await?
-            .error_for_status()?;
-        let trades: Vec<TradeSnapshot> = response.json().await.unwrap_or_default();
-        Ok(trades)
+        self.clob.trades(maker, taker, after, self.limit).await
     }
 }
 
-#[derive(Debug, Clone)]
-struct ClobAuth {
-    address: String,
-    api_key: String,
-    api_passphrase: String,
-    api_secret: String,
-}
-
 #[derive(Debug, Clone, Deserialize)]
 struct TradeSnapshot {
     pub id: String,
@@ -393,51 +300,6 @@ impl TradeSnapshot {
     }
 }
 
-fn build_clob_headers(auth: &ClobAuth, method: &str, path: &str, body: &str) -> Result<HeaderMap> {
-    let timestamp = current_unix_timestamp();
-    let signature = build_hmac_signature(&auth.api_secret, timestamp, method, path, body)?;
-
-    let mut headers = HeaderMap::new();
-    headers.insert(HEADER_POLY_ADDRESS, header_value(&auth.address)?);
-    headers.insert(HEADER_POLY_API_KEY, header_value(&auth.api_key)?);
-    headers.insert(HEADER_POLY_PASSPHRASE, header_value(&auth.api_passphrase)?);
-    headers.insert(HEADER_POLY_SIGNATURE, header_value(&signature)?);
-    headers.insert(HEADER_POLY_TIMESTAMP, header_value(&timestamp.to_string())?);
-    Ok(headers)
-}
-
-fn build_hmac_signature(
-    secret: &str,
-    timestamp: u64,
-    method: &str,
-    path: &str,
-    body: &str,
-) -> Result<String> {
-    let key = general_purpose::STANDARD
-        .decode(secret)
-        .map_err(|err| BankaiError::InvalidArgument(format!("api secret decode error: {err}")))?;
-    let message = format!("{timestamp}{method}{path}{body}");
-    let mut mac = HmacSha256::new_from_slice(&key)
-        .map_err(|_| BankaiError::InvalidArgument("api secret is invalid for hmac".to_string()))?;
-    mac.update(message.as_bytes());
-    let result = mac.finalize().into_bytes();
-    let signature = general_purpose::STANDARD.encode(result);
-    Ok(signature.replace('+', "-").replace('/', "_"))
-}
-
-fn build_request_path(url: &Url) -> String {
-    match url.query() {
-        Some(query) => format!("{}?{}", url.path(), query),
-        None => url.path().to_string(),
-    }
-}
-
-fn header_value(value: &str) -> Result<HeaderValue> {
-    HeaderValue::from_str(value).map_err(|_| {
-        BankaiError::InvalidArgument("header value contains invalid characters".to_string())
-    })
-}
-
 fn read_env_value(key: &str) -> Option<String> {
     std::env::var(key)
         .ok()
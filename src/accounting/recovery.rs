@@ -3,28 +3,21 @@
  * Startup recovery reconciles on-chain balances and open orders into Redis.
  *
  * @dependencies
- * - base64: decode API secrets for HMAC signatures
  * - ethers-core: ABI types for ERC20/ERC1155 balanceOf calls
  * - ethers-providers: JSON-RPC client for chain reads
  * - ethers-signers: wallet address derivation
- * - hmac/sha2: CLOB auth signature
- * - reqwest: CLOB HTTP client
+ * - execution::clob: authenticated open orders fetch
+ * - reqwest: JSON-RPC HTTP transport
  * - redis: reconcile hot state in Redis
  *
  * @notes
  * - Open orders are pulled from the Polymarket CLOB API when credentials exist.
  * - Chain balances are used to rehydrate bankroll and conditional token positions.
  */
-use base64::engine::general_purpose;
-use base64::Engine as _;
 use ethers_core::abi::{Function, Param, ParamType, StateMutability, Token};
 use ethers_core::types::transaction::eip2718::TypedTransaction;
 use ethers_core::types::{Address, Bytes, TransactionRequest, U256};
 use ethers_providers::{Http, Middleware, Provider};
-use hmac::{Hmac, Mac};
-use reqwest::header::{HeaderMap, HeaderValue};
-use reqwest::{Client, Url};
-use secrecy::ExposeSecret;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::str::FromStr;
@@ -33,16 +26,14 @@ use std::time::Duration;
 use crate::accounting::utils::scale_u256;
 use crate::config::Config;
 use crate::error::{BankaiError, Result};
+use crate::execution::clob::ClobClient;
 use crate::execution::signer::Eip712Signer;
 use crate::security::Secrets;
 use crate::storage::redis::RedisManager;
 
-type HmacSha256 = Hmac<sha2::Sha256>;
-
 const DEFAULT_CHAIN_ID: u64 = 137;
 const DEFAULT_COLLATERAL_DECIMALS: u32 = 6;
 const DEFAULT_CTF_ABI_PATH: &str = "abi/ConditionalTokens.json";
-const DEFAULT_OPEN_ORDERS_PATH: &str = "/data/orders";
 const DEFAULT_REQUEST_TIMEOUT_MS: u64 = 4_000;
 const DEFAULT_OPEN_ORDERS_LIMIT: usize = 200;
 
@@ -55,16 +46,8 @@ const ENV_CHAIN_ID: &str = "POLYGON_CHAIN_ID";
 const ENV_COLLATERAL_ADDRESS: &str = "POLYMARKET_COLLATERAL_ADDRESS";
 const ENV_COLLATERAL_DECIMALS: &str = "POLYMARKET_COLLATERAL_DECIMALS";
 const ENV_CTF_ADDRESS: &str = "POLYMARKET_CTF_ADDRESS";
-const ENV_CLOB_BASE_URL: &str = "POLYMARKET_CLOB_URL";
-const ENV_OPEN_ORDERS_PATH: &str = "POLYMARKET_OPEN_ORDERS_PATH";
 const ENV_OPEN_ORDERS_LIMIT: &str = "POLYMARKET_OPEN_ORDERS_LIMIT";
 
-const HEADER_POLY_ADDRESS: &str = "POLY_ADDRESS";
-const HEADER_POLY_API_KEY: &str = "POLY_API_KEY";
-const HEADER_POLY_PASSPHRASE: &str = "POLY_PASSPHRASE";
-const HEADER_POLY_SIGNATURE: &str = "POLY_SIGNATURE";
-const HEADER_POLY_TIMESTAMP: &str = "POLY_TIMESTAMP";
-
 /// Runtime configuration for startup recovery.
 #[derive(Debug, Clone)]
 pub struct RecoveryConfig {
@@ -74,8 +57,6 @@ pub struct RecoveryConfig {
     pub collateral_decimals: u32,
     pub conditional_tokens: String,
     pub ctf_abi_path: String,
-    pub clob_base_url: String,
-    pub open_orders_path: String,
     pub request_timeout: Duration,
     pub open_orders_limit: usize,
     pub asset_ids: Vec<String>,
@@ -103,10 +84,6 @@ impl RecoveryConfig {
         let chain_id = read_env_u64(ENV_CHAIN_ID)?.unwrap_or(DEFAULT_CHAIN_ID);
         let collateral_decimals =
             read_env_u32(ENV_COLLATERAL_DECIMALS)?.unwrap_or(DEFAULT_COLLATERAL_DECIMALS);
-        let clob_base_url = read_env_value(ENV_CLOB_BASE_URL)
-            .unwrap_or_else(|| config.endpoints.relayer_http.clone());
-        let open_orders_path = read_env_value(ENV_OPEN_ORDERS_PATH)
-            .unwrap_or_else(|| DEFAULT_OPEN_ORDERS_PATH.to_string());
         let open_orders_limit =
             read_env_usize(ENV_OPEN_ORDERS_LIMIT)?.unwrap_or(DEFAULT_OPEN_ORDERS_LIMIT);
 
@@ -117,8 +94,6 @@ impl RecoveryConfig {
             collateral_decimals,
             conditional_tokens: conditional_tokens.unwrap(),
             ctf_abi_path: DEFAULT_CTF_ABI_PATH.to_string(),
-            clob_base_url,
-            open_orders_path,
             request_timeout: Duration::from_millis(DEFAULT_REQUEST_TIMEOUT_MS),
             open_orders_limit,
             asset_ids: config.polymarket.asset_ids.clone(),
@@ -159,80 +134,6 @@ struct OpenOrderSnapshot {
     pub expiration: Option<String>,
 }
 
-#[derive(Debug, Clone)]
-struct ClobAuth {
-    address: String,
-    api_key: String,
-    passphrase: String,
-    secret: String,
-}
-
-struct ClobClient {
-    base_url: String,
-    orders_path: String,
-    client: Client,
-    auth: ClobAuth,
-}
-
-impl ClobClient {
-    fn new(
-        base_url: String,
-        orders_path: String,
-        timeout: Duration,
-        auth: ClobAuth,
-    ) -> Result<Self> {
-        if base_url.trim().is_empty() {
-            return Err(BankaiError::InvalidArgument(
-                "clob base url is required".to_string(),
-            ));
-        }
-        if orders_path.trim().is_empty() {
-            return Err(BankaiError::InvalidArgument(
-                "clob orders path is required".to_string(),
-            ));
-        }
-        let client = Client::builder().timeout(timeout).build()?;
-        Ok(Self {
-            base_url,
-            orders_path,
-            client,
-            auth,
-        })
-    }
-
-    async fn fetch_open_orders(
-        &self,
-        asset_id: Option<&str>,
-        limit: usize,
-    ) -> Result<Vec<OpenOrderSnapshot>> {
-        let mut url = Url::parse(self.base_url.trim_end_matches('/'))
-            .map_err(|_| BankaiError::InvalidArgument("clob base url is invalid".to_string()))?;
-        url.set_path(self.orders_path.trim_start_matches('/'));
-        {
-            let mut pairs = url.query_pairs_mut();
-            if let Some(asset_id) = asset_id {
-                if !asset_id.trim().is_empty() {
-                    pairs.append_pair("asset_id", asset_id.trim());
-                }
-            }
-            if limit > 0 {
-                pairs.append_pair("limit", &limit.to_string());
-            }
-        }
-
-        let request_path = build_request_path(&url);
-        let headers = build_clob_headers(&self.auth, "GET", &request_path, "")?;
-        let response = self.client.get(url).headers(headers).send().await?;
-        if !response.status().is_success() {
-            return Err(BankaiError::Rpc(format!(
-                "clob orders request failed with status {}",
-                response.status()
-            )));
-        }
-        Ok(response.json::<Vec<OpenOrderSnapshot>>().await?)
-    }
-}
-
 /// Startup recovery workflow runner.
 pub struct StartupRecovery {
     config: RecoveryConfig,
@@ -249,10 +150,12 @@ pub struct StartupRecovery {
 
 impl StartupRecovery {
     /// Initialize startup recovery when required settings are available.
+    /// Open orders are only synced when `clob` carries API credentials.
     pub fn from_env(
         config: &Config,
         secrets: &Secrets,
         redis: RedisManager,
+        clob: &ClobClient,
     ) -> Result<Option<Self>> {
         let Some(recovery_config) = RecoveryConfig::from_env(config)? else {
             return Ok(None);
@@ -266,7 +169,7 @@ impl StartupRecovery {
             parse_address(&recovery_config.collateral_token, "collateral token")?;
         let conditional_tokens =
             parse_address(&recovery_config.conditional_tokens, "conditional tokens")?;
-        let clob = build_clob_client(&recovery_config, secrets, &wallet_key)?;
+        let clob = clob.has_credentials().then(|| clob.clone());
 
         Ok(Some(Self {
             config: recovery_config,
@@ -430,13 +333,13 @@ impl StartupRecovery {
         let mut orders = HashMap::new();
         if asset_ids.is_empty() {
             let batch = clob
-                .fetch_open_orders(None, self.config.open_orders_limit)
+                .open_orders(None, None, self.config.open_orders_limit)
                 .await?;
             insert_orders(&mut orders, batch);
         } else {
             for asset_id in asset_ids {
                 let batch = clob
-                    .fetch_open_orders(Some(asset_id), self.config.open_orders_limit)
+                    .open_orders(Some(asset_id), None, self.config.open_orders_limit)
                     .await?;
                 insert_orders(&mut orders, batch);
             }
@@ -493,86 +396,6 @@ fn insert_orders(orders: &mut HashMap<String, OpenOrderSnapshot>, batch: Vec<Ope
     }
 }
 
-fn build_clob_client(
-    config: &RecoveryConfig,
-    secrets: &Secrets,
-    wallet_address: &str,
-) -> Result<Option<ClobClient>> {
-    let api_key = secrets
-        .polymarket_api_key
-        .as_ref()
-        .map(|value| value.expose_secret().trim().to_string())
-        .filter(|value| !value.is_empty());
-    let passphrase = secrets
-        .polymarket_api_passphrase
-        .as_ref()
-        .map(|value| value.expose_secret().trim().to_string())
-        .filter(|value| !value.is_empty());
-    let secret = secrets
-        .polymarket_api_secret
-        .as_ref()
-        .map(|value| value.expose_secret().trim().to_string())
-        .filter(|value| !value.is_empty());
-
-    let (Some(api_key), Some(passphrase), Some(secret)) = (api_key, passphrase, secret) else {
-        return Ok(None);
-    };
-
-    let auth = ClobAuth {
-        address: wallet_address.to_string(),
-        api_key,
-        passphrase,
-        secret,
-    };
-
-    let client = ClobClient::new(
-        config.clob_base_url.clone(),
-        config.open_orders_path.clone(),
-        config.request_timeout,
-        auth,
-    )?;
-    Ok(Some(client))
-}
-
-fn build_clob_headers(auth: &ClobAuth, method: &str, path: &str, body: &str) -> Result<HeaderMap> {
-    let timestamp = current_unix_timestamp();
-    let signature = build_hmac_signature(&auth.secret, timestamp, method, path, body)?;
-
-    let mut headers = HeaderMap::new();
-    headers.insert(HEADER_POLY_ADDRESS, header_value(&auth.address)?);
-    headers.insert(HEADER_POLY_API_KEY, header_value(&auth.api_key)?);
-    headers.insert(HEADER_POLY_PASSPHRASE, header_value(&auth.passphrase)?);
-    headers.insert(HEADER_POLY_SIGNATURE, header_value(&signature)?);
-    headers.insert(HEADER_POLY_TIMESTAMP, header_value(&timestamp.to_string())?);
-    Ok(headers)
-}
-
-fn build_hmac_signature(
-    secret: &str,
-    timestamp: u64,
-    method: &str,
-    path: &str,
-    body: &str,
-) -> Result<String> {
-    let key = general_purpose::STANDARD
-        .decode(secret)
-        .map_err(|err| BankaiError::InvalidArgument(format!("api secret decode error: {err}")))?;
-    let message = format!("{timestamp}{method}{path}{body}");
-    let mut mac = HmacSha256::new_from_slice(&key)
-        .map_err(|_| BankaiError::InvalidArgument("api secret is invalid for hmac".to_string()))?;
-    mac.update(message.as_bytes());
-    let result = mac.finalize().into_bytes();
-    let signature = general_purpose::STANDARD.encode(result);
-    Ok(signature.replace('+', "-").replace('/', "_"))
-}
-
-fn build_request_path(url: &Url) -> String {
-    match url.query() {
-        Some(query) => format!("{}?{}", url.path(), query),
-        None => url.path().to_string(),
-    }
-}
-
 fn build_provider(config: &RecoveryConfig) -> Result<Provider<Http>> {
     let url = reqwest::Url::parse(config.polygon_rpc.trim())
         .map_err(|_| BankaiError::InvalidArgument("polygon rpc url is invalid".to_string()))?;
@@ -695,16 +518,3 @@ fn read_env_usize(key: &str) -> Result<Option<usize>> {
         .map(Some)
         .map_err(|_| BankaiError::InvalidArgument(format!("{key} must be a valid integer")))
 }
-
-fn header_value(value: &str) -> Result<HeaderValue> {
-    HeaderValue::from_str(value).map_err(|_| {
-        BankaiError::InvalidArgument("header value contains invalid characters".to_string())
-    })
-}
-
-fn current_unix_timestamp() -> u64 {
-    let duration = std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .unwrap_or_default();
-    duration.as_secs()
-}
@@ -15,6 +15,10 @@
  * - ConditionResolution logs from the CTF contract are the primary signal.
  * - UMA adapter QuestionResolved logs provide redundancy.
  * - Subgraph backfill is optional and runs on a slower interval.
+ * - Block and subgraph cursors are checkpointed to Redis after each poll and restored at
+ *   startup; block cursors rewind by `min_confirmations` to re-scan across a reorg.
+ * - Redeemed conditions are claimed in a Redis set before the transaction is sent, so a
+ *   rewind or a second process never redeems the same condition twice.
  */
 use ethers_core::abi::{
     Abi, Event, EventParam, Function, LogParam, Param, ParamType, RawLog, StateMutability, Token,
@@ -58,6 +62,8 @@ const DEFAULT_SUBGRAPH_POLL_INTERVAL_SECS: u64 = 60;
 const DEFAULT_SUBGRAPH_MAX_ITEMS: usize = 200;
 const WEI_PER_GWEI: u64 = 1_000_000_000;
 const BANKROLL_REDIS_KEY: &str = "sys:bankroll:usdc";
+const CURSOR_KEY_PREFIX: &str = "redemption:cursor:";
+const REDEEMED_KEY_PREFIX: &str = "redemption:redeemed:";
 const SUBGRAPH_QUERY: &str =
     "query Resolutions($last: BigInt!, $limit: Int!) { marketResolutions(where: { status: \"resolved\", lastUpdateTimestamp_gt: $last }, orderBy: lastUpdateTimestamp, orderDirection: asc, first: $limit) { id lastUpdateTimestamp } }";
 
@@ -714,6 +720,7 @@ pub struct RedemptionListener<R: PositionResolver> {
     processed_conditions: HashSet<H256>,
     subgraph_cursor: Option<u64>,
     last_subgraph_poll: Option<Instant>,
+    redeemed_key: String,
 }
 
 impl<R: PositionResolver + 'static> RedemptionListener<R> {
@@ -721,6 +728,7 @@ impl<R: PositionResolver + 'static> RedemptionListener<R> {
     pub fn new(client: RedemptionClient, redis: RedisManager, resolver: R) -> Self {
         let start_block = client.config().start_block;
         let adapter_states = build_adapter_states(client.config().adapter_configs.as_slice());
+        let redeemed_key = redeemed_key(client.wallet_address());
         Self {
             client,
             redis,
@@ -730,12 +738,19 @@ impl<R: PositionResolver + 'static> RedemptionListener<R> {
             processed_conditions: HashSet::new(),
             subgraph_cursor: None,
             last_subgraph_poll: None,
+            redeemed_key,
         }
     }
 
     /// Spawn the redemption polling task.
     pub fn spawn(mut self) -> tokio::task::JoinHandle<()> {
         tokio::spawn(async move {
+            if let Err(error) = self.restore_cursors().await {
+                tracing::warn!(
+                    ?error,
+                    "redemption cursor restore failed; using start blocks"
+                );
+            }
             loop {
                 if let Err(error) = self.poll_once().await {
                     tracing::error!(?error, "redemption listener polling failed");
@@ -745,6 +760,46 @@ impl<R: PositionResolver + 'static> RedemptionListener<R> {
         })
     }
 
+    /// Loads checkpointed cursors, rewinding block cursors by `min_confirmations`.
+    /// A stored cursor takes precedence over the configured start block.
+    async fn restore_cursors(&mut self) -> Result<()> {
+        let rewind = self.client.config().min_confirmations;
+        if let Some(block) = self.load_cursor("ctf").await? {
+            let block = rewind_block_cursor(block, rewind);
+            tracing::info!(from_block = block, "redemption ctf cursor restored");
+            self.ctf_next_block = Some(block);
+        }
+        for index in 0..self.adapter_states.len() {
+            let name = adapter_cursor_name(self.adapter_states[index].address);
+            if let Some(block) = self.load_cursor(&name).await? {
+                let block = rewind_block_cursor(block, rewind);
+                tracing::info!(
+                    adapter = %format!("{:?}", self.adapter_states[index].address),
+                    from_block = block,
+                    "redemption adapter cursor restored"
+                );
+                self.adapter_states[index].next_block = Some(block);
+            }
+        }
+        if let Some(timestamp) = self.load_cursor("subgraph").await? {
+            self.subgraph_cursor = Some(timestamp);
+        }
+        Ok(())
+    }
+
+    async fn load_cursor(&self, name: &str) -> Result<Option<u64>> {
+        let key = cursor_key(self.client.config().chain_id, name);
+        let value = self.redis.get_string(&key).await?;
+        Ok(value.as_deref().and_then(parse_cursor))
+    }
+
+    async fn save_cursor(&self, name: &str, value: u64) {
+        let key = cursor_key(self.client.config().chain_id, name);
+        if let Err(error) = self.redis.set_string(&key, &value.to_string()).await {
+            tracing::warn!(?error, cursor = name, "redemption cursor checkpoint failed");
+        }
+    }
+
     async fn poll_once(&mut self) -> Result<()> {
         self.poll_ctf_events().await?;
         self.poll_adapter_events().await?;
@@ -787,7 +842,9 @@ impl<R: PositionResolver + 'static> RedemptionListener<R> {
             self.handle_resolution(resolved).await?;
         }
 
-        self.ctf_next_block = Some(to_block.saturating_add(1));
+        let next_block = to_block.saturating_add(1);
+        self.ctf_next_block = Some(next_block);
+        self.save_cursor("ctf", next_block).await;
         Ok(())
     }
 
@@ -840,10 +897,10 @@ impl<R: PositionResolver + 'static> RedemptionListener<R> {
                 self.handle_resolution(event).await?;
             }
 
-            {
-                let state = &mut self.adapter_states[index];
-                state.next_block = Some(to_block.saturating_add(1));
-            }
+            let next_block = to_block.saturating_add(1);
+            self.adapter_states[index].next_block = Some(next_block);
+            self.save_cursor(&adapter_cursor_name(address), next_block)
+                .await;
         }
 
         Ok(())
@@ -909,6 +966,7 @@ impl<R: PositionResolver + 'static> RedemptionListener<R> {
         }
 
         self.subgraph_cursor = Some(newest_timestamp);
+        self.save_cursor("subgraph", newest_timestamp).await;
         Ok(())
     }
 
@@ -962,6 +1020,37 @@ impl<R: PositionResolver + 'static> RedemptionListener<R> {
             return Ok(());
         }
 
+        let member = redeemed_member(resolved.condition_id);
+        if !self.redis.sadd(&self.redeemed_key, &member).await? {
+            tracing::info!(
+                condition_id = %member,
+                source = resolved.source.label(),
+                "condition already redeemed"
+            );
+            self.processed_conditions.insert(resolved.condition_id);
+            return Ok(());
+        }
+
+        if let Err(error) = self.redeem_targets(&resolved, targets).await {
+            if let Err(release_error) = self.redis.srem(&self.redeemed_key, &member).await {
+                tracing::warn!(
+                    ?release_error,
+                    condition_id = %member,
+                    "failed to release redemption claim"
+                );
+            }
+            return Err(error);
+        }
+
+        self.processed_conditions.insert(resolved.condition_id);
+        self.update_bankroll().await
+    }
+
+    async fn redeem_targets(
+        &self,
+        resolved: &ResolvedCondition,
+        targets: Vec<RedemptionTarget>,
+    ) -> Result<()> {
         for target in targets {
             let request = RedemptionRequest::from_target(
                 resolved.condition_id,
@@ -974,10 +1063,7 @@ impl<R: PositionResolver + 'static> RedemptionListener<R> {
                 source = resolved.source.label(),
                 "redeemPositions confirmed"
             );
-            self.update_bankroll().await?;
         }
-
-        self.processed_conditions.insert(resolved.condition_id);
         Ok(())
     }
 
@@ -1013,6 +1099,32 @@ struct SubgraphError {
     message: String,
 }
 
+fn cursor_key(chain_id: u64, name: &str) -> String {
+    format!("{CURSOR_KEY_PREFIX}{chain_id}:{name}")
+}
+
+fn parse_cursor(value: &str) -> Option<u64> {
+    value.trim().parse::<u64>().ok()
+}
+
+/// Steps a restored block cursor back so blocks that were not yet final get re-scanned.
+fn rewind_block_cursor(block: u64, min_confirmations: u64) -> u64 {
+    block.saturating_sub(min_confirmations)
+}
+
+fn redeemed_key(wallet: Address) -> String {
+    format!("{REDEEMED_KEY_PREFIX}{wallet:?}")
+}
+
+/// Full-hex condition id; `Display` for H256 abbreviates and would collide.
+fn redeemed_member(condition_id: H256) -> String {
+    format!("{condition_id:?}")
+}
+
+fn adapter_cursor_name(address: Address) -> String {
+    format!("adapter:{address:?}")
+}
+
 fn build_wallet(secrets: &Secrets, chain_id: u64) -> Result<LocalWallet> {
     let key = secrets
         .polygon_private_key
@@ -1363,3 +1475,53 @@ async fn resolve_position_balance(
         .await?
         .unwrap_or(0.0))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_keys_are_scoped_by_chain_and_source() {
+        assert_eq!(cursor_key(137, "ctf"), "redemption:cursor:137:ctf");
+        let adapter = Address::from_low_u64_be(0xabc);
+        assert_eq!(
+            cursor_key(80002, &adapter_cursor_name(adapter)),
+            format!("redemption:cursor:80002:adapter:{adapter:?}")
+        );
+        assert_eq!(
+            redeemed_key(adapter),
+            format!("redemption:redeemed:{adapter:?}")
+        );
+    }
+
+    #[test]
+    fn restored_cursors_parse_and_rewind() {
+        assert_eq!(parse_cursor(" 1200\n"), Some(1_200));
+        assert_eq!(parse_cursor("not-a-block"), None);
+        assert_eq!(rewind_block_cursor(1_200, 64), 1_136);
+        assert_eq!(rewind_block_cursor(10, 64), 0);
+    }
+
+    #[test]
+    fn rewound_condition_is_skipped_once_redeemed() {
+        let oracle = Address::from_low_u64_be(0x1234);
+        let question_id = H256::from_low_u64_be(42);
+        let condition_id = compute_condition_id(oracle, question_id, 2);
+        // Stands in for the Redis set: SADD reports whether the member was new.
+        let mut redeemed = HashSet::new();
+
+        assert!(redeemed.insert(redeemed_member(condition_id)));
+        // A rewind re-scans the same log, or the adapter path reports the same condition.
+        assert!(!redeemed.insert(redeemed_member(compute_condition_id(
+            oracle,
+            question_id,
+            2
+        ))));
+        assert!(redeemed.insert(redeemed_member(compute_condition_id(
+            oracle,
+            H256::from_low_u64_be(43),
+            2
+        ))));
+        assert_eq!(redeemed_member(condition_id).len(), 66);
+    }
+}
@@ -0,0 +1,254 @@
+/**
+ * @purpose
+ * Regret tracking: scores emitted and blocker-suppressed intents once their window resolves.
+ *
+ * @dependencies
+ * - storage::redis: pending candidates and window start/end prices
+ * - storage::database: `regret_metrics` inserts
+ *
+ * @notes
+ * - Values are per share bought at the intent price (fees included), so taken and
+ *   counterfactual rows are comparable regardless of order size.
+ * - Taken intents: regret = expected EV - realized PnL.
+ * - Blocked intents: regret = the PnL the blocker forfeited (0 when the trade would have lost).
+ */
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::task::JoinHandle;
+
+use crate::error::Result;
+use crate::storage::database::{DatabaseManager, RegretMetricLog};
+use crate::storage::redis::RedisManager;
+
+const PENDING_ZSET_KEY: &str = "regret:pending";
+const CANDIDATE_KEY_PREFIX: &str = "regret:candidate:";
+const CANDIDATE_TTL_SECS: u64 = 24 * 60 * 60;
+const SETTLE_INTERVAL: Duration = Duration::from_secs(10);
+const SETTLE_GRACE_MS: u64 = 120_000;
+const TAKEN_LABEL: &str = "taken";
+
+/// An intent (emitted or suppressed) awaiting its window's outcome.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegretCandidate {
+    pub asset: String,
+    pub market_id: String,
+    /// Outcome the intent would buy: "UP" or "DOWN".
+    pub direction: String,
+    /// Active probability model label.
+    pub strategy: String,
+    pub mode: String,
+    /// Blocker key that suppressed the intent; `None` when it was emitted.
+    #[serde(default)]
+    pub blocker: Option<String>,
+    pub implied_prob: f64,
+    pub true_prob: f64,
+    pub fee_bps: f64,
+    pub start_time_ms: u64,
+    pub end_time_ms: u64,
+    pub recorded_at_ms: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RegretOutcome {
+    pub expected_ev: f64,
+    pub actual_pnl: f64,
+    pub regret_value: f64,
+}
+
+impl RegretCandidate {
+    pub fn outcome(&self, won: bool) -> RegretOutcome {
+        let cost = self.implied_prob * (1.0 + self.fee_bps.max(0.0) / 10_000.0);
+        let expected_ev = self.true_prob - cost;
+        let actual_pnl = if won { 1.0 } else { 0.0 } - cost;
+        let regret_value = match self.blocker {
+            Some(_) => actual_pnl.max(0.0),
+            None => expected_ev - actual_pnl,
+        };
+        RegretOutcome {
+            expected_ev,
+            actual_pnl,
+            regret_value,
+        }
+    }
+
+    fn key(&self) -> String {
+        format!(
+            "{CANDIDATE_KEY_PREFIX}{}:{}:{}:{}",
+            self.asset,
+            self.start_time_ms,
+            self.direction,
+            self.blocker.as_deref().unwrap_or(TAKEN_LABEL)
+        )
+    }
+}
+
+/// Stores a candidate once per asset/window/direction/blocker; repeats are ignored.
+pub async fn record_regret_candidate(
+    redis: &RedisManager,
+    candidate: &RegretCandidate,
+) -> Result<()> {
+    let key = candidate.key();
+    let payload = serde_json::to_string(candidate)?;
+    if redis
+        .set_if_absent(&key, &payload, CANDIDATE_TTL_SECS)
+        .await?
+    {
+        redis
+            .zadd(PENDING_ZSET_KEY, candidate.end_time_ms as f64, &key)
+            .await?;
+    }
+    Ok(())
+}
+
+pub struct RegretTracker {
+    redis: RedisManager,
+    database: DatabaseManager,
+}
+
+impl RegretTracker {
+    pub fn new(redis: RedisManager, database: DatabaseManager) -> Self {
+        Self { redis, database }
+    }
+
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            if let Err(error) = self.run().await {
+                tracing::error!(?error, "regret tracker stopped");
+            }
+        })
+    }
+
+    async fn run(self) -> Result<()> {
+        let mut interval = tokio::time::interval(SETTLE_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Err(error) = self.settle().await {
+                tracing::warn!(?error, "regret settlement failed");
+            }
+        }
+    }
+
+    async fn settle(&self) -> Result<()> {
+        let now = now_ms()?;
+        let due = self
+            .redis
+            .zrangebyscore(PENDING_ZSET_KEY, f64::NEG_INFINITY, now as f64)
+            .await?;
+        for key in due {
+            let Some(raw) = self.redis.get_string(&key).await? else {
+                self.redis.zrem(PENDING_ZSET_KEY, &key).await?;
+                continue;
+            };
+            let candidate: RegretCandidate = match serde_json::from_str(&raw) {
+                Ok(candidate) => candidate,
+                Err(error) => {
+                    tracing::warn!(?error, key = %key, "dropping invalid regret candidate");
+                    self.drop_candidate(&key).await?;
+                    continue;
+                }
+            };
+            let Some(won) = self.resolve(&candidate).await? else {
+                if now.saturating_sub(candidate.end_time_ms) > SETTLE_GRACE_MS {
+                    tracing::warn!(key = %key, "window prices missing; dropping regret candidate");
+                    self.drop_candidate(&key).await?;
+                }
+                continue;
+            };
+            let outcome = candidate.outcome(won);
+            self.database
+                .log_regret_metric(&RegretMetricLog {
+                    market_id: candidate.market_id.clone(),
+                    strategy: candidate.strategy.clone(),
+                    expected_ev: outcome.expected_ev,
+                    actual_pnl: Some(outcome.actual_pnl),
+                    regret_value: outcome.regret_value,
+                    metadata: Some(json!({
+                        "kind": if candidate.blocker.is_some() { "counterfactual" } else { TAKEN_LABEL },
+                        "blocker": candidate.blocker,
+                        "asset": candidate.asset,
+                        "direction": candidate.direction,
+                        "mode": candidate.mode,
+                        "won": won,
+                        "implied_prob": candidate.implied_prob,
+                        "true_prob": candidate.true_prob,
+                        "fee_bps": candidate.fee_bps,
+                        "window_start_ms": candidate.start_time_ms,
+                        "window_end_ms": candidate.end_time_ms,
+                        "recorded_at_ms": candidate.recorded_at_ms,
+                    })),
+                })
+                .await?;
+            self.drop_candidate(&key).await?;
+        }
+        Ok(())
+    }
+
+    /// Whether the candidate's outcome won, once both window prices are known.
+    async fn resolve(&self, candidate: &RegretCandidate) -> Result<Option<bool>> {
+        let Some((_, start_price)) = self
+            .redis
+            .get_asset_start_price_window(&candidate.asset, candidate.start_time_ms)
+            .await?
+        else {
+            return Ok(None);
+        };
+        let Some((_, end_price)) = self
+            .redis
+            .get_asset_end_price_window(&candidate.asset, candidate.end_time_ms)
+            .await?
+        else {
+            return Ok(None);
+        };
+        let actual = if end_price >= start_price {
+            "UP"
+        } else {
+            "DOWN"
+        };
+        Ok(Some(candidate.direction == actual))
+    }
+
+    async fn drop_candidate(&self, key: &str) -> Result<()> {
+        self.redis.zrem(PENDING_ZSET_KEY, key).await?;
+        self.redis.del(key).await
+    }
+}
+
+fn now_ms() -> Result<u64> {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
+    Ok(now.as_millis() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(blocker: Option<&str>) -> RegretCandidate {
+        RegretCandidate {
+            asset: "BTC".to_string(),
+            market_id: "m".to_string(),
+            direction: "UP".to_string(),
+            strategy: "v2".to_string(),
+            mode: "SNIPE".to_string(),
+            blocker: blocker.map(str::to_string),
+            implied_prob: 0.40,
+            true_prob: 0.55,
+            fee_bps: 0.0,
+            start_time_ms: 0,
+            end_time_ms: 1,
+            recorded_at_ms: 0,
+        }
+    }
+
+    #[test]
+    fn taken_and_counterfactual_regret() {
+        let taken = candidate(None).outcome(false);
+        assert!((taken.expected_ev - 0.15).abs() < 1e-9);
+        assert!((taken.actual_pnl + 0.40).abs() < 1e-9);
+        assert!((taken.regret_value - 0.55).abs() < 1e-9);
+
+        let blocked = candidate(Some("v2_edge_floor_up"));
+        assert!((blocked.outcome(true).regret_value - 0.60).abs() < 1e-9);
+        assert_eq!(blocked.outcome(false).regret_value, 0.0);
+    }
+}
@@ -0,0 +1,228 @@
+#![allow(clippy::result_large_err)]
+
+/**
+ * @description
+ * CLI that replays recorded market feeds through the trading engine and reports PnL.
+ *
+ * @dependencies
+ * - bankai_terminal::engine::backtest: replay driver and fill simulator
+ * - tokio: async runtime for Redis access
+ *
+ * @notes
+ * - Requires a scratch Redis database; refuses to touch a non-empty one unless --flush is set.
+ * - Config overrides use dotted paths, e.g. --set execution.model_v2_k=0.8.
+ */
+use bankai_terminal::config::load_config_with_overrides;
+use bankai_terminal::engine::backtest::{read_replay_file, BacktestSettings, Backtester};
+use bankai_terminal::error::{BankaiError, Result};
+use bankai_terminal::storage::redis::RedisManager;
+use serde_json::Value;
+use std::{env, fs, path::PathBuf};
+
+struct Args {
+    replays: Vec<PathBuf>,
+    config: PathBuf,
+    strategies: Option<PathBuf>,
+    overrides: Vec<(String, Value)>,
+    redis_url: Option<String>,
+    output: Option<PathBuf>,
+    bankroll: Option<f64>,
+    flush: bool,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let args = parse_args()?;
+    let config =
+        load_config_with_overrides(&args.config, args.strategies.as_deref(), &args.overrides)?;
+    let redis_url = args
+        .redis_url
+        .clone()
+        .or_else(|| env::var("REDIS_URL").ok())
+        .ok_or_else(|| {
+            BankaiError::InvalidArgument("missing --redis-url (or REDIS_URL)".to_string())
+        })?;
+    let redis = RedisManager::new(&redis_url).await?;
+    prepare_scratch_db(&redis, args.flush).await?;
+
+    let mut records = Vec::new();
+    for path in &args.replays {
+        records.extend(read_replay_file(path)?);
+    }
+    records.sort_by_key(|record| record.ts_ms);
+
+    let mut settings = BacktestSettings::from_config(&config);
+    if let Some(bankroll) = args.bankroll {
+        settings.start_bankroll_usdc = bankroll;
+    }
+    let report = Backtester::new(config, redis, settings)
+        .run(records)
+        .await?;
+
+    for window in &report.windows {
+        println!(
+            "{} {} [{}-{}] start={} end={} outcome={} fills={} pnl={:.4}",
+            window.asset,
+            window.market_id,
+            window.start_time_ms,
+            window.end_time_ms,
+            format_price(window.start_price),
+            format_price(window.end_price),
+            window.outcome.as_deref().unwrap_or("-"),
+            window.fills.len(),
+            window.pnl
+        );
+        for fill in &window.fills {
+            println!(
+                "    {} {} price={:.4} size={:.2} fee={:.4} edge_bps={:.1}",
+                fill.mode, fill.outcome, fill.price, fill.size, fill.fee, fill.edge_bps
+            );
+        }
+    }
+    println!(
+        "records={} intents={} fills={} wins={} losses={} pnl={:.4} bankroll={:.2}->{:.2}",
+        report.records,
+        report.intents,
+        report.fills,
+        report.wins,
+        report.losses,
+        report.total_pnl,
+        report.start_bankroll_usdc,
+        report.end_bankroll_usdc
+    );
+    if !report.blockers.is_empty() {
+        println!("blockers:");
+        for (reason, count) in &report.blockers {
+            println!("    {reason}: {count}");
+        }
+    }
+    if !report.rejections.is_empty() {
+        println!("rejections:");
+        for (reason, count) in &report.rejections {
+            println!("    {reason}: {count}");
+        }
+    }
+
+    if let Some(output) = args.output {
+        fs::write(&output, serde_json::to_string_pretty(&report)?)?;
+        println!("Report written to {}", output.display());
+    }
+    Ok(())
+}
+
+async fn prepare_scratch_db(redis: &RedisManager, flush: bool) -> Result<()> {
+    let mut conn = redis.connection();
+    let size: u64 = redis::cmd("DBSIZE").query_async(&mut conn).await?;
+    if size == 0 {
+        return Ok(());
+    }
+    if !flush {
+        return Err(BankaiError::InvalidArgument(format!(
+            "redis database holds {size} keys; point --redis-url at a scratch db or pass --flush"
+        )));
+    }
+    let _: () = redis::cmd("FLUSHDB").query_async(&mut conn).await?;
+    Ok(())
+}
+
+fn parse_args() -> Result<Args> {
+    let mut replays = Vec::new();
+    let mut config = PathBuf::from("config/config.json");
+    let mut strategies = None;
+    let mut overrides = Vec::new();
+    let mut redis_url = None;
+    let mut output = None;
+    let mut bankroll = None;
+    let mut flush = false;
+    let mut args = env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--replay" | "-r" => {
+                replays.push(PathBuf::from(next_value(&mut args, "--replay")?));
+            }
+            "--config" | "-c" => {
+                config = PathBuf::from(next_value(&mut args, "--config")?);
+            }
+            "--strategies" => {
+                strategies = Some(PathBuf::from(next_value(&mut args, "--strategies")?));
+            }
+            "--set" => {
+                overrides.push(parse_override(&next_value(&mut args, "--set")?)?);
+            }
+            "--redis-url" => {
+                redis_url = Some(next_value(&mut args, "--redis-url")?);
+            }
+            "--output" | "-o" => {
+                output = Some(PathBuf::from(next_value(&mut args, "--output")?));
+            }
+            "--bankroll" => {
+                let value = next_value(&mut args, "--bankroll")?;
+                bankroll = Some(value.parse::<f64>().map_err(|_| {
+                    BankaiError::InvalidArgument(format!("invalid --bankroll value: {value}"))
+                })?);
+            }
+            "--flush" => {
+                flush = true;
+            }
+            "--help" | "-h" => {
+                print_usage();
+                std::process::exit(0);
+            }
+            _ => {
+                return Err(BankaiError::InvalidArgument(format!(
+                    "unknown argument: {arg}"
+                )));
+            }
+        }
+    }
+
+    if replays.is_empty() {
+        return Err(BankaiError::InvalidArgument(
+            "at least one --replay file is required".to_string(),
+        ));
+    }
+    let strategies = strategies.or_else(|| config.parent().map(|dir| dir.join("strategies.json")));
+
+    Ok(Args {
+        replays,
+        config,
+        strategies,
+        overrides,
+        redis_url,
+        output,
+        bankroll,
+        flush,
+    })
+}
+
+fn next_value(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<String> {
+    args.next()
+        .ok_or_else(|| BankaiError::InvalidArgument(format!("missing value for {flag}")))
+}
+
+fn parse_override(raw: &str) -> Result<(String, Value)> {
+    let (key, value) = raw.split_once('=').ok_or_else(|| {
+        BankaiError::InvalidArgument(format!("override must be key=value: {raw}"))
+    })?;
+    let parsed = serde_json::from_str(value).unwrap_or_else(|_| Value::String(value.to_string()));
+    Ok((key.trim().to_string(), parsed))
+}
+
+fn format_price(value: Option<f64>) -> String {
+    value
+        .map(|price| format!("{price:.2}"))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+fn print_usage() {
+    println!("Usage: backtest --replay <file.ndjson> [--replay <file>] [options]");
+    println!("  --replay, -r     NDJSON replay file (repeatable)");
+    println!("  --config, -c     Config path (default: config/config.json)");
+    println!("  --strategies     Strategy override path (default: <config dir>/strategies.json)");
+    println!("  --set            Config override as dotted.key=value (repeatable)");
+    println!("  --redis-url      Scratch Redis URL (default: REDIS_URL)");
+    println!("  --flush          Flush the Redis database before replaying");
+    println!("  --bankroll       Starting bankroll in USDC (default: paper_start_bankroll_usdc)");
+    println!("  --output, -o     Write the full JSON report to this path");
+}
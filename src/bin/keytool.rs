@@ -2,29 +2,76 @@
 
 /**
  * @description
- * CLI utility to capture credentials and write an encrypted secrets.enc file.
+ * CLI utility to create and maintain the encrypted secrets.enc file: capture credentials,
+ * inspect or update single fields, rotate the password, verify the wallet and CLOB auth, and
+ * derive or rotate Polymarket API credentials from the stored wallet key.
  *
  * @dependencies
  * - bankai_terminal::security: encryption helpers and password prompts
+ * - bankai_terminal::execution::clob: L1-authenticated API key endpoints
  * - secrecy: secret handling for input normalization
  *
  * @notes
- * - Avoids printing secrets to stdout or logs.
+ * - Avoids printing secrets to stdout or logs; only field presence and the API key id are shown.
+ * - Commands that open an existing file reuse the password from `BANKAI_SECRETS_PASSWORD_FILE`
+ *   or `BANKAI_SECRETS_PASSWORD` when set, otherwise prompt.
+ * - Any write re-encrypts in the current file format, so older files upgrade in place.
  */
+use bankai_terminal::config::load_config_with_overrides;
 use bankai_terminal::error::{BankaiError, Result};
+use bankai_terminal::execution::clob::{ClobClient, ClobClientConfig, ClobCredentials};
+use bankai_terminal::execution::signer::Eip712Signer;
 use bankai_terminal::security::{
-    encrypt_to_file, prompt_new_password, prompt_password, SecretsPayload, DEFAULT_SECRETS_PATH,
+    encrypt_to_file, prompt_new_password, prompt_password, read_secrets_info,
+    resolve_secrets_password, KdfParams, SecretsFile, SecretsPayload, DEFAULT_SECRETS_PATH,
+    SECRET_FIELDS,
 };
-use secrecy::ExposeSecret;
+use secrecy::{ExposeSecret, SecretString};
+use serde_json::Value;
 use std::{env, fs, path::PathBuf};
 
-struct Args {
+const DEFAULT_CONFIG_PATH: &str = "config/config.json";
+const DEFAULT_CHAIN_ID: u64 = 137;
+
+enum Command {
+    Init(InitArgs),
+    Show(FileArgs),
+    Set(FileArgs),
+    RotatePassword(FileArgs),
+    Verify(FileArgs),
+    DeriveApiKey(FileArgs),
+    RotateApiKey(FileArgs),
+}
+
+struct InitArgs {
     output: PathBuf,
     force: bool,
 }
 
+/// Options shared by the commands that open an existing secrets file.
+struct FileArgs {
+    secrets: PathBuf,
+    config: PathBuf,
+    clob_url: Option<String>,
+    nonce: Option<u64>,
+    check_clob: bool,
+    kdf: KdfParams,
+    field: Option<String>,
+}
+
 fn main() -> Result<()> {
-    let args = parse_args()?;
+    match parse_args()? {
+        Command::Init(args) => run_init(args),
+        Command::Show(args) => run_show(args),
+        Command::Set(args) => run_set(args),
+        Command::RotatePassword(args) => run_rotate_password(args),
+        Command::Verify(args) => run_verify(args),
+        Command::DeriveApiKey(args) => run_api_key(args, false),
+        Command::RotateApiKey(args) => run_api_key(args, true),
+    }
+}
+
+fn run_init(args: InitArgs) -> Result<()> {
     if args.output.exists() && !args.force {
         return Err(BankaiError::InvalidArgument(format!(
             "refusing to overwrite {}; use --force to continue",
@@ -47,18 +94,241 @@ fn main() -> Result<()> {
     Ok(())
 }
 
-fn parse_args() -> Result<Args> {
+/// Prints the format, KDF parameters, which fields are present, and the wallet address.
+fn run_show(args: FileArgs) -> Result<()> {
+    let info = read_secrets_info(&args.secrets)?;
+    let secrets_file = SecretsFile::new(&args.secrets, resolve_secrets_password()?);
+    let payload = secrets_file.read_payload()?;
+
+    println!("File: {}", args.secrets.display());
+    if info.is_current() {
+        println!("Format version: {}", info.version);
+    } else {
+        println!("Format version: {} (upgraded on next write)", info.version);
+    }
+    println!(
+        "KDF: argon2id m={} KiB t={} p={}",
+        info.kdf.m_cost_kib, info.kdf.t_cost, info.kdf.p_cost
+    );
+    for name in SECRET_FIELDS {
+        let present = payload
+            .field(name)
+            .and_then(Option::as_deref)
+            .is_some_and(|value| !value.trim().is_empty());
+        println!("  {name}: {}", if present { "set" } else { "missing" });
+    }
+    match wallet_address(&payload) {
+        Some(Ok(address)) => println!("Wallet address: {address}"),
+        Some(Err(error)) => println!("Wallet address: invalid private key ({error})"),
+        None => println!("Wallet address: none"),
+    }
+    Ok(())
+}
+
+/// Replaces (or clears, on empty input) a single field.
+fn run_set(args: FileArgs) -> Result<()> {
+    let field = args.field.ok_or_else(|| {
+        BankaiError::InvalidArgument(format!(
+            "set requires a field: {}",
+            SECRET_FIELDS.join(", ")
+        ))
+    })?;
+    if !SECRET_FIELDS.contains(&field.as_str()) {
+        return Err(BankaiError::InvalidArgument(format!(
+            "unknown field {field}; expected one of {}",
+            SECRET_FIELDS.join(", ")
+        )));
+    }
+
+    let secrets_file = SecretsFile::new(&args.secrets, resolve_secrets_password()?);
+    secrets_file.read_payload()?;
+    let value = prompt_optional_value(&format!("New value for {field} (empty to clear): "))?;
+    if field == "polygon_private_key" {
+        if let Some(key) = value.as_ref() {
+            let signer =
+                Eip712Signer::from_private_key(&SecretString::new(key.clone()), chain_id())?;
+            println!("Wallet address: {}", signer.checksum_address());
+        }
+    }
+
+    let cleared = value.is_none();
+    secrets_file.update(|payload| {
+        if let Some(slot) = payload.field_mut(&field) {
+            *slot = value;
+        }
+    })?;
+    if cleared {
+        println!("Cleared {field} in {}", args.secrets.display());
+    } else {
+        println!("Updated {field} in {}", args.secrets.display());
+    }
+    Ok(())
+}
+
+/// Re-encrypts the file under a new password and Argon2id parameters.
+fn run_rotate_password(args: FileArgs) -> Result<()> {
+    let secrets_file = SecretsFile::new(&args.secrets, resolve_secrets_password()?);
+    secrets_file.read_payload()?;
+    let password = prompt_new_password()?;
+    secrets_file.rotate_password(password, &args.kdf)?;
+    println!(
+        "Re-encrypted {} with argon2id m={} KiB t={} p={}",
+        args.secrets.display(),
+        args.kdf.m_cost_kib,
+        args.kdf.t_cost,
+        args.kdf.p_cost
+    );
+    Ok(())
+}
+
+/// Decrypts the file, derives the wallet, and with `--clob` checks L1 and L2 auth.
+fn run_verify(args: FileArgs) -> Result<()> {
+    let secrets_file = SecretsFile::new(&args.secrets, resolve_secrets_password()?);
+    let secrets = secrets_file.load()?;
+    println!("Decrypted {}", args.secrets.display());
+
+    let signer = Eip712Signer::from_secrets(&secrets, chain_id())?;
+    let address = signer.checksum_address();
+    println!("Wallet address: {address}");
+    let credentials = ClobCredentials::from_secrets(&secrets, &address);
+    match credentials.as_ref() {
+        Some(credentials) => println!("API credentials: present (key {})", credentials.api_key),
+        None => println!("API credentials: incomplete"),
+    }
+    if !args.check_clob {
+        return Ok(());
+    }
+
+    let (clob, nonce) = build_clob(&args, credentials.clone())?;
+    let runtime = build_runtime()?;
+    let mut failed = false;
+    match runtime.block_on(clob.derive_api_key(&signer, nonce)) {
+        Ok(derived) => {
+            let matches = credentials
+                .as_ref()
+                .is_some_and(|stored| stored.api_key == derived.api_key);
+            println!(
+                "L1 auth: ok (nonce {nonce} key {}{})",
+                derived.api_key,
+                if matches {
+                    ", matches stored key"
+                } else {
+                    ", differs from stored key"
+                }
+            );
+        }
+        Err(error) => {
+            failed = true;
+            println!("L1 auth: failed ({error})");
+        }
+    }
+    if clob.has_credentials() {
+        match runtime.block_on(clob.open_orders::<Value>(None, None, 1)) {
+            Ok(_) => println!("L2 auth: ok"),
+            Err(error) => {
+                failed = true;
+                println!("L2 auth: failed ({error})");
+            }
+        }
+    }
+    if failed {
+        return Err(BankaiError::Rpc(
+            "clob auth verification failed".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Derives the API key, or when rotating creates a new one, stores it in secrets.enc, and only
+/// then revokes the old key so a failure never leaves the file holding a revoked key.
+fn run_api_key(args: FileArgs, rotate: bool) -> Result<()> {
+    let secrets_file = SecretsFile::new(&args.secrets, resolve_secrets_password()?);
+    let secrets = secrets_file.load()?;
+    let signer = Eip712Signer::from_secrets(&secrets, chain_id())?;
+    let address = signer.checksum_address();
+    let current = ClobCredentials::from_secrets(&secrets, &address);
+    let (clob, configured_nonce) = build_clob(&args, current.clone())?;
+
+    let runtime = build_runtime()?;
+    if !rotate {
+        let credentials =
+            runtime.block_on(clob.derive_or_create_api_key(&signer, configured_nonce))?;
+        secrets_file.update(|payload| credentials.write_to(payload))?;
+        println!(
+            "API key {} for {} written to {}",
+            credentials.api_key,
+            address,
+            args.secrets.display()
+        );
+        return Ok(());
+    }
+
+    // A nonce can hold one key, so rotation creates under the next nonce unless told otherwise.
+    let nonce = args.nonce.unwrap_or(configured_nonce.saturating_add(1));
+    let credentials = runtime.block_on(clob.create_api_key(&signer, nonce))?;
+    if current
+        .as_ref()
+        .is_some_and(|current| current.api_key == credentials.api_key)
+    {
+        return Err(BankaiError::InvalidArgument(format!(
+            "nonce {nonce} returned the stored API key; pass --nonce with an unused value"
+        )));
+    }
+    secrets_file.update(|payload| credentials.write_to(payload))?;
+    println!(
+        "API key {} (nonce {nonce}) for {} written to {}",
+        credentials.api_key,
+        address,
+        args.secrets.display()
+    );
+    if nonce != configured_nonce {
+        println!("Set clob.api_key_nonce to {nonce} so runtime re-derivation finds this key");
+    }
+
+    let Some(current) = current else {
+        println!("No previous API key stored; nothing to revoke");
+        return Ok(());
+    };
+    runtime.block_on(clob.delete_api_key()).map_err(|error| {
+        BankaiError::Rpc(format!(
+            "new API key saved, but revoking old API key {} failed: {error}",
+            current.api_key
+        ))
+    })?;
+    println!("Revoked API key {}", current.api_key);
+    Ok(())
+}
+
+fn parse_args() -> Result<Command> {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let command = match args.first().map(String::as_str) {
+        Some(
+            "init" | "show" | "set" | "rotate-password" | "verify" | "derive-api-key"
+            | "rotate-api-key",
+        ) => Some(args.remove(0)),
+        _ => None,
+    };
+
+    match command.as_deref() {
+        Some("show") => Ok(Command::Show(parse_file_args(args, false)?)),
+        Some("set") => Ok(Command::Set(parse_file_args(args, true)?)),
+        Some("rotate-password") => Ok(Command::RotatePassword(parse_file_args(args, false)?)),
+        Some("verify") => Ok(Command::Verify(parse_file_args(args, false)?)),
+        Some("derive-api-key") => Ok(Command::DeriveApiKey(parse_file_args(args, false)?)),
+        Some("rotate-api-key") => Ok(Command::RotateApiKey(parse_file_args(args, false)?)),
+        _ => Ok(Command::Init(parse_init_args(args)?)),
+    }
+}
+
+fn parse_init_args(args: Vec<String>) -> Result<InitArgs> {
     let mut output = PathBuf::from(DEFAULT_SECRETS_PATH);
     let mut force = false;
-    let mut args = env::args().skip(1);
+    let mut args = args.into_iter();
 
     while let Some(arg) = args.next() {
         match arg.as_str() {
             "--output" | "-o" => {
-                let value = args.next().ok_or_else(|| {
-                    BankaiError::InvalidArgument("missing value for --output".to_string())
-                })?;
-                output = PathBuf::from(value);
+                output = PathBuf::from(next_value(&mut args, "--output")?);
             }
             "--force" | "-f" => {
                 force = true;
@@ -81,13 +351,147 @@ fn parse_args() -> Result<Args> {
         }
     }
 
-    Ok(Args { output, force })
+    Ok(InitArgs { output, force })
+}
+
+/// Only `set` takes a positional field name; other subcommands reject stray values.
+fn parse_file_args(args: Vec<String>, accepts_field: bool) -> Result<FileArgs> {
+    let mut parsed = FileArgs {
+        secrets: PathBuf::from(DEFAULT_SECRETS_PATH),
+        config: PathBuf::from(DEFAULT_CONFIG_PATH),
+        clob_url: None,
+        nonce: None,
+        check_clob: false,
+        kdf: KdfParams::default(),
+        field: None,
+    };
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--secrets" | "-s" => {
+                parsed.secrets = PathBuf::from(next_value(&mut args, "--secrets")?);
+            }
+            "--config" | "-c" => {
+                parsed.config = PathBuf::from(next_value(&mut args, "--config")?);
+            }
+            "--clob-url" => {
+                parsed.clob_url = Some(next_value(&mut args, "--clob-url")?);
+            }
+            "--nonce" => {
+                parsed.nonce = Some(next_number(&mut args, "--nonce")?);
+            }
+            "--clob" => {
+                parsed.check_clob = true;
+            }
+            "--m-cost" => {
+                parsed.kdf.m_cost_kib = next_number(&mut args, "--m-cost")?;
+            }
+            "--t-cost" => {
+                parsed.kdf.t_cost = next_number(&mut args, "--t-cost")?;
+            }
+            "--p-cost" => {
+                parsed.kdf.p_cost = next_number(&mut args, "--p-cost")?;
+            }
+            "--help" | "-h" => {
+                print_usage();
+                std::process::exit(0);
+            }
+            value if accepts_field && !value.starts_with('-') && parsed.field.is_none() => {
+                parsed.field = Some(value.to_string());
+            }
+            _ => {
+                return Err(BankaiError::InvalidArgument(format!(
+                    "unknown argument: {arg}"
+                )));
+            }
+        }
+    }
+
+    Ok(parsed)
+}
+
+fn next_value(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<String> {
+    args.next()
+        .ok_or_else(|| BankaiError::InvalidArgument(format!("missing value for {flag}")))
+}
+
+fn next_number<T: std::str::FromStr>(
+    args: &mut impl Iterator<Item = String>,
+    flag: &str,
+) -> Result<T> {
+    let value = next_value(args, flag)?;
+    value
+        .parse::<T>()
+        .map_err(|_| BankaiError::InvalidArgument(format!("invalid {flag} value: {value}")))
+}
+
+fn build_clob(args: &FileArgs, credentials: Option<ClobCredentials>) -> Result<(ClobClient, u64)> {
+    let config = load_config_with_overrides(&args.config, None, &[])?;
+    let mut clob_config = ClobClientConfig::from_config(&config);
+    if let Some(url) = args.clob_url.clone() {
+        clob_config.base_url = url;
+    }
+    let nonce = args.nonce.unwrap_or(config.clob.api_key_nonce);
+    Ok((ClobClient::new(clob_config, credentials)?, nonce))
+}
+
+fn build_runtime() -> Result<tokio::runtime::Runtime> {
+    Ok(tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?)
+}
+
+fn chain_id() -> u64 {
+    env::var("POLYGON_CHAIN_ID")
+        .ok()
+        .and_then(|value| value.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_CHAIN_ID)
+}
+
+fn wallet_address(payload: &SecretsPayload) -> Option<Result<String>> {
+    let key = payload
+        .polygon_private_key
+        .as_ref()
+        .filter(|value| !value.trim().is_empty())?;
+    Some(
+        Eip712Signer::from_private_key(&SecretString::new(key.clone()), chain_id())
+            .map(|signer| signer.checksum_address()),
+    )
 }
 
 fn print_usage() {
-    println!("Usage: keytool [--output <path>] [--force]");
+    println!("Usage: keytool [init] [--output <path>] [--force]");
+    println!("       keytool show [--secrets <path>]");
+    println!("       keytool set <field> [--secrets <path>]");
+    println!("       keytool rotate-password [--secrets <path>] [--m-cost KiB] [--t-cost N] [--p-cost N]");
+    println!("       keytool verify [--secrets <path>] [--clob] [clob options]");
+    println!("       keytool derive-api-key [--secrets <path>] [clob options]");
+    println!("       keytool rotate-api-key [--secrets <path>] [clob options]");
+    println!();
+    println!("init (default):");
     println!("  --output, -o  Path for secrets.enc (default: config/secrets.enc)");
     println!("  --force, -f   Overwrite existing secrets.enc");
+    println!();
+    println!("show             List which fields are set (never values) and the wallet address");
+    println!("set <field>      Replace one field; empty input clears it");
+    println!("                 fields: {}", SECRET_FIELDS.join(", "));
+    println!("rotate-password  Re-encrypt under a new password and Argon2id parameters");
+    println!("                 (default m=65536 KiB t=3 p=1)");
+    println!("verify           Decrypt and derive the wallet; --clob also checks L1 and L2 auth");
+    println!("derive-api-key   Derive (or create) API credentials from the wallet and store them");
+    println!("rotate-api-key   Create and store a new API key (next nonce by default), then");
+    println!("                 revoke the previous one");
+    println!();
+    println!("Common options:");
+    println!("  --secrets, -s  Path to secrets.enc (default: config/secrets.enc)");
+    println!();
+    println!("CLOB options:");
+    println!("  --config, -c   Config file for the CLOB endpoint (default: config/config.json)");
+    println!("  --clob-url     Override the CLOB base URL");
+    println!(
+        "  --nonce        ClobAuth nonce (default: clob.api_key_nonce, +1 for rotate-api-key)"
+    );
 }
 
 fn prompt_optional_value(label: &str) -> Result<Option<String>> {
@@ -12,15 +12,20 @@
  */
 use arc_swap::ArcSwap;
 use notify::{EventKind, RecursiveMode, Watcher};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::{
+    collections::{HashMap, HashSet},
     fs,
     path::{Path, PathBuf},
-    sync::{mpsc, Arc},
+    sync::{mpsc, Arc, Mutex},
 };
 
 use crate::error::{BankaiError, Result};
 
+const DEFAULT_SIGNAL_HORIZON_MS: u64 = 5 * 60 * 1_000;
+const DEFAULT_SIGNAL_TIMEFRAME: &str = "5m";
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct Config {
     pub endpoints: EndpointConfig,
@@ -37,11 +42,31 @@ pub struct Config {
     pub preflight: PreflightConfig,
     #[serde(default)]
     pub allora_consumer: Option<AlloraConsumerConfig>,
+    #[serde(default)]
+    pub recorder: RecorderConfig,
+    #[serde(default)]
+    pub binance: BinanceConfig,
+    #[serde(default)]
+    pub direct: DirectConfig,
+    #[serde(default)]
+    pub python_strategy: PythonStrategyConfig,
+    #[serde(default)]
+    pub meta_brain: MetaBrainConfig,
+    #[serde(default)]
+    pub telemetry: TelemetryConfig,
+    #[serde(default)]
+    pub admin: AdminConfig,
+    #[serde(default)]
+    pub clob: ClobConfig,
+    #[serde(default)]
+    pub secrets: SecretsConfig,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct EndpointConfig {
     pub chainlink_ws: String,
+    #[serde(default = "default_binance_ws")]
+    pub binance_ws: String,
     pub polymarket_ws: String,
     #[serde(default)]
     pub polymarket_user_ws: Option<String>,
@@ -61,7 +86,7 @@ pub struct TradingConfig {
     pub kill_switch_consecutive_losses: u32,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct StrategyConfig {
     pub kelly_fraction: f64,
     pub snipe_min_edge_bps: f64,
@@ -113,6 +138,9 @@ pub struct ExecutionConfig {
     pub model_version: String,
     #[serde(default = "default_execution_model_shadow_mode")]
     pub model_shadow_mode: bool,
+    /// Shadow models to run next to the active one; empty means every other registered model.
+    #[serde(default)]
+    pub model_shadow_versions: Vec<String>,
     #[serde(default = "default_execution_model_v2_k")]
     pub model_v2_k: f64,
     #[serde(default = "default_execution_model_v2_z_min")]
@@ -125,6 +153,9 @@ pub struct ExecutionConfig {
     pub contrarian_min_edge_bps: f64,
     #[serde(default = "default_execution_contrarian_confidence_min")]
     pub contrarian_confidence_min: f64,
+    /// With the direction gate on, signals below this confidence block both sides; 0 disables.
+    #[serde(default = "default_execution_signal_confidence_min")]
+    pub signal_confidence_min: f64,
     #[serde(default = "default_execution_min_volatility")]
     pub min_volatility: f64,
     #[serde(default = "default_execution_staleness_max_ratio")]
@@ -187,6 +218,16 @@ pub struct ExecutionConfig {
     pub trailing_stop_bps: f64,
     #[serde(default = "default_execution_close_fraction")]
     pub close_fraction: f64,
+    #[serde(default = "default_execution_order_sweep_interval_ms")]
+    pub order_sweep_interval_ms: u64,
+    #[serde(default = "default_execution_ladder_max_age_secs")]
+    pub ladder_max_age_secs: u64,
+    #[serde(default = "default_execution_ladder_cancel_grace_ms")]
+    pub ladder_cancel_grace_ms: u64,
+    #[serde(default = "default_execution_fill_check_delay_ms")]
+    pub fill_check_delay_ms: u64,
+    #[serde(default = "default_execution_degraded_book_stale_ms")]
+    pub degraded_book_stale_ms: u64,
 }
 
 impl Default for ExecutionConfig {
@@ -202,12 +243,14 @@ impl Default for ExecutionConfig {
             probability_max_offset: default_execution_probability_max_offset(),
             model_version: default_execution_model_version(),
             model_shadow_mode: default_execution_model_shadow_mode(),
+            model_shadow_versions: Vec::new(),
             model_v2_k: default_execution_model_v2_k(),
             model_v2_z_min: default_execution_model_v2_z_min(),
             model_v2_edge_floor_bps: default_execution_model_v2_edge_floor_bps(),
             signal_direction_gate: default_execution_signal_direction_gate(),
             contrarian_min_edge_bps: default_execution_contrarian_min_edge_bps(),
             contrarian_confidence_min: default_execution_contrarian_confidence_min(),
+            signal_confidence_min: default_execution_signal_confidence_min(),
             min_volatility: default_execution_min_volatility(),
             staleness_max_ratio: default_execution_staleness_max_ratio(),
             order_expiry_secs: default_execution_order_expiry_secs(),
@@ -239,6 +282,11 @@ impl Default for ExecutionConfig {
             stop_loss_bps: default_execution_stop_loss_bps(),
             trailing_stop_bps: default_execution_trailing_stop_bps(),
             close_fraction: default_execution_close_fraction(),
+            order_sweep_interval_ms: default_execution_order_sweep_interval_ms(),
+            ladder_max_age_secs: default_execution_ladder_max_age_secs(),
+            ladder_cancel_grace_ms: default_execution_ladder_cancel_grace_ms(),
+            fill_check_delay_ms: default_execution_fill_check_delay_ms(),
+            degraded_book_stale_ms: default_execution_degraded_book_stale_ms(),
         }
     }
 }
@@ -257,6 +305,12 @@ pub struct PolymarketConfig {
     pub reconnect_delay_secs: u64,
     #[serde(default = "default_polymarket_snapshot_timeout_ms")]
     pub snapshot_timeout_ms: u64,
+    /// How often dirty in-memory books are mirrored to Redis.
+    #[serde(default = "default_polymarket_book_mirror_interval_ms")]
+    pub book_mirror_interval_ms: u64,
+    /// Up/down market families to discover and trade, one entry per asset.
+    #[serde(default = "default_polymarket_markets")]
+    pub markets: Vec<MarketCatalogueEntry>,
 }
 
 impl Default for PolymarketConfig {
@@ -268,10 +322,129 @@ impl Default for PolymarketConfig {
             ping_interval_secs: default_polymarket_ping_interval_secs(),
             reconnect_delay_secs: default_polymarket_reconnect_delay_secs(),
             snapshot_timeout_ms: default_polymarket_snapshot_timeout_ms(),
+            book_mirror_interval_ms: default_polymarket_book_mirror_interval_ms(),
+            markets: default_polymarket_markets(),
         }
     }
 }
 
+impl PolymarketConfig {
+    pub fn market(&self, asset: &str) -> Option<&MarketCatalogueEntry> {
+        self.markets
+            .iter()
+            .find(|entry| entry.asset.eq_ignore_ascii_case(asset))
+    }
+
+    /// Allora timeframe that drives trading for `asset`; 5m when the asset is not catalogued.
+    pub fn signal_timeframe(&self, asset: &str) -> &str {
+        self.market(asset)
+            .map(|entry| entry.signal_timeframe.trim())
+            .unwrap_or(DEFAULT_SIGNAL_TIMEFRAME)
+    }
+
+    pub fn market_assets(&self) -> Vec<String> {
+        self.markets
+            .iter()
+            .map(|entry| entry.asset.to_ascii_uppercase())
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct MarketCatalogueEntry {
+    /// Canonical asset symbol used for Redis keys and signals (e.g. "BTC").
+    pub asset: String,
+    /// Gamma tag ids scanned for this asset.
+    #[serde(default)]
+    pub tag_ids: Vec<String>,
+    /// Extra names matched against slug prefixes and market titles.
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    /// Substrings a market slug must contain (any of them); empty accepts all.
+    #[serde(default)]
+    pub slug_patterns: Vec<String>,
+    #[serde(default = "default_market_window_secs")]
+    pub window_secs: u64,
+    /// Allora timeframe that drives trading on this market (e.g. "5m").
+    #[serde(default = "default_market_signal_timeframe")]
+    pub signal_timeframe: String,
+    /// Overrides the derived signal alignment horizon.
+    #[serde(default)]
+    pub alignment_max_secs: Option<u64>,
+}
+
+impl MarketCatalogueEntry {
+    pub fn window_ms(&self) -> u64 {
+        self.window_secs.saturating_mul(1000)
+    }
+
+    pub fn signal_timeframe_ms(&self) -> Option<u64> {
+        parse_timeframe_ms(&self.signal_timeframe)
+    }
+
+    /// Names matched against slugs and titles: the asset itself plus aliases.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.asset.as_str()).chain(self.aliases.iter().map(String::as_str))
+    }
+}
+
+impl Config {
+    /// Maximum age of a trading signal relative to the window end.
+    ///
+    /// Uses the catalogue override when set, the execution 5m settings for 5m signals,
+    /// and otherwise the signal timeframe itself; never longer than the market window.
+    pub fn signal_alignment_horizon_ms(&self, asset: &str) -> u64 {
+        let entry = self.polymarket.market(asset);
+        let override_ms = entry
+            .and_then(|entry| entry.alignment_max_secs)
+            .map(|secs| secs.saturating_mul(1000))
+            .filter(|value| *value > 0);
+        let timeframe_ms = entry
+            .map(|entry| entry.signal_timeframe_ms())
+            .unwrap_or(Some(DEFAULT_SIGNAL_HORIZON_MS));
+        let selected = override_ms.or_else(|| match timeframe_ms {
+            Some(DEFAULT_SIGNAL_HORIZON_MS) => {
+                let base_ms = self
+                    .execution
+                    .signal_alignment_max_secs
+                    .saturating_mul(1000);
+                let sol_ms = self
+                    .execution
+                    .signal_alignment_max_secs_sol
+                    .saturating_mul(1000);
+                let selected = if asset.eq_ignore_ascii_case("SOL") && sol_ms > 0 {
+                    sol_ms
+                } else {
+                    base_ms
+                };
+                Some(selected).filter(|value| *value > 0)
+            }
+            other => other,
+        });
+        let horizon = selected.unwrap_or(DEFAULT_SIGNAL_HORIZON_MS);
+        match entry.map(MarketCatalogueEntry::window_ms) {
+            Some(window_ms) if window_ms > 0 => horizon.min(window_ms),
+            _ => horizon,
+        }
+    }
+}
+
+/// Parses Allora-style timeframes such as "5m", "1h" or "30s" into milliseconds.
+pub fn parse_timeframe_ms(value: &str) -> Option<u64> {
+    let value = value.trim().to_ascii_lowercase();
+    let split = value.find(|ch: char| !ch.is_ascii_digit())?;
+    let (amount, unit) = value.split_at(split);
+    let amount: u64 = amount.parse().ok()?;
+    let unit_ms = match unit {
+        "s" => 1_000,
+        "m" => 60_000,
+        "h" => 3_600_000,
+        "d" => 86_400_000,
+        _ => return None,
+    };
+    amount.checked_mul(unit_ms).filter(|value| *value > 0)
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct AlloraConsumerConfig {
     pub base_url: String,
@@ -281,6 +454,8 @@ pub struct AlloraConsumerConfig {
     #[serde(default)]
     pub api_key: Option<String>,
     pub topics: Vec<AlloraTopicConfig>,
+    #[serde(default)]
+    pub signature: AlloraSignatureConfig,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -289,6 +464,51 @@ pub struct AlloraTopicConfig {
     pub timeframe: String,
     #[serde(default)]
     pub topic_id: Option<u64>,
+    /// Base weight of this topic in the fused signal; 0 keeps it out of fusion.
+    #[serde(default = "default_allora_topic_weight")]
+    pub weight: f64,
+    /// Age at which the topic's fusion weight halves; defaults to its timeframe.
+    #[serde(default)]
+    pub half_life_secs: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlloraSignatureMode {
+    /// Signatures are not checked.
+    #[default]
+    Off,
+    /// Updates are checked and tagged, but unsigned/invalid ones are still broadcast.
+    Tag,
+    /// Unsigned/invalid updates are dropped before the broadcast channel.
+    Reject,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AlloraSignatureConfig {
+    #[serde(default)]
+    pub mode: AlloraSignatureMode,
+    /// Addresses of the Allora data providers allowed to sign inferences.
+    #[serde(default)]
+    pub signers: Vec<String>,
+    /// Consumer contract the signature is bound to (part of the signed message).
+    #[serde(default)]
+    pub consumer_contract: Option<String>,
+    /// EVM chain id of the consumer contract; parsed from `chain` (e.g. "ethereum-11155111") when unset.
+    #[serde(default)]
+    pub chain_id: Option<u64>,
+}
+
+impl AlloraConsumerConfig {
+    pub fn topic(&self, asset: &str, timeframe: &str) -> Option<&AlloraTopicConfig> {
+        self.topics.iter().find(|topic| {
+            topic.asset.eq_ignore_ascii_case(asset)
+                && topic
+                    .timeframe
+                    .trim()
+                    .eq_ignore_ascii_case(timeframe.trim())
+        })
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -334,6 +554,447 @@ impl Default for PreflightConfig {
     }
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct RecorderConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_recorder_directory")]
+    pub directory: String,
+    #[serde(default = "default_recorder_segment_max_bytes")]
+    pub segment_max_bytes: u64,
+    #[serde(default = "default_recorder_segment_max_secs")]
+    pub segment_max_secs: u64,
+    #[serde(default)]
+    pub max_segments: usize,
+    #[serde(default = "default_recorder_queue_capacity")]
+    pub queue_capacity: usize,
+}
+
+impl Default for RecorderConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            directory: default_recorder_directory(),
+            segment_max_bytes: default_recorder_segment_max_bytes(),
+            segment_max_secs: default_recorder_segment_max_secs(),
+            max_segments: 0,
+            queue_capacity: default_recorder_queue_capacity(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TelemetryConfig {
+    #[serde(default = "default_metrics_enabled")]
+    pub metrics_enabled: bool,
+    /// Bind address of the Prometheus `/metrics` endpoint.
+    #[serde(default = "default_metrics_listen_addr")]
+    pub metrics_listen_addr: String,
+}
+
+impl Default for TelemetryConfig {
+    fn default() -> Self {
+        Self {
+            metrics_enabled: default_metrics_enabled(),
+            metrics_listen_addr: default_metrics_listen_addr(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AdminConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_admin_listen_addr")]
+    pub listen_addr: String,
+    /// Env var holding the bearer token for write endpoints; writes are refused when unset.
+    #[serde(default = "default_admin_token_env")]
+    pub token_env: String,
+}
+
+impl Default for AdminConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            listen_addr: default_admin_listen_addr(),
+            token_env: default_admin_token_env(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ClobConfig {
+    #[serde(default = "default_clob_request_timeout_ms")]
+    pub request_timeout_ms: u64,
+    #[serde(default = "default_clob_order_timeout_ms")]
+    pub order_timeout_ms: u64,
+    /// Retries for reads and cancels; order posts are retried by the orchestrator instead.
+    #[serde(default = "default_clob_max_retries")]
+    pub max_retries: u32,
+    #[serde(default = "default_clob_backoff_ms")]
+    pub backoff_ms: u64,
+    #[serde(default = "default_clob_backoff_max_ms")]
+    pub backoff_max_ms: u64,
+    #[serde(default)]
+    pub rate_limits: ClobRateLimitConfig,
+    /// Derive API credentials from the wallet when missing, and re-derive them after a 401.
+    #[serde(default)]
+    pub auto_derive_credentials: bool,
+    /// ClobAuth nonce used when deriving or creating API keys.
+    #[serde(default)]
+    pub api_key_nonce: u64,
+}
+
+impl Default for ClobConfig {
+    fn default() -> Self {
+        Self {
+            request_timeout_ms: default_clob_request_timeout_ms(),
+            order_timeout_ms: default_clob_order_timeout_ms(),
+            max_retries: default_clob_max_retries(),
+            backoff_ms: default_clob_backoff_ms(),
+            backoff_max_ms: default_clob_backoff_max_ms(),
+            rate_limits: ClobRateLimitConfig::default(),
+            auto_derive_credentials: false,
+            api_key_nonce: 0,
+        }
+    }
+}
+
+/// Token bucket sizes per endpoint class, in requests per 10 seconds (0 disables the limit).
+#[derive(Debug, Clone, Deserialize)]
+pub struct ClobRateLimitConfig {
+    #[serde(default = "default_clob_post_order_per_10s")]
+    pub post_order_per_10s: u32,
+    #[serde(default = "default_clob_cancel_per_10s")]
+    pub cancel_per_10s: u32,
+    #[serde(default = "default_clob_cancel_all_per_10s")]
+    pub cancel_all_per_10s: u32,
+    #[serde(default = "default_clob_data_per_10s")]
+    pub data_per_10s: u32,
+    #[serde(default = "default_clob_fee_rate_per_10s")]
+    pub fee_rate_per_10s: u32,
+}
+
+impl Default for ClobRateLimitConfig {
+    fn default() -> Self {
+        Self {
+            post_order_per_10s: default_clob_post_order_per_10s(),
+            cancel_per_10s: default_clob_cancel_per_10s(),
+            cancel_all_per_10s: default_clob_cancel_all_per_10s(),
+            data_per_10s: default_clob_data_per_10s(),
+            fee_rate_per_10s: default_clob_fee_rate_per_10s(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DivergenceAction {
+    Block,
+    Halt,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BinanceConfig {
+    #[serde(default = "default_binance_enabled")]
+    pub enabled: bool,
+    #[serde(default = "default_binance_max_divergence_bps")]
+    pub max_divergence_bps: f64,
+    #[serde(default = "default_binance_divergence_action")]
+    pub divergence_action: DivergenceAction,
+    #[serde(default = "default_binance_chainlink_stale_ms")]
+    pub chainlink_stale_ms: u64,
+    #[serde(default = "default_binance_stale_ms")]
+    pub binance_stale_ms: u64,
+    #[serde(default = "default_binance_fallback_enabled")]
+    pub fallback_enabled: bool,
+}
+
+impl Default for BinanceConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_binance_enabled(),
+            max_divergence_bps: default_binance_max_divergence_bps(),
+            divergence_action: default_binance_divergence_action(),
+            chainlink_stale_ms: default_binance_chainlink_stale_ms(),
+            binance_stale_ms: default_binance_stale_ms(),
+            fallback_enabled: default_binance_fallback_enabled(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DirectConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Overrides `endpoints.polygon_rpc`, e.g. for a private mempool RPC.
+    #[serde(default)]
+    pub rpc_url: Option<String>,
+    #[serde(default = "default_direct_abi_path")]
+    pub abi_path: String,
+    #[serde(default = "default_direct_request_timeout_ms")]
+    pub request_timeout_ms: u64,
+    #[serde(default)]
+    pub gas_limit: Option<u64>,
+    #[serde(default)]
+    pub max_fee_per_gas_gwei: Option<u64>,
+    #[serde(default)]
+    pub max_priority_fee_gwei: Option<u64>,
+    #[serde(default)]
+    pub private_rpc_headers: HashMap<String, String>,
+    #[serde(default = "default_direct_nonce_stuck_timeout_ms")]
+    pub nonce_stuck_timeout_ms: u64,
+    #[serde(default = "default_direct_nonce_bump_cooldown_ms")]
+    pub nonce_bump_cooldown_ms: u64,
+    #[serde(default = "default_direct_nonce_lock_ttl_ms")]
+    pub nonce_lock_ttl_ms: u64,
+    #[serde(default = "default_direct_monitor_interval_ms")]
+    pub monitor_interval_ms: u64,
+    #[serde(default = "default_direct_rbf_bump_pct")]
+    pub rbf_bump_pct: f64,
+    #[serde(default = "default_direct_rbf_max_bumps")]
+    pub rbf_max_bumps: u32,
+    #[serde(default)]
+    pub rbf_max_fee_per_gas_gwei: Option<u64>,
+}
+
+impl Default for DirectConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            rpc_url: None,
+            abi_path: default_direct_abi_path(),
+            request_timeout_ms: default_direct_request_timeout_ms(),
+            gas_limit: None,
+            max_fee_per_gas_gwei: None,
+            max_priority_fee_gwei: None,
+            private_rpc_headers: HashMap::new(),
+            nonce_stuck_timeout_ms: default_direct_nonce_stuck_timeout_ms(),
+            nonce_bump_cooldown_ms: default_direct_nonce_bump_cooldown_ms(),
+            nonce_lock_ttl_ms: default_direct_nonce_lock_ttl_ms(),
+            monitor_interval_ms: default_direct_monitor_interval_ms(),
+            rbf_bump_pct: default_direct_rbf_bump_pct(),
+            rbf_max_bumps: default_direct_rbf_max_bumps(),
+            rbf_max_fee_per_gas_gwei: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PythonStrategyConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_python_strategy_module")]
+    pub module: String,
+    #[serde(default = "default_python_strategy_module_dir")]
+    pub module_dir: String,
+    /// Per-call budget; slower calls fall back to the Rust model.
+    #[serde(default = "default_python_strategy_timeout_ms")]
+    pub timeout_ms: u64,
+    /// Let the module's `calculate_kelly` size buy intents.
+    #[serde(default = "default_python_strategy_sizing")]
+    pub sizing: bool,
+    /// Re-import the module when its source file changes.
+    #[serde(default = "default_python_strategy_hot_reload")]
+    pub hot_reload: bool,
+}
+
+impl Default for PythonStrategyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            module: default_python_strategy_module(),
+            module_dir: default_python_strategy_module_dir(),
+            timeout_ms: default_python_strategy_timeout_ms(),
+            sizing: default_python_strategy_sizing(),
+            hot_reload: default_python_strategy_hot_reload(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct MetaBrainConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_meta_brain_interval_secs")]
+    pub interval_secs: u64,
+    #[serde(default = "default_meta_brain_lookback_hours")]
+    pub lookback_hours: u64,
+    #[serde(default = "default_meta_brain_min_samples")]
+    pub min_samples: usize,
+    /// Also learn from settled no-money paper trades, not only `trade_logs`.
+    #[serde(default = "default_meta_brain_include_paper")]
+    pub include_paper: bool,
+    /// Largest kelly_fraction change applied in one cycle.
+    #[serde(default = "default_meta_brain_max_kelly_step")]
+    pub max_kelly_step: f64,
+    /// Largest snipe_min_edge_bps change applied in one cycle.
+    #[serde(default = "default_meta_brain_max_snipe_step_bps")]
+    pub max_snipe_step_bps: f64,
+    #[serde(default = "default_meta_brain_min_kelly_fraction")]
+    pub min_kelly_fraction: f64,
+    #[serde(default = "default_meta_brain_max_kelly_fraction")]
+    pub max_kelly_fraction: f64,
+    #[serde(default = "default_meta_brain_min_snipe_edge_bps")]
+    pub min_snipe_edge_bps: f64,
+    #[serde(default = "default_meta_brain_max_snipe_edge_bps")]
+    pub max_snipe_edge_bps: f64,
+}
+
+impl Default for MetaBrainConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval_secs: default_meta_brain_interval_secs(),
+            lookback_hours: default_meta_brain_lookback_hours(),
+            min_samples: default_meta_brain_min_samples(),
+            include_paper: default_meta_brain_include_paper(),
+            max_kelly_step: default_meta_brain_max_kelly_step(),
+            max_snipe_step_bps: default_meta_brain_max_snipe_step_bps(),
+            min_kelly_fraction: default_meta_brain_min_kelly_fraction(),
+            max_kelly_fraction: default_meta_brain_max_kelly_fraction(),
+            min_snipe_edge_bps: default_meta_brain_min_snipe_edge_bps(),
+            max_snipe_edge_bps: default_meta_brain_max_snipe_edge_bps(),
+        }
+    }
+}
+
+fn default_meta_brain_interval_secs() -> u64 {
+    21_600
+}
+
+fn default_meta_brain_lookback_hours() -> u64 {
+    6
+}
+
+fn default_meta_brain_min_samples() -> usize {
+    10
+}
+
+fn default_meta_brain_include_paper() -> bool {
+    true
+}
+
+fn default_meta_brain_max_kelly_step() -> f64 {
+    0.05
+}
+
+fn default_meta_brain_max_snipe_step_bps() -> f64 {
+    50.0
+}
+
+fn default_meta_brain_min_kelly_fraction() -> f64 {
+    0.05
+}
+
+fn default_meta_brain_max_kelly_fraction() -> f64 {
+    0.5
+}
+
+fn default_meta_brain_min_snipe_edge_bps() -> f64 {
+    50.0
+}
+
+fn default_meta_brain_max_snipe_edge_bps() -> f64 {
+    2000.0
+}
+
+fn default_python_strategy_module() -> String {
+    "strategy".to_string()
+}
+
+fn default_python_strategy_module_dir() -> String {
+    "python".to_string()
+}
+
+fn default_python_strategy_timeout_ms() -> u64 {
+    25
+}
+
+fn default_python_strategy_sizing() -> bool {
+    true
+}
+
+fn default_python_strategy_hot_reload() -> bool {
+    true
+}
+
+fn default_direct_abi_path() -> String {
+    "abi/CTFExchange.json".to_string()
+}
+
+fn default_direct_request_timeout_ms() -> u64 {
+    3500
+}
+
+fn default_direct_nonce_stuck_timeout_ms() -> u64 {
+    15_000
+}
+
+fn default_direct_nonce_bump_cooldown_ms() -> u64 {
+    3_000
+}
+
+fn default_direct_nonce_lock_ttl_ms() -> u64 {
+    2_000
+}
+
+fn default_direct_monitor_interval_ms() -> u64 {
+    2_000
+}
+
+fn default_direct_rbf_bump_pct() -> f64 {
+    15.0
+}
+
+fn default_direct_rbf_max_bumps() -> u32 {
+    5
+}
+
+fn default_binance_ws() -> String {
+    "wss://stream.binance.com:9443/ws".to_string()
+}
+
+fn default_binance_enabled() -> bool {
+    true
+}
+
+fn default_binance_max_divergence_bps() -> f64 {
+    50.0
+}
+
+fn default_binance_divergence_action() -> DivergenceAction {
+    DivergenceAction::Block
+}
+
+fn default_binance_chainlink_stale_ms() -> u64 {
+    15_000
+}
+
+fn default_binance_stale_ms() -> u64 {
+    5_000
+}
+
+fn default_binance_fallback_enabled() -> bool {
+    true
+}
+
+fn default_recorder_directory() -> String {
+    "data/recordings".to_string()
+}
+
+fn default_recorder_segment_max_bytes() -> u64 {
+    64 * 1024 * 1024
+}
+
+fn default_recorder_segment_max_secs() -> u64 {
+    3600
+}
+
+fn default_recorder_queue_capacity() -> usize {
+    16_384
+}
+
 fn default_clock_drift_interval_secs() -> u64 {
     30
 }
@@ -374,6 +1035,152 @@ fn default_polymarket_snapshot_timeout_ms() -> u64 {
     10_000
 }
 
+fn default_polymarket_book_mirror_interval_ms() -> u64 {
+    100
+}
+
+fn default_polymarket_markets() -> Vec<MarketCatalogueEntry> {
+    [
+        ("BTC", "235", "bitcoin"),
+        ("ETH", "39", "ethereum"),
+        ("SOL", "968", "solana"),
+    ]
+    .into_iter()
+    .map(|(asset, tag_id, alias)| MarketCatalogueEntry {
+        asset: asset.to_string(),
+        tag_ids: vec![tag_id.to_string()],
+        aliases: vec![alias.to_string()],
+        slug_patterns: vec!["15m".to_string()],
+        window_secs: default_market_window_secs(),
+        signal_timeframe: default_market_signal_timeframe(),
+        alignment_max_secs: None,
+    })
+    .collect()
+}
+
+fn default_metrics_enabled() -> bool {
+    true
+}
+
+fn default_metrics_listen_addr() -> String {
+    "127.0.0.1:9898".to_string()
+}
+
+fn default_admin_listen_addr() -> String {
+    "127.0.0.1:8787".to_string()
+}
+
+/// Where runtime secrets come from. Providers are consulted in order and the first one to
+/// supply a field wins.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SecretsConfig {
+    /// Any of `file`, `env`, `command`, and `keyring` when built with the `keyring` feature.
+    #[serde(default = "default_secrets_providers")]
+    pub providers: Vec<String>,
+    #[serde(default = "default_secrets_file_path")]
+    pub file_path: String,
+    /// Prefix for env vars named after the secret fields, e.g. `BANKAI_POLYGON_PRIVATE_KEY`.
+    #[serde(default = "default_secrets_env_prefix")]
+    pub env_prefix: String,
+    /// Program and arguments that print a JSON object of secret fields to stdout.
+    #[serde(default)]
+    pub command: Vec<String>,
+    #[serde(default = "default_secrets_command_timeout_ms")]
+    pub command_timeout_ms: u64,
+    /// OS keyring service name; each secret field is stored as its own entry.
+    #[serde(default = "default_secrets_keyring_service")]
+    pub keyring_service: String,
+}
+
+impl Default for SecretsConfig {
+    fn default() -> Self {
+        Self {
+            providers: default_secrets_providers(),
+            file_path: default_secrets_file_path(),
+            env_prefix: default_secrets_env_prefix(),
+            command: Vec::new(),
+            command_timeout_ms: default_secrets_command_timeout_ms(),
+            keyring_service: default_secrets_keyring_service(),
+        }
+    }
+}
+
+fn default_secrets_providers() -> Vec<String> {
+    vec!["file".to_string()]
+}
+
+fn default_secrets_file_path() -> String {
+    crate::security::DEFAULT_SECRETS_PATH.to_string()
+}
+
+fn default_secrets_env_prefix() -> String {
+    "BANKAI_".to_string()
+}
+
+fn default_secrets_command_timeout_ms() -> u64 {
+    5_000
+}
+
+fn default_secrets_keyring_service() -> String {
+    "bankai_terminal".to_string()
+}
+
+fn default_admin_token_env() -> String {
+    "BANKAI_ADMIN_TOKEN".to_string()
+}
+
+fn default_clob_request_timeout_ms() -> u64 {
+    4_000
+}
+
+fn default_clob_order_timeout_ms() -> u64 {
+    500
+}
+
+fn default_clob_max_retries() -> u32 {
+    2
+}
+
+fn default_clob_backoff_ms() -> u64 {
+    200
+}
+
+fn default_clob_backoff_max_ms() -> u64 {
+    2_000
+}
+
+fn default_clob_post_order_per_10s() -> u32 {
+    500
+}
+
+fn default_clob_cancel_per_10s() -> u32 {
+    500
+}
+
+fn default_clob_cancel_all_per_10s() -> u32 {
+    25
+}
+
+fn default_clob_data_per_10s() -> u32 {
+    150
+}
+
+fn default_clob_fee_rate_per_10s() -> u32 {
+    100
+}
+
+fn default_allora_topic_weight() -> f64 {
+    1.0
+}
+
+fn default_market_window_secs() -> u64 {
+    15 * 60
+}
+
+fn default_market_signal_timeframe() -> String {
+    DEFAULT_SIGNAL_TIMEFRAME.to_string()
+}
+
 fn default_execution_enabled() -> bool {
     false
 }
@@ -438,6 +1245,10 @@ fn default_execution_contrarian_confidence_min() -> f64 {
     0.7
 }
 
+fn default_execution_signal_confidence_min() -> f64 {
+    0.0
+}
+
 fn default_execution_min_volatility() -> f64 {
     0.001
 }
@@ -478,6 +1289,26 @@ fn default_execution_order_cancel_grace_secs() -> u64 {
     2
 }
 
+fn default_execution_order_sweep_interval_ms() -> u64 {
+    500
+}
+
+fn default_execution_ladder_max_age_secs() -> u64 {
+    120
+}
+
+fn default_execution_ladder_cancel_grace_ms() -> u64 {
+    250
+}
+
+fn default_execution_fill_check_delay_ms() -> u64 {
+    1_000
+}
+
+fn default_execution_degraded_book_stale_ms() -> u64 {
+    15_000
+}
+
 fn default_execution_cancel_before_replace() -> bool {
     true
 }
@@ -562,10 +1393,24 @@ fn default_execution_close_fraction() -> f64 {
     1.0
 }
 
+const STRATEGY_OVERRIDE_HEADER: &str = "/**
+ * @purpose
+ * Dynamic strategy parameters managed by the meta-brain optimizer.
+ *
+ * @dependencies
+ * - None
+ *
+ * @notes
+ * - Values override config/config.json and hot-reload at runtime.
+ */
+";
+
+#[derive(Clone)]
 pub struct ConfigManager {
     path: PathBuf,
     strategies_path: Option<PathBuf>,
     state: Arc<ArcSwap<Config>>,
+    overrides: Arc<Mutex<Vec<(String, Value)>>>,
 }
 
 impl ConfigManager {
@@ -584,6 +1429,7 @@ impl ConfigManager {
             path,
             strategies_path,
             state: Arc::new(ArcSwap::from_pointee(config)),
+            overrides: Arc::new(Mutex::new(Vec::new())),
         })
     }
 
@@ -595,13 +1441,74 @@ impl ConfigManager {
         Arc::clone(&self.state)
     }
 
+    /// Atomically rewrites the strategy override file with a bumped version and publishes
+    /// the new strategy immediately. Returns the new version.
+    pub fn write_strategy_override(&self, strategy: &StrategyConfig) -> Result<u64> {
+        validate_strategy_config(strategy)?;
+        let path = self.strategies_path.as_deref().ok_or_else(|| {
+            BankaiError::InvalidArgument("strategy override path not configured".to_string())
+        })?;
+        let version = if path.exists() {
+            load_strategy_override_version(path)?.unwrap_or(0) + 1
+        } else {
+            1
+        };
+        let payload = serde_json::json!({
+            "version": version,
+            "updated_at": chrono::Utc::now().to_rfc3339(),
+            "strategy": strategy,
+        });
+        let serialized = format!(
+            "{STRATEGY_OVERRIDE_HEADER}{}\n",
+            serde_json::to_string_pretty(&payload)?
+        );
+        let mut tmp_name = path.file_name().unwrap_or_default().to_os_string();
+        tmp_name.push(".tmp");
+        let tmp_path = path.with_file_name(tmp_name);
+        fs::write(&tmp_path, serialized)?;
+        fs::rename(&tmp_path, path)?;
+
+        let mut config = (*self.state.load_full()).clone();
+        config.strategy = strategy.clone();
+        self.state.store(Arc::new(config));
+        Ok(version)
+    }
+
+    /// Sets a runtime override for a dotted config path (e.g. `execution.no_money_mode`) and
+    /// publishes the result. Overrides are not written to disk but survive file reloads.
+    pub fn set_override(&self, key: &str, value: Value) -> Result<Arc<Config>> {
+        self.set_overrides(vec![(key.to_string(), value)])
+    }
+
+    /// Applies several dotted-path overrides at once; none are kept if the result is invalid.
+    pub fn set_overrides(&self, patch: Vec<(String, Value)>) -> Result<Arc<Config>> {
+        let mut overrides = self
+            .overrides
+            .lock()
+            .map_err(|_| BankaiError::InvalidArgument("config overrides poisoned".to_string()))?;
+        let mut next = overrides.clone();
+        for (key, value) in patch {
+            next.retain(|(existing, _)| *existing != key);
+            next.push((key, value));
+        }
+        let config = Arc::new(load_config_with_overrides(
+            &self.path,
+            self.strategies_path.as_deref(),
+            &next,
+        )?);
+        *overrides = next;
+        self.state.store(Arc::clone(&config));
+        Ok(config)
+    }
+
     pub fn spawn_watcher(&self) -> Result<tokio::task::JoinHandle<()>> {
         let path = self.path.clone();
         let strategies_path = self.strategies_path.clone();
         let state = Arc::clone(&self.state);
+        let overrides = Arc::clone(&self.overrides);
 
         Ok(tokio::task::spawn_blocking(move || {
-            if let Err(error) = watch_loop(&path, strategies_path.as_deref(), state) {
+            if let Err(error) = watch_loop(&path, strategies_path.as_deref(), state, overrides) {
                 tracing::error!(?error, "config watcher exited");
             }
         }))
@@ -629,6 +1536,57 @@ fn load_config_with_strategy_override(
     Ok(config)
 }
 
+/// Loads config the same way ConfigManager does, then applies dotted-path overrides
+/// (e.g. `execution.model_v2_k`) before deserializing. Used by offline tooling.
+pub fn load_config_with_overrides(
+    path: &Path,
+    strategies_path: Option<&Path>,
+    overrides: &[(String, Value)],
+) -> Result<Config> {
+    let raw = fs::read_to_string(path)?;
+    let stripped = strip_jsdoc_header(&raw)?;
+    let mut value: Value = serde_json::from_str(stripped.trim())?;
+    if let Some(strategies_path) = strategies_path {
+        if strategies_path.exists() {
+            let strategy = load_strategy_override(strategies_path)?;
+            set_config_path(&mut value, "strategy", serde_json::to_value(strategy)?)?;
+        }
+    }
+    for (key, override_value) in overrides {
+        set_config_path(&mut value, key, override_value.clone())?;
+    }
+    let config: Config = serde_json::from_value(value)?;
+    validate_strategy_config(&config.strategy)?;
+    validate_market_catalogue(&config.polymarket.markets)?;
+    if let Some(allora) = config.allora_consumer.as_ref() {
+        validate_allora_signature(allora)?;
+    }
+    Ok(config)
+}
+
+fn set_config_path(root: &mut Value, key: &str, new_value: Value) -> Result<()> {
+    let parts: Vec<&str> = key.split('.').filter(|part| !part.is_empty()).collect();
+    let Some((last, parents)) = parts.split_last() else {
+        return Err(BankaiError::InvalidArgument(format!(
+            "invalid config override key: {key}"
+        )));
+    };
+    let mut current = root;
+    for part in parents {
+        let object = current.as_object_mut().ok_or_else(|| {
+            BankaiError::InvalidArgument(format!("config override path not an object: {key}"))
+        })?;
+        current = object
+            .entry(part.to_string())
+            .or_insert_with(|| Value::Object(Default::default()));
+    }
+    let object = current.as_object_mut().ok_or_else(|| {
+        BankaiError::InvalidArgument(format!("config override path not an object: {key}"))
+    })?;
+    object.insert(last.to_string(), new_value);
+    Ok(())
+}
+
 fn load_strategy_override(path: &Path) -> Result<StrategyConfig> {
     let raw = fs::read_to_string(path)?;
     let stripped = strip_jsdoc_header(&raw)?;
@@ -642,6 +1600,16 @@ fn load_strategy_override(path: &Path) -> Result<StrategyConfig> {
     Ok(strategy)
 }
 
+fn load_strategy_override_version(path: &Path) -> Result<Option<u64>> {
+    let raw = fs::read_to_string(path)?;
+    let stripped = strip_jsdoc_header(&raw)?;
+    let parsed: StrategyOverrideFile = serde_json::from_str(stripped.trim())?;
+    Ok(match parsed {
+        StrategyOverrideFile::Wrapped(wrapper) => wrapper.version,
+        StrategyOverrideFile::Direct(_) => None,
+    })
+}
+
 fn validate_strategy_config(strategy: &StrategyConfig) -> Result<()> {
     if !(0.0..=1.0).contains(&strategy.kelly_fraction) {
         return Err(BankaiError::InvalidArgument(
@@ -661,10 +1629,67 @@ fn validate_strategy_config(strategy: &StrategyConfig) -> Result<()> {
     Ok(())
 }
 
+fn validate_market_catalogue(markets: &[MarketCatalogueEntry]) -> Result<()> {
+    let mut seen = HashSet::new();
+    for entry in markets {
+        let asset = entry.asset.trim().to_ascii_uppercase();
+        if asset.is_empty() {
+            return Err(BankaiError::InvalidArgument(
+                "polymarket.markets asset must be non-empty".to_string(),
+            ));
+        }
+        if !seen.insert(asset.clone()) {
+            return Err(BankaiError::InvalidArgument(format!(
+                "polymarket.markets has duplicate asset {asset}"
+            )));
+        }
+        if entry.window_secs == 0 {
+            return Err(BankaiError::InvalidArgument(format!(
+                "polymarket.markets {asset} window_secs must be positive"
+            )));
+        }
+        if entry.signal_timeframe_ms().is_none() {
+            return Err(BankaiError::InvalidArgument(format!(
+                "polymarket.markets {asset} has invalid signal_timeframe {}",
+                entry.signal_timeframe
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn validate_allora_signature(allora: &AlloraConsumerConfig) -> Result<()> {
+    let signature = &allora.signature;
+    if signature.mode == AlloraSignatureMode::Off {
+        return Ok(());
+    }
+    if signature
+        .signers
+        .iter()
+        .all(|signer| signer.trim().is_empty())
+    {
+        return Err(BankaiError::InvalidArgument(
+            "allora_consumer.signature.signers required when verification is enabled".to_string(),
+        ));
+    }
+    if signature
+        .consumer_contract
+        .as_deref()
+        .is_none_or(|contract| contract.trim().is_empty())
+    {
+        return Err(BankaiError::InvalidArgument(
+            "allora_consumer.signature.consumer_contract required when verification is enabled"
+                .to_string(),
+        ));
+    }
+    Ok(())
+}
+
 fn watch_loop(
     path: &Path,
     strategies_path: Option<&Path>,
     state: Arc<ArcSwap<Config>>,
+    overrides: Arc<Mutex<Vec<(String, Value)>>>,
 ) -> Result<()> {
     let (tx, rx) = mpsc::channel();
     let mut watcher = notify::recommended_watcher(tx)?;
@@ -682,7 +1707,16 @@ fn watch_loop(
                 if !is_relevant_event(&event.kind) {
                     continue;
                 }
-                match load_config_with_strategy_override(path, strategies_path) {
+                let overrides = overrides
+                    .lock()
+                    .map(|overrides| overrides.clone())
+                    .unwrap_or_default();
+                let loaded = if overrides.is_empty() {
+                    load_config_with_strategy_override(path, strategies_path)
+                } else {
+                    load_config_with_overrides(path, strategies_path, &overrides)
+                };
+                match loaded {
                     Ok(config) => {
                         state.store(Arc::new(config));
                         tracing::info!("config reloaded");
@@ -699,7 +1733,7 @@ fn watch_loop(
     }
 }
 
-fn is_relevant_event(kind: &EventKind) -> bool {
+pub(crate) fn is_relevant_event(kind: &EventKind) -> bool {
     matches!(kind, EventKind::Modify(_) | EventKind::Create(_))
 }
 
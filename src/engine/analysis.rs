@@ -139,6 +139,20 @@ pub fn true_probability_from_allora(value: f64) -> Result<f64> {
     validate_probability(value, "allora_inference")
 }
 
+/// Full Kelly fraction for a binary payout at decimal `odds`, clamped to 0..1.
+pub fn calculate_kelly(win_prob: f64, odds: f64) -> f64 {
+    if win_prob <= 0.0 || win_prob >= 1.0 {
+        return 0.0;
+    }
+    if odds <= 1.0 {
+        return 0.0;
+    }
+    let payout = odds - 1.0;
+    let loss_prob = 1.0 - win_prob;
+    let kelly = (win_prob * payout - loss_prob) / payout;
+    kelly.clamp(0.0, 1.0)
+}
+
 pub fn snipe_threshold_bps(strategy: &StrategyConfig, fees: &FeeConfig) -> f64 {
     let fee_guard = fees.taker_fee_bps + fees.estimated_gas_bps + SNIPE_EDGE_BUFFER_BPS;
     strategy.snipe_min_edge_bps.max(fee_guard)
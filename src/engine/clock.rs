@@ -0,0 +1,52 @@
+/**
+ * @description
+ * Time source abstraction so engine logic can run against wall-clock or replayed time.
+ *
+ * @dependencies
+ * - std: SystemTime and atomics
+ *
+ * @notes
+ * - Live components use SystemClock; the backtester drives a SimulatedClock.
+ */
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::error::Result;
+
+pub trait Clock: Send + Sync {
+    fn now_ms(&self) -> Result<u64>;
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now_ms(&self) -> Result<u64> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
+        Ok(now.as_millis() as u64)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct SimulatedClock {
+    now_ms: AtomicU64,
+}
+
+impl SimulatedClock {
+    pub fn new(start_ms: u64) -> Self {
+        Self {
+            now_ms: AtomicU64::new(start_ms),
+        }
+    }
+
+    /// Moves the clock forward; timestamps earlier than the current time are ignored.
+    pub fn advance_to(&self, now_ms: u64) {
+        self.now_ms.fetch_max(now_ms, Ordering::SeqCst);
+    }
+}
+
+impl Clock for SimulatedClock {
+    fn now_ms(&self) -> Result<u64> {
+        Ok(self.now_ms.load(Ordering::SeqCst))
+    }
+}
@@ -0,0 +1,307 @@
+/**
+ * @description
+ * Operator control plane: applies commands issued from the TUI on the async runtime.
+ *
+ * @dependencies
+ * - engine::risk: manual halt/resume (resume never clears kill-switch halts)
+ * - config::ConfigManager: runtime mode toggles
+ * - execution::clob: account-wide order cancellation
+ *
+ * @notes
+ * - The UI thread only sends `ControlCommand`s; all I/O happens here.
+ * - Toggles are for interactive keys; remote callers use the `Set*` commands so a retried
+ *   request cannot flip the mode back.
+ * - Flatten sends Sell intents straight to the orchestrator, so it works while halted
+ *   (halts gate the trader, not execution); `enable_trading` still applies.
+ * - Every outcome is written to the activity log so the operator sees it in the TUI.
+ */
+use serde_json::Value;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+use crate::config::ConfigManager;
+use crate::engine::risk::RiskState;
+use crate::engine::types::{TradeIntent, TradeMode, TradeSide};
+use crate::error::{BankaiError, Result};
+use crate::execution::clob::ClobClient;
+use crate::storage::orderbook::OrderBookStore;
+use crate::storage::redis::RedisManager;
+
+const ACTIVITY_LOG_LIMIT: usize = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ControlCommand {
+    Halt,
+    Resume,
+    ToggleNoMoney,
+    ToggleTrading,
+    SetNoMoney(bool),
+    SetTrading(bool),
+    CancelAll,
+    Flatten,
+}
+
+impl ControlCommand {
+    pub fn label(self) -> &'static str {
+        match self {
+            ControlCommand::Halt => "halt trading",
+            ControlCommand::Resume => "resume trading",
+            ControlCommand::ToggleNoMoney => "toggle no-money mode",
+            ControlCommand::ToggleTrading => "toggle enable_trading",
+            ControlCommand::SetNoMoney(true) => "enable no-money mode",
+            ControlCommand::SetNoMoney(false) => "disable no-money mode",
+            ControlCommand::SetTrading(true) => "enable trading",
+            ControlCommand::SetTrading(false) => "disable trading",
+            ControlCommand::CancelAll => "cancel all open orders",
+            ControlCommand::Flatten => "flatten tracked positions",
+        }
+    }
+}
+
+struct FlattenContext {
+    redis: RedisManager,
+    orderbook: OrderBookStore,
+    intent_tx: mpsc::Sender<TradeIntent>,
+    wallet_key: String,
+}
+
+pub struct ControlPlane {
+    risk: Arc<RiskState>,
+    config_manager: ConfigManager,
+    activity_redis: Option<RedisManager>,
+    cancel_client: Option<ClobClient>,
+    flatten: Option<FlattenContext>,
+}
+
+impl ControlPlane {
+    pub fn new(risk: Arc<RiskState>, config_manager: ConfigManager) -> Self {
+        Self {
+            risk,
+            config_manager,
+            activity_redis: None,
+            cancel_client: None,
+            flatten: None,
+        }
+    }
+
+    pub fn with_activity_log(mut self, redis: RedisManager) -> Self {
+        self.activity_redis = Some(redis);
+        self
+    }
+
+    pub fn with_cancel_client(mut self, cancel_client: ClobClient) -> Self {
+        self.cancel_client = Some(cancel_client);
+        self
+    }
+
+    pub fn with_flatten(
+        mut self,
+        redis: RedisManager,
+        orderbook: OrderBookStore,
+        intent_tx: mpsc::Sender<TradeIntent>,
+        wallet_key: String,
+    ) -> Self {
+        self.flatten = Some(FlattenContext {
+            redis,
+            orderbook,
+            intent_tx,
+            wallet_key,
+        });
+        self
+    }
+
+    pub fn spawn(self, mut receiver: mpsc::UnboundedReceiver<ControlCommand>) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            while let Some(command) = receiver.recv().await {
+                let message = match self.apply(command).await {
+                    Ok(detail) => format!("[CONTROL] {}: {detail}", command.label()),
+                    Err(error) => {
+                        tracing::warn!(?error, ?command, "control command failed");
+                        format!("[CONTROL] {} failed: {error}", command.label())
+                    }
+                };
+                tracing::info!(?command, %message, "control command handled");
+                if let Some(redis) = self.activity_redis.as_ref() {
+                    let _ = redis.push_activity_log(&message, ACTIVITY_LOG_LIMIT).await;
+                }
+            }
+        })
+    }
+
+    async fn apply(&self, command: ControlCommand) -> Result<String> {
+        match command {
+            ControlCommand::Halt => {
+                self.risk.manual_halt();
+                Ok("halted".to_string())
+            }
+            ControlCommand::Resume => match self.risk.clear_manual_halt() {
+                Ok(()) => Ok("resumed".to_string()),
+                Err(reason) => Err(BankaiError::InvalidArgument(format!(
+                    "kill switch halt ({}) clears on recovery, not resume",
+                    reason.metric_label()
+                ))),
+            },
+            ControlCommand::ToggleNoMoney => {
+                let next = !self.config_manager.current().execution.no_money_mode;
+                self.set_no_money(next)
+            }
+            ControlCommand::ToggleTrading => {
+                let next = !self.config_manager.current().execution.enable_trading;
+                self.set_trading(next)
+            }
+            ControlCommand::SetNoMoney(enabled) => self.set_no_money(enabled),
+            ControlCommand::SetTrading(enabled) => self.set_trading(enabled),
+            ControlCommand::CancelAll => {
+                let client = self.cancel_client.as_ref().ok_or_else(|| {
+                    BankaiError::InvalidArgument("cancel client unavailable".to_string())
+                })?;
+                let response = client.cancel_all().await?;
+                Ok(format!("canceled={}", response.canceled.len()))
+            }
+            ControlCommand::Flatten => self.flatten_positions().await,
+        }
+    }
+
+    fn set_no_money(&self, enabled: bool) -> Result<String> {
+        self.config_manager
+            .set_override("execution.no_money_mode", Value::Bool(enabled))?;
+        Ok(format!("no_money_mode={enabled}"))
+    }
+
+    fn set_trading(&self, enabled: bool) -> Result<String> {
+        self.config_manager
+            .set_override("execution.enable_trading", Value::Bool(enabled))?;
+        Ok(format!("enable_trading={enabled}"))
+    }
+
+    async fn flatten_positions(&self) -> Result<String> {
+        let context = self.flatten.as_ref().ok_or_else(|| {
+            BankaiError::InvalidArgument("execution pipeline unavailable".to_string())
+        })?;
+        if self.config_manager.current().execution.no_money_mode {
+            return Err(BankaiError::InvalidArgument(
+                "no-money mode has no live positions".to_string(),
+            ));
+        }
+        let positions = context
+            .redis
+            .get_tracked_positions(&context.wallet_key)
+            .await?;
+        let spread_offset_bps = self.config_manager.current().strategy.spread_offset_bps;
+        let now = now_ms()?;
+        let mut sent = 0usize;
+        let mut skipped = 0usize;
+        for (token_id, size) in positions {
+            if size <= 0.0 {
+                continue;
+            }
+            let Some(market_id) = context.redis.get_token_market(&token_id).await? else {
+                skipped += 1;
+                continue;
+            };
+            let price = match context.orderbook.best_bid_ask(&token_id).await? {
+                Some((bid, _)) => Some(bid),
+                None => context.orderbook.mid_price(&token_id).await?,
+            };
+            let Some(price) = price.filter(|price| *price > 0.0) else {
+                skipped += 1;
+                continue;
+            };
+            let intent = TradeIntent {
+                market_id,
+                asset_id: token_id,
+                side: TradeSide::Sell,
+                mode: TradeMode::Snipe,
+                implied_prob: price,
+                true_prob: price,
+                edge: 0.0,
+                edge_bps: 0.0,
+                spread_offset_bps,
+                timestamp_ms: now,
+                market_window: None,
+                requested_size: Some(size),
+            };
+            context
+                .intent_tx
+                .send(intent)
+                .await
+                .map_err(|_| BankaiError::InvalidArgument("intent channel closed".to_string()))?;
+            sent += 1;
+        }
+        Ok(format!("sell_intents={sent} skipped={skipped}"))
+    }
+}
+
+fn now_ms() -> Result<u64> {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
+    Ok(now.as_millis() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::risk::{HaltReason, KillSwitchConfig};
+
+    fn control_plane() -> (ControlPlane, Arc<RiskState>) {
+        let config_manager = ConfigManager::new("config/config.json").expect("config");
+        let risk = Arc::new(RiskState::new(KillSwitchConfig::from_trading(
+            &config_manager.current().trading,
+        )));
+        (ControlPlane::new(risk.clone(), config_manager), risk)
+    }
+
+    fn block_on<F: std::future::Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .expect("runtime")
+            .block_on(future)
+    }
+
+    #[test]
+    fn halt_and_resume_only_clear_manual_halts() {
+        let (control, risk) = control_plane();
+        block_on(control.apply(ControlCommand::Halt)).expect("halt");
+        assert_eq!(risk.halt_reason(), HaltReason::Manual);
+        block_on(control.apply(ControlCommand::Resume)).expect("resume");
+        assert!(!risk.is_halted());
+
+        risk.record_clock_drift_ms(i64::MAX);
+        assert!(block_on(control.apply(ControlCommand::Resume)).is_err());
+        assert_eq!(risk.halt_reason(), HaltReason::ClockDrift);
+    }
+
+    #[test]
+    fn toggles_flip_runtime_overrides() {
+        let (control, _) = control_plane();
+        let before = control.config_manager.current().execution.clone();
+        block_on(control.apply(ControlCommand::ToggleNoMoney)).expect("toggle no-money");
+        block_on(control.apply(ControlCommand::ToggleTrading)).expect("toggle trading");
+        let after = control.config_manager.current();
+        assert_eq!(after.execution.no_money_mode, !before.no_money_mode);
+        assert_eq!(after.execution.enable_trading, !before.enable_trading);
+    }
+
+    #[test]
+    fn set_commands_are_idempotent() {
+        let (control, _) = control_plane();
+        for _ in 0..2 {
+            block_on(control.apply(ControlCommand::SetNoMoney(true))).expect("set no-money");
+            block_on(control.apply(ControlCommand::SetTrading(false))).expect("set trading");
+            let current = control.config_manager.current();
+            assert!(current.execution.no_money_mode);
+            assert!(!current.execution.enable_trading);
+        }
+    }
+
+    #[test]
+    fn flatten_and_cancel_need_their_clients() {
+        let (control, _) = control_plane();
+        let flatten = block_on(control.apply(ControlCommand::Flatten)).unwrap_err();
+        assert!(flatten
+            .to_string()
+            .contains("execution pipeline unavailable"));
+        assert!(block_on(control.apply(ControlCommand::CancelAll)).is_err());
+    }
+}
@@ -8,6 +8,7 @@
  * @notes
  * - Volatility halts are enforced when signals are neutral or missing.
  * - Uses RiskState to respect kill switch conditions.
+ * - Compares Chainlink with Binance per asset via SourceGuard and publishes source status.
  */
 use arc_swap::ArcSwap;
 use std::collections::HashMap;
@@ -18,22 +19,34 @@ use tokio::sync::broadcast;
 
 use crate::config::Config;
 use crate::engine::risk::RiskState;
+use crate::engine::source_guard::{
+    PriceSource, SourceGuard, SourceGuardConfig, SourceStatus, SourceStatusMap,
+};
 use crate::engine::types::{AlloraMarketUpdate, ChainlinkMarketUpdate, MarketUpdate};
 use crate::error::{BankaiError, Result};
 use crate::telemetry::metrics;
 
 const ENGINE_TICK_INTERVAL: Duration = Duration::from_secs(5);
 const NEUTRAL_SIGNAL_THRESHOLD_PCT: f64 = 0.001;
-const DEFAULT_SIGNAL_HORIZON_MS: u64 = 5 * 60 * 1_000;
 
 pub struct EngineCore {
     config: Arc<ArcSwap<Config>>,
     risk: Arc<RiskState>,
+    sources: Arc<SourceStatusMap>,
 }
 
 impl EngineCore {
     pub fn new(config: Arc<ArcSwap<Config>>, risk: Arc<RiskState>) -> Self {
-        Self { config, risk }
+        Self {
+            config,
+            risk,
+            sources: Arc::new(SourceStatusMap::new()),
+        }
+    }
+
+    pub fn with_source_statuses(mut self, sources: Arc<SourceStatusMap>) -> Self {
+        self.sources = sources;
+        self
     }
 
     pub fn spawn(self, receiver: broadcast::Receiver<MarketUpdate>) -> tokio::task::JoinHandle<()> {
@@ -45,12 +58,14 @@ impl EngineCore {
     }
 
     async fn run(self, mut receiver: broadcast::Receiver<MarketUpdate>) -> Result<()> {
-        let mut state = EngineState::new();
+        let mut state = EngineState::new(SourceGuard::new(self.sources.clone(), self.risk.clone()));
         let mut tick = tokio::time::interval(ENGINE_TICK_INTERVAL);
 
         loop {
             tokio::select! {
                 _ = tick.tick() => {
+                    let guard_config = SourceGuardConfig::from_config(&self.config.load_full());
+                    state.guard.refresh(&guard_config, now_ms()?);
                     let snapshot = self.risk.snapshot();
                     if snapshot.halted {
                         tracing::warn!(?snapshot, "engine halted");
@@ -76,6 +91,7 @@ impl EngineCore {
     async fn handle_update(&self, state: &mut EngineState, update: MarketUpdate) -> Result<()> {
         match update {
             MarketUpdate::Chainlink(update) => self.handle_chainlink_update(state, update).await,
+            MarketUpdate::Binance(update) => self.handle_binance_update(state, update).await,
             MarketUpdate::Allora(update) => self.handle_allora_update(state, update).await,
         }
     }
@@ -90,16 +106,51 @@ impl EngineCore {
         state
             .last_chainlink
             .insert(update.asset.clone(), update.clone());
+        state.guard.observe(
+            &SourceGuardConfig::from_config(&config),
+            PriceSource::Chainlink,
+            &update,
+            now_ms()?,
+        );
 
         if self.risk.is_halted() {
             tracing::warn!(asset = %update.asset, "risk halt active; skipping chainlink update");
             return Ok(());
         }
 
+        self.check_volatility(&config, state, &update);
+        Ok(())
+    }
+
+    async fn handle_binance_update(
+        &self,
+        state: &mut EngineState,
+        update: ChainlinkMarketUpdate,
+    ) -> Result<()> {
+        let config = self.config.load_full();
+        let status = state.guard.observe(
+            &SourceGuardConfig::from_config(&config),
+            PriceSource::Binance,
+            &update,
+            now_ms()?,
+        );
+        if status != SourceStatus::Fallback || self.risk.is_halted() {
+            return Ok(());
+        }
+        self.check_volatility(&config, state, &update);
+        Ok(())
+    }
+
+    fn check_volatility(
+        &self,
+        config: &Config,
+        state: &EngineState,
+        update: &ChainlinkMarketUpdate,
+    ) {
         if let Some(volatility) = update.volatility_1m {
             if volatility > config.trading.max_volatility {
                 let is_neutral =
-                    is_neutral_signal(state.last_allora.get(&update.asset), resolve_price(&update));
+                    is_neutral_signal(state.last_allora.get(&update.asset), resolve_price(update));
                 if is_neutral {
                     tracing::warn!(
                         asset = %update.asset,
@@ -110,8 +161,6 @@ impl EngineCore {
                 }
             }
         }
-
-        Ok(())
     }
 
     async fn handle_allora_update(
@@ -119,12 +168,12 @@ impl EngineCore {
         state: &mut EngineState,
         update: AlloraMarketUpdate,
     ) -> Result<()> {
-        if !is_five_min_signal(&update) {
+        let config = self.config.load_full();
+        if !is_trading_signal(&config, &update) {
             return Ok(());
         }
         let mut update = update;
-        let config = self.config.load_full();
-        let horizon_ms = alignment_horizon_ms(&config, &update.asset);
+        let horizon_ms = config.signal_alignment_horizon_ms(&update.asset);
         if let Some(prev) = state.last_allora.get(&update.asset) {
             if update.inference_value == prev.inference_value && prev.signal_timestamp_ms > 0 {
                 let diff = update
@@ -158,37 +207,18 @@ impl EngineCore {
     }
 }
 
-fn alignment_horizon_ms(config: &Config, asset: &str) -> u64 {
-    let base_ms = config
-        .execution
-        .signal_alignment_max_secs
-        .saturating_mul(1000);
-    let sol_ms = config
-        .execution
-        .signal_alignment_max_secs_sol
-        .saturating_mul(1000);
-    let selected = if asset.eq_ignore_ascii_case("SOL") && sol_ms > 0 {
-        sol_ms
-    } else {
-        base_ms
-    };
-    if selected > 0 {
-        selected
-    } else {
-        DEFAULT_SIGNAL_HORIZON_MS
-    }
-}
-
 struct EngineState {
     last_chainlink: HashMap<String, ChainlinkMarketUpdate>,
     last_allora: HashMap<String, AlloraMarketUpdate>,
+    guard: SourceGuard,
 }
 
 impl EngineState {
-    fn new() -> Self {
+    fn new(guard: SourceGuard) -> Self {
         Self {
             last_chainlink: HashMap::new(),
             last_allora: HashMap::new(),
+            guard,
         }
     }
 }
@@ -209,8 +239,11 @@ fn is_neutral_signal(allora: Option<&AlloraMarketUpdate>, price: Option<f64>) ->
     delta.abs() < NEUTRAL_SIGNAL_THRESHOLD_PCT
 }
 
-fn is_five_min_signal(update: &AlloraMarketUpdate) -> bool {
-    update.timeframe.trim().eq_ignore_ascii_case("5m")
+fn is_trading_signal(config: &Config, update: &AlloraMarketUpdate) -> bool {
+    update
+        .timeframe
+        .trim()
+        .eq_ignore_ascii_case(config.polymarket.signal_timeframe(&update.asset))
 }
 
 fn resolve_price(update: &ChainlinkMarketUpdate) -> Option<f64> {
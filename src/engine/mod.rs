@@ -1,5 +1,9 @@
 pub mod analysis;
+pub mod backtest;
+pub mod clock;
+pub mod control;
 pub mod core;
+pub mod models;
 pub mod orders;
 pub mod python_host;
 /**
@@ -13,5 +17,7 @@ pub mod python_host;
  * - Keep module boundaries aligned with docs/implementation_plan.md.
  */
 pub mod risk;
+pub mod signal_fusion;
+pub mod source_guard;
 pub mod trader;
 pub mod types;
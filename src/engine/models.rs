@@ -0,0 +1,589 @@
+/**
+ * @description
+ * Pluggable probability models that turn oracle inputs into a fair UP probability.
+ *
+ * @dependencies
+ * - None (pure math over ExecutionConfig parameters)
+ *
+ * @notes
+ * - `execution.model_version` picks the active model from the registry; unknown names fall back to v1.
+ * - Shadow models see the same inputs but only feed logs and paper scoring.
+ * - The interval model reads P(end > start) off the Allora confidence-interval quantiles,
+ *   interpolated linearly; tails are clamped to the outermost percentiles.
+ */
+use std::sync::Arc;
+
+use crate::config::ExecutionConfig;
+
+pub const SIGNAL_DIR_UP: i8 = 1;
+pub const SIGNAL_DIR_DOWN: i8 = -1;
+
+const SQRT_5: f64 = 2.236_067_977_5;
+const ONE_SIGMA_LOWER: f64 = 0.1587;
+const ONE_SIGMA_UPPER: f64 = 0.8413;
+const DEFAULT_MODEL: &str = "v1";
+
+#[derive(Debug, Clone)]
+pub struct ModelInput {
+    /// Market-implied probability of UP (book mid or gamma fallback).
+    pub implied_up: f64,
+    pub start_price: f64,
+    pub current_price: f64,
+    /// Allora price inference, fused across the asset's timeframes.
+    pub inference: f64,
+    pub volatility_1m: f64,
+    /// 0..1 weight for how well the inference lines up with the window target.
+    pub alignment: f64,
+    /// Predicted price distribution from the signal's confidence intervals, if published.
+    pub distribution: Option<PriceDistribution>,
+}
+
+/// Piecewise-linear CDF of the predicted price through (probability, price) quantiles.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PriceDistribution {
+    quantiles: Vec<(f64, f64)>,
+}
+
+impl PriceDistribution {
+    /// Builds from percentiles (0-100, increasing) and matching non-decreasing prices.
+    pub fn from_intervals(percentiles: &[f64], values: &[f64]) -> Option<Self> {
+        if percentiles.len() < 2 || percentiles.len() != values.len() {
+            return None;
+        }
+        let quantiles: Vec<(f64, f64)> = percentiles
+            .iter()
+            .zip(values)
+            .map(|(percentile, value)| (percentile / 100.0, *value))
+            .collect();
+        let valid = quantiles
+            .iter()
+            .all(|(p, v)| *p > 0.0 && *p < 1.0 && v.is_finite())
+            && quantiles
+                .windows(2)
+                .all(|pair| pair[0].0 < pair[1].0 && pair[0].1 <= pair[1].1)
+            && quantiles.last()?.1 > quantiles.first()?.1;
+        valid.then_some(Self { quantiles })
+    }
+
+    /// Moves every quantile by `delta`, keeping the shape (e.g. to recentre on a fused signal).
+    pub fn shifted(&self, delta: f64) -> Self {
+        Self {
+            quantiles: self
+                .quantiles
+                .iter()
+                .map(|(p, v)| (*p, v + delta))
+                .collect(),
+        }
+    }
+
+    pub fn cdf(&self, price: f64) -> f64 {
+        let (first_p, first_v) = self.quantiles[0];
+        if price < first_v {
+            return first_p;
+        }
+        for pair in self.quantiles.windows(2) {
+            let ((p0, v0), (p1, v1)) = (pair[0], pair[1]);
+            if price < v1 {
+                let t = (price - v0) / (v1 - v0);
+                return p0 + t * (p1 - p0);
+            }
+        }
+        self.quantiles[self.quantiles.len() - 1].0
+    }
+
+    /// Price at cumulative probability `p`, clamped to the outermost quantiles.
+    pub fn quantile(&self, p: f64) -> f64 {
+        let (first_p, first_v) = self.quantiles[0];
+        if p <= first_p {
+            return first_v;
+        }
+        for pair in self.quantiles.windows(2) {
+            let ((p0, v0), (p1, v1)) = (pair[0], pair[1]);
+            if p <= p1 {
+                let t = (p - p0) / (p1 - p0);
+                return v0 + t * (v1 - v0);
+            }
+        }
+        self.quantiles[self.quantiles.len() - 1].1
+    }
+
+    pub fn prob_above(&self, price: f64) -> f64 {
+        1.0 - self.cdf(price)
+    }
+
+    /// One-sigma equivalent: half the 15.87-84.13 percentile range.
+    pub fn sigma(&self) -> f64 {
+        (self.quantile(ONE_SIGMA_UPPER) - self.quantile(ONE_SIGMA_LOWER)) / 2.0
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SignalContext {
+    pub direction: i8,
+    pub confidence: f64,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ModelOutput {
+    pub true_up: f64,
+    pub signal_context: Option<SignalContext>,
+    pub z_score: f64,
+}
+
+pub trait ProbabilityModel: Send + Sync {
+    fn name(&self) -> &str;
+
+    fn compute(&self, input: &ModelInput, execution: &ExecutionConfig) -> Option<ModelOutput>;
+
+    /// Minimum |z| before the model may trade; 0 disables the gate.
+    fn min_abs_z(&self, _execution: &ExecutionConfig) -> f64 {
+        0.0
+    }
+
+    /// Minimum edge per side before an intent is emitted; `None` disables the floor.
+    fn edge_floor_bps(&self, _execution: &ExecutionConfig) -> Option<f64> {
+        None
+    }
+}
+
+/// Start-anchored model: tanh of the z-score around 0.5, capped by `probability_max_offset`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StartAnchoredModel;
+
+impl ProbabilityModel for StartAnchoredModel {
+    fn name(&self) -> &str {
+        "v1"
+    }
+
+    fn compute(&self, input: &ModelInput, execution: &ExecutionConfig) -> Option<ModelOutput> {
+        let z = compute_signal_z_score(
+            input.start_price,
+            input.inference,
+            input.volatility_1m,
+            execution.probability_scale,
+        )
+        .unwrap_or(0.0);
+        Some(ModelOutput {
+            true_up: compute_true_probability_5m(
+                input.start_price,
+                input.inference,
+                input.volatility_1m,
+                execution.probability_scale,
+                execution.probability_max_offset,
+                input.alignment,
+            ),
+            signal_context: compute_signal_context(
+                input.start_price,
+                input.inference,
+                input.volatility_1m,
+                execution.probability_scale,
+                input.alignment,
+            ),
+            z_score: z,
+        })
+    }
+}
+
+/// Market-prior model: Bayesian logit update of the implied probability by the live z-score.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MarketPriorModel;
+
+impl ProbabilityModel for MarketPriorModel {
+    fn name(&self) -> &str {
+        "v2"
+    }
+
+    fn compute(&self, input: &ModelInput, execution: &ExecutionConfig) -> Option<ModelOutput> {
+        let z = compute_signal_z_score(
+            input.current_price,
+            input.inference,
+            input.volatility_1m,
+            execution.probability_scale,
+        )?;
+        Some(ModelOutput {
+            true_up: compute_true_probability_v2(
+                input.implied_up,
+                z,
+                execution.model_v2_k,
+                input.alignment,
+            ),
+            signal_context: compute_signal_context(
+                input.current_price,
+                input.inference,
+                input.volatility_1m,
+                execution.probability_scale,
+                input.alignment,
+            ),
+            z_score: z,
+        })
+    }
+
+    fn min_abs_z(&self, execution: &ExecutionConfig) -> f64 {
+        execution.model_v2_z_min
+    }
+
+    fn edge_floor_bps(&self, execution: &ExecutionConfig) -> Option<f64> {
+        Some(execution.model_v2_edge_floor_bps)
+    }
+}
+
+/// Interval model: P(end > start) straight from the confidence-interval distribution.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IntervalModel;
+
+impl ProbabilityModel for IntervalModel {
+    fn name(&self) -> &str {
+        "interval"
+    }
+
+    fn compute(&self, input: &ModelInput, _execution: &ExecutionConfig) -> Option<ModelOutput> {
+        let distribution = input.distribution.as_ref()?;
+        if input.start_price <= 0.0 {
+            return None;
+        }
+        let p_up = distribution.prob_above(input.start_price);
+        let true_up = 0.5 + (p_up - 0.5) * input.alignment;
+        let sigma = distribution.sigma();
+        let z = if sigma > 0.0 {
+            (distribution.quantile(0.5) - input.start_price) / sigma
+        } else {
+            0.0
+        };
+        Some(ModelOutput {
+            true_up: true_up.clamp(0.01, 0.99),
+            signal_context: Some(compute_interval_signal_context(p_up, input.alignment)),
+            z_score: z,
+        })
+    }
+}
+
+/// Direction from which side of 0.5 P(up) falls; confidence is |2p - 1| scaled by alignment.
+pub fn compute_interval_signal_context(p_up: f64, alignment: f64) -> SignalContext {
+    let direction = if p_up > 0.5 {
+        SIGNAL_DIR_UP
+    } else if p_up < 0.5 {
+        SIGNAL_DIR_DOWN
+    } else {
+        0
+    };
+    SignalContext {
+        direction,
+        confidence: ((2.0 * p_up - 1.0).abs() * alignment).clamp(0.0, 1.0),
+    }
+}
+
+/// Models keyed by their (lowercase) name, in registration order.
+#[derive(Clone)]
+pub struct ModelRegistry {
+    models: Vec<Arc<dyn ProbabilityModel>>,
+}
+
+impl Default for ModelRegistry {
+    fn default() -> Self {
+        Self::empty()
+            .with_model(Arc::new(StartAnchoredModel))
+            .with_model(Arc::new(MarketPriorModel))
+            .with_model(Arc::new(IntervalModel))
+    }
+}
+
+impl ModelRegistry {
+    pub fn empty() -> Self {
+        Self { models: Vec::new() }
+    }
+
+    /// Adds a model, replacing any model already registered under the same name.
+    pub fn with_model(mut self, model: Arc<dyn ProbabilityModel>) -> Self {
+        self.register(model);
+        self
+    }
+
+    pub fn register(&mut self, model: Arc<dyn ProbabilityModel>) {
+        let name = normalize_name(model.name());
+        self.models
+            .retain(|existing| normalize_name(existing.name()) != name);
+        self.models.push(model);
+    }
+
+    pub fn get(&self, name: &str) -> Option<Arc<dyn ProbabilityModel>> {
+        let name = normalize_name(name);
+        self.models
+            .iter()
+            .find(|model| normalize_name(model.name()) == name)
+            .cloned()
+    }
+
+    pub fn names(&self) -> Vec<String> {
+        self.models
+            .iter()
+            .map(|model| normalize_name(model.name()))
+            .collect()
+    }
+
+    /// The model selected by `execution.model_version`, falling back to v1.
+    pub fn active(&self, execution: &ExecutionConfig) -> Option<Arc<dyn ProbabilityModel>> {
+        self.get(&execution.model_version)
+            .or_else(|| self.get(DEFAULT_MODEL))
+            .or_else(|| self.models.first().cloned())
+    }
+
+    /// Shadow models for the current config: the listed `model_shadow_versions`, or every
+    /// other registered model when the list is empty. Never includes the active model.
+    pub fn shadows(&self, execution: &ExecutionConfig) -> Vec<Arc<dyn ProbabilityModel>> {
+        if !execution.model_shadow_mode {
+            return Vec::new();
+        }
+        let active = self
+            .active(execution)
+            .map(|model| normalize_name(model.name()));
+        let is_shadow = |name: &str| active.as_deref() != Some(name);
+        if execution.model_shadow_versions.is_empty() {
+            return self
+                .models
+                .iter()
+                .filter(|model| is_shadow(&normalize_name(model.name())))
+                .cloned()
+                .collect();
+        }
+        let mut shadows: Vec<Arc<dyn ProbabilityModel>> = Vec::new();
+        for name in &execution.model_shadow_versions {
+            let Some(model) = self.get(name) else {
+                continue;
+            };
+            let name = normalize_name(model.name());
+            let duplicate = shadows
+                .iter()
+                .any(|existing| normalize_name(existing.name()) == name);
+            if is_shadow(&name) && !duplicate {
+                shadows.push(model);
+            }
+        }
+        shadows
+    }
+}
+
+fn normalize_name(name: &str) -> String {
+    name.trim().to_ascii_lowercase()
+}
+
+pub fn compute_signal_context(
+    current_price: f64,
+    predicted_price: f64,
+    volatility_1m: f64,
+    scale: f64,
+    alignment: f64,
+) -> Option<SignalContext> {
+    let z_scaled = compute_signal_z_score(current_price, predicted_price, volatility_1m, scale)?;
+    let confidence = z_scaled.abs().tanh() * alignment;
+    let direction = if z_scaled > 0.0 {
+        SIGNAL_DIR_UP
+    } else if z_scaled < 0.0 {
+        SIGNAL_DIR_DOWN
+    } else {
+        0
+    };
+    Some(SignalContext {
+        direction,
+        confidence: confidence.clamp(0.0, 1.0),
+    })
+}
+
+pub fn compute_signal_z_score(
+    current_price: f64,
+    predicted_price: f64,
+    volatility_1m: f64,
+    scale: f64,
+) -> Option<f64> {
+    if current_price <= 0.0 {
+        return None;
+    }
+    let delta = (predicted_price - current_price) / current_price;
+    let volatility_5m = (volatility_1m * SQRT_5).max(1e-9);
+    let z = delta / volatility_5m;
+    Some(z * scale)
+}
+
+pub fn compute_true_probability_5m(
+    current_price: f64,
+    predicted_price: f64,
+    volatility_1m: f64,
+    scale: f64,
+    max_offset: f64,
+    alignment: f64,
+) -> f64 {
+    if current_price <= 0.0 {
+        return 0.5;
+    }
+    let delta = (predicted_price - current_price) / current_price;
+    let volatility_5m = (volatility_1m * SQRT_5).max(1e-9);
+    let z = delta / volatility_5m;
+    let offset = (z * scale).tanh() * max_offset * alignment;
+    (0.5 + offset).clamp(0.01, 0.99)
+}
+
+pub fn compute_true_probability_v2(
+    market_up_prob: f64,
+    z_score: f64,
+    k: f64,
+    alignment: f64,
+) -> f64 {
+    let prior = clamp_probability(market_up_prob);
+    let update = z_score * k * alignment;
+    let posterior_logit = logit(prior) + update;
+    sigmoid(posterior_logit).clamp(0.01, 0.99)
+}
+
+fn clamp_probability(value: f64) -> f64 {
+    value.clamp(0.01, 0.99)
+}
+
+fn logit(value: f64) -> f64 {
+    let p = clamp_probability(value);
+    (p / (1.0 - p)).ln()
+}
+
+fn sigmoid(value: f64) -> f64 {
+    if value >= 0.0 {
+        let exp = (-value).exp();
+        1.0 / (1.0 + exp)
+    } else {
+        let exp = value.exp();
+        exp / (1.0 + exp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registry_resolves_active_and_defaults_to_v1() {
+        let registry = ModelRegistry::default();
+        let mut execution = ExecutionConfig {
+            model_version: "unknown".to_string(),
+            ..Default::default()
+        };
+        let active = registry.active(&execution).expect("active model");
+        assert_eq!(active.name(), "v1");
+        execution.model_version = " V2 ".to_string();
+        let active = registry.active(&execution).expect("active model");
+        assert_eq!(active.name(), "v2");
+    }
+
+    #[test]
+    fn registry_shadows_exclude_active_model() {
+        let registry = ModelRegistry::default();
+        let mut execution = ExecutionConfig {
+            model_version: "v2".to_string(),
+            model_shadow_mode: true,
+            ..Default::default()
+        };
+        let names: Vec<String> = registry
+            .shadows(&execution)
+            .iter()
+            .map(|model| model.name().to_string())
+            .collect();
+        assert_eq!(names, vec!["v1".to_string(), "interval".to_string()]);
+
+        execution.model_shadow_versions = vec!["v2".into(), "v1".into(), "v9".into()];
+        assert_eq!(registry.shadows(&execution).len(), 1);
+
+        execution.model_shadow_mode = false;
+        assert!(registry.shadows(&execution).is_empty());
+    }
+
+    #[test]
+    fn v2_probability_moves_with_signal_direction() {
+        let up = compute_true_probability_v2(0.55, 1.2, 0.5, 1.0);
+        let down = compute_true_probability_v2(0.55, -1.2, 0.5, 1.0);
+        assert!(up > 0.55);
+        assert!(down < 0.55);
+    }
+
+    #[test]
+    fn v2_alignment_scales_adjustment() {
+        let full_align = compute_true_probability_v2(0.60, 1.0, 0.7, 1.0);
+        let weak_align = compute_true_probability_v2(0.60, 1.0, 0.7, 0.2);
+        assert!(full_align > weak_align);
+        assert!(weak_align > 0.60);
+    }
+
+    fn normal_shape(mean: f64, sigma: f64) -> PriceDistribution {
+        PriceDistribution::from_intervals(
+            &[2.28, 15.87, 50.0, 84.13, 97.72],
+            &[
+                mean - 2.0 * sigma,
+                mean - sigma,
+                mean,
+                mean + sigma,
+                mean + 2.0 * sigma,
+            ],
+        )
+        .expect("distribution")
+    }
+
+    #[test]
+    fn interval_distribution_matches_normal_quantiles() {
+        let dist = normal_shape(100.0, 1.0);
+        assert!((dist.prob_above(100.0) - 0.5).abs() < 1e-9);
+        assert!((dist.prob_above(101.0) - 0.1587).abs() < 1e-9);
+        assert!((dist.prob_above(98.0) - 0.9772).abs() < 1e-9);
+        // Tails clamp to the outermost percentiles.
+        assert!((dist.prob_above(90.0) - 0.9772).abs() < 1e-9);
+        assert!((dist.prob_above(110.0) - 0.0228).abs() < 1e-9);
+        assert!((dist.sigma() - 1.0).abs() < 1e-9);
+        let shifted = dist.shifted(2.0);
+        assert!((shifted.prob_above(102.0) - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn interval_distribution_handles_skew_and_rejects_bad_shapes() {
+        // Long upper tail: the median sits below the midpoint of the range.
+        let skewed = PriceDistribution::from_intervals(
+            &[2.28, 15.87, 50.0, 84.13, 97.72],
+            &[98.0, 99.0, 100.0, 104.0, 110.0],
+        )
+        .expect("distribution");
+        assert!((skewed.prob_above(102.0) - (1.0 - (0.5 + 0.5 * 0.3413))).abs() < 1e-9);
+        assert!(skewed.prob_above(100.5) > 0.4);
+
+        assert!(PriceDistribution::from_intervals(&[50.0], &[100.0]).is_none());
+        assert!(PriceDistribution::from_intervals(&[15.87, 84.13], &[101.0, 99.0]).is_none());
+        assert!(PriceDistribution::from_intervals(&[15.87, 84.13], &[100.0, 100.0]).is_none());
+    }
+
+    #[test]
+    fn interval_model_reads_probability_from_distribution() {
+        let execution = ExecutionConfig::default();
+        let mut input = ModelInput {
+            implied_up: 0.5,
+            start_price: 99.0,
+            current_price: 100.0,
+            inference: 100.0,
+            volatility_1m: 0.001,
+            alignment: 1.0,
+            distribution: Some(normal_shape(100.0, 1.0)),
+        };
+        let output = IntervalModel.compute(&input, &execution).expect("output");
+        assert!((output.true_up - 0.8413).abs() < 1e-9);
+        let signal = output.signal_context.expect("signal");
+        assert_eq!(signal.direction, SIGNAL_DIR_UP);
+        assert!((signal.confidence - 0.6826).abs() < 1e-9);
+        assert!((output.z_score - 1.0).abs() < 1e-9);
+
+        input.alignment = 0.5;
+        let output = IntervalModel.compute(&input, &execution).expect("output");
+        assert!((output.true_up - (0.5 + 0.3413 * 0.5)).abs() < 1e-9);
+
+        input.distribution = None;
+        assert!(IntervalModel.compute(&input, &execution).is_none());
+    }
+
+    #[test]
+    fn v2_uses_market_prior() {
+        let z = 0.2;
+        let low_prior = compute_true_probability_v2(0.35, z, 0.5, 1.0);
+        let high_prior = compute_true_probability_v2(0.75, z, 0.5, 1.0);
+        assert!(high_prior > low_prior);
+    }
+}
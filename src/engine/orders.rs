@@ -11,6 +11,7 @@
  */
 use std::collections::HashMap;
 
+use crate::config::ExecutionConfig;
 use crate::engine::types::TradeMode;
 use crate::error::{BankaiError, Result};
 
@@ -23,6 +24,15 @@ pub struct OrderLifecycleConfig {
     pub fill_check_delay_ms: u64,
 }
 
+impl OrderLifecycleConfig {
+    pub fn from_execution(execution: &ExecutionConfig) -> Self {
+        Self {
+            ladder_cancel_grace_ms: execution.ladder_cancel_grace_ms,
+            fill_check_delay_ms: execution.fill_check_delay_ms,
+        }
+    }
+}
+
 impl Default for OrderLifecycleConfig {
     fn default() -> Self {
         Self {
@@ -235,6 +245,19 @@ impl OrderLifecycleManager {
         }
     }
 
+    /// Return a cancel that could not be executed to the active set so the next sweep retries it.
+    pub fn release_pending_cancel(&mut self, order_id: &str, now_ms: u64) {
+        if let Some(order) = self.orders.get_mut(order_id) {
+            if matches!(
+                order.status,
+                OrderStatus::PendingCancel | OrderStatus::Expired
+            ) {
+                order.status = OrderStatus::Active;
+                order.last_update_ms = now_ms;
+            }
+        }
+    }
+
     /// Mark an order as rejected.
     pub fn mark_rejected(&mut self, order_id: &str, now_ms: u64) {
         if let Some(order) = self.orders.get_mut(order_id) {
@@ -304,6 +327,11 @@ impl OrderLifecycleManager {
         });
     }
 
+    /// Whether the order is currently tracked.
+    pub fn is_tracked(&self, order_id: &str) -> bool {
+        self.orders.contains_key(order_id)
+    }
+
     /// Get a snapshot of all tracked orders.
     pub fn snapshot(&self) -> Vec<TrackedOrder> {
         self.orders.values().cloned().collect()
@@ -4,18 +4,39 @@
  *
  * @dependencies
  * - pyo3: Python bindings and GIL management
+ * - tokio: oneshot replies and per-call timeouts for the live backend
  *
  * @notes
  * - Ensure `python/` is on sys.path before importing strategy modules.
+ * - The live backend owns the interpreter on a dedicated OS thread so the GIL is never
+ *   taken on a tokio worker; callers fall back to Rust when a call errors or times out.
+ * - Hot reload executes the edited file into a fresh module object and smoke-tests it; the
+ *   running module is only swapped out if every check passes.
  */
+use notify::{RecursiveMode, Watcher};
 use pyo3::prelude::*;
-use pyo3::types::{PyList, PyModule};
+use pyo3::types::{PyDict, PyList, PyModule};
 use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, RecvTimeoutError, SyncSender, TrySendError};
+use std::time::Duration;
+use tokio::sync::oneshot;
 
+use crate::config::{is_relevant_event, ExecutionConfig, PythonStrategyConfig};
+use crate::engine::models::ModelInput;
 use crate::error::{BankaiError, Result};
 
+const BACKEND_QUEUE_CAPACITY: usize = 4;
+const RELOAD_DEBOUNCE: Duration = Duration::from_millis(250);
+const REQUIRED_FUNCTIONS: [&str; 4] = [
+    "calculate_kelly",
+    "true_probability",
+    "validate_signal",
+    "calculate_staleness",
+];
+
 pub trait StrategyInterface {
     fn calculate_kelly(&self, win_prob: f64, odds: f64) -> Result<f64>;
+    fn true_probability(&self, input: &ModelInput, execution: &ExecutionConfig) -> Result<f64>;
     fn validate_signal(&self, inference_json: &str, current_price: f64) -> Result<bool>;
     fn calculate_staleness(
         &self,
@@ -27,6 +48,7 @@ pub trait StrategyInterface {
 
 pub struct PythonHost {
     module_name: String,
+    module_dir: PathBuf,
     module: Py<PyModule>,
 }
 
@@ -46,6 +68,7 @@ impl PythonHost {
             let module = py.import_bound(module_name)?;
             Ok(Self {
                 module_name: module_name.to_string(),
+                module_dir: module_dir.to_path_buf(),
                 module: module.into(),
             })
         })
@@ -54,6 +77,139 @@ impl PythonHost {
     pub fn module_name(&self) -> &str {
         &self.module_name
     }
+
+    /// Source file backing the module (`a.b` resolves to `<module_dir>/a/b.py`).
+    pub fn module_path(&self) -> PathBuf {
+        module_file(&self.module_dir, &self.module_name)
+    }
+
+    /// Re-imports the module from source and swaps it in only if it passes `validate_module`.
+    /// On failure the previous module stays active and in `sys.modules`.
+    pub fn reload(&mut self) -> Result<()> {
+        let path = self.module_path();
+        let path_str = path
+            .to_str()
+            .ok_or_else(|| BankaiError::InvalidArgument("python path is not utf-8".to_string()))?;
+        Python::with_gil(|py| {
+            let util = py.import_bound("importlib.util")?;
+            let spec = util
+                .getattr("spec_from_file_location")?
+                .call1((self.module_name.as_str(), path_str))?;
+            if spec.is_none() {
+                return Err(BankaiError::InvalidArgument(format!(
+                    "cannot load python module from {path_str}"
+                )));
+            }
+            let fresh = util.getattr("module_from_spec")?.call1((&spec,))?;
+            let modules_any = py.import_bound("sys")?.getattr("modules")?;
+            let modules = modules_any
+                .downcast::<PyDict>()
+                .map_err(|err| BankaiError::Python(err.into()))?;
+            // Decorators such as dataclass resolve their defining module through sys.modules
+            // while it executes, so the fresh module is registered before exec and rolled back
+            // if anything fails.
+            modules.set_item(self.module_name.as_str(), &fresh)?;
+            let loaded = spec
+                .getattr("loader")?
+                .call_method1("exec_module", (&fresh,))
+                .map_err(BankaiError::from)
+                .and_then(|_| {
+                    let fresh = fresh
+                        .downcast::<PyModule>()
+                        .map_err(|err| BankaiError::Python(err.into()))?;
+                    validate_module(fresh)?;
+                    Ok(fresh.clone().unbind())
+                });
+            match loaded {
+                Ok(module) => {
+                    self.module = module;
+                    Ok(())
+                }
+                Err(error) => {
+                    modules.set_item(self.module_name.as_str(), self.module.bind(py))?;
+                    Err(error)
+                }
+            }
+        })
+    }
+}
+
+/// Checks the module exposes every `StrategyInterface` function and smoke-calls the live ones.
+fn validate_module(module: &Bound<'_, PyModule>) -> Result<()> {
+    for name in REQUIRED_FUNCTIONS {
+        let callable = module
+            .getattr(name)
+            .map(|attr| attr.is_callable())
+            .unwrap_or(false);
+        if !callable {
+            return Err(BankaiError::InvalidArgument(format!(
+                "python module missing function {name}"
+            )));
+        }
+    }
+    let kelly = module
+        .getattr("calculate_kelly")?
+        .call1((0.6, 2.0))?
+        .extract::<f64>()?;
+    let probability = module
+        .getattr("true_probability")?
+        .call1((0.5, 100.0, 100.0, 101.0, 0.01, 1.0, 1.0, 0.35))?
+        .extract::<f64>()?;
+    if !kelly.is_finite() || !probability.is_finite() || !(0.0..=1.0).contains(&probability) {
+        return Err(BankaiError::InvalidArgument(format!(
+            "python smoke call returned kelly={kelly} true_probability={probability}"
+        )));
+    }
+    Ok(())
+}
+
+/// Watches the module's directory (editors often save via rename) and calls `on_change`
+/// once per burst of writes to the module file. Runs until `on_change` returns false.
+pub fn spawn_module_watcher(
+    module_path: PathBuf,
+    mut on_change: impl FnMut() -> bool + Send + 'static,
+) -> Result<std::thread::JoinHandle<()>> {
+    let watch_dir = module_path
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| PathBuf::from("."));
+    let file_name = module_path.file_name().map(|name| name.to_os_string());
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx)?;
+    watcher.watch(&watch_dir, RecursiveMode::NonRecursive)?;
+    let handle = std::thread::Builder::new()
+        .name("bankai-python-watch".to_string())
+        .spawn(move || {
+            let _watcher = watcher;
+            let is_module_event = |event: &notify::Event| {
+                is_relevant_event(&event.kind)
+                    && event
+                        .paths
+                        .iter()
+                        .any(|path| path.file_name() == file_name.as_deref())
+            };
+            while let Ok(event) = rx.recv() {
+                match event {
+                    Ok(event) if is_module_event(&event) => {}
+                    Ok(_) => continue,
+                    Err(error) => {
+                        tracing::error!(?error, "python module watcher error");
+                        continue;
+                    }
+                }
+                loop {
+                    match rx.recv_timeout(RELOAD_DEBOUNCE) {
+                        Ok(_) => continue,
+                        Err(RecvTimeoutError::Timeout) => break,
+                        Err(RecvTimeoutError::Disconnected) => return,
+                    }
+                }
+                if !on_change() {
+                    return;
+                }
+            }
+        })?;
+    Ok(handle)
 }
 
 impl StrategyInterface for PythonHost {
@@ -65,6 +221,23 @@ impl StrategyInterface for PythonHost {
         })
     }
 
+    fn true_probability(&self, input: &ModelInput, execution: &ExecutionConfig) -> Result<f64> {
+        Python::with_gil(|py| {
+            let module = self.module.bind(py);
+            let value = module.getattr("true_probability")?.call1((
+                input.implied_up,
+                input.start_price,
+                input.current_price,
+                input.inference,
+                input.volatility_1m,
+                input.alignment,
+                execution.probability_scale,
+                execution.probability_max_offset,
+            ))?;
+            Ok(value.extract::<f64>()?)
+        })
+    }
+
     fn validate_signal(&self, inference_json: &str, current_price: f64) -> Result<bool> {
         Python::with_gil(|py| {
             let module = self.module.bind(py);
@@ -93,6 +266,182 @@ impl StrategyInterface for PythonHost {
     }
 }
 
+enum BackendRequest {
+    TrueProbability {
+        input: ModelInput,
+        execution: Box<ExecutionConfig>,
+        reply: oneshot::Sender<Result<f64>>,
+    },
+    Kelly {
+        win_prob: f64,
+        odds: f64,
+        reply: oneshot::Sender<Result<f64>>,
+    },
+    Reload,
+}
+
+/// Async handle to a PythonHost running on its own thread.
+#[derive(Clone)]
+pub struct PythonStrategyBackend {
+    sender: SyncSender<BackendRequest>,
+    timeout: Duration,
+    sizing: bool,
+}
+
+impl PythonStrategyBackend {
+    /// Starts the interpreter thread and imports the configured module, failing if the
+    /// import does.
+    pub async fn spawn(config: &PythonStrategyConfig) -> Result<Self> {
+        let module = config.module.clone();
+        let module_dir = PathBuf::from(&config.module_dir);
+        let (sender, receiver) = mpsc::sync_channel::<BackendRequest>(BACKEND_QUEUE_CAPACITY);
+        let (ready_tx, ready_rx) = oneshot::channel::<Result<()>>();
+        std::thread::Builder::new()
+            .name("bankai-python".to_string())
+            .spawn(move || {
+                PythonHost::initialize();
+                let mut host = match PythonHost::new_with_path(&module, &module_dir) {
+                    Ok(host) => {
+                        let _ = ready_tx.send(Ok(()));
+                        host
+                    }
+                    Err(error) => {
+                        let _ = ready_tx.send(Err(error));
+                        return;
+                    }
+                };
+                while let Ok(request) = receiver.recv() {
+                    match request {
+                        // Requests whose caller already timed out are skipped, not evaluated.
+                        BackendRequest::TrueProbability { reply, .. } if reply.is_closed() => {}
+                        BackendRequest::Kelly { reply, .. } if reply.is_closed() => {}
+                        BackendRequest::TrueProbability {
+                            input,
+                            execution,
+                            reply,
+                        } => {
+                            let _ = reply.send(host.true_probability(&input, &execution));
+                        }
+                        BackendRequest::Kelly {
+                            win_prob,
+                            odds,
+                            reply,
+                        } => {
+                            let _ = reply.send(host.calculate_kelly(win_prob, odds));
+                        }
+                        BackendRequest::Reload => match host.reload() {
+                            Ok(()) => {
+                                tracing::info!(module = %host.module_name(), "python strategy reloaded");
+                            }
+                            Err(error) => {
+                                tracing::error!(
+                                    ?error,
+                                    module = %host.module_name(),
+                                    "python strategy reload failed; keeping previous module"
+                                );
+                            }
+                        },
+                    }
+                }
+            })?;
+        ready_rx.await.map_err(|_| {
+            BankaiError::InvalidArgument("python backend thread exited during startup".to_string())
+        })??;
+        if config.hot_reload {
+            let module_path = module_file(Path::new(&config.module_dir), &config.module);
+            let reload_tx = sender.clone();
+            // Reloads queue behind in-flight calls rather than being dropped like live calls.
+            spawn_module_watcher(module_path, move || {
+                reload_tx.send(BackendRequest::Reload).is_ok()
+            })?;
+        }
+        Ok(Self {
+            sender,
+            timeout: Duration::from_millis(config.timeout_ms.max(1)),
+            sizing: config.sizing,
+        })
+    }
+
+    pub fn sizing_enabled(&self) -> bool {
+        self.sizing
+    }
+
+    pub async fn true_probability(
+        &self,
+        input: ModelInput,
+        execution: &ExecutionConfig,
+    ) -> Result<f64> {
+        let execution = Box::new(execution.clone());
+        let value = self
+            .call(|reply| BackendRequest::TrueProbability {
+                input,
+                execution,
+                reply,
+            })
+            .await?;
+        if !value.is_finite() || !(0.0..=1.0).contains(&value) {
+            return Err(BankaiError::InvalidArgument(format!(
+                "python true_probability out of range: {value}"
+            )));
+        }
+        Ok(value)
+    }
+
+    pub async fn calculate_kelly(&self, win_prob: f64, odds: f64) -> Result<f64> {
+        let value = self
+            .call(|reply| BackendRequest::Kelly {
+                win_prob,
+                odds,
+                reply,
+            })
+            .await?;
+        if !value.is_finite() || value < 0.0 {
+            return Err(BankaiError::InvalidArgument(format!(
+                "python calculate_kelly out of range: {value}"
+            )));
+        }
+        Ok(value.min(1.0))
+    }
+
+    async fn call(
+        &self,
+        build: impl FnOnce(oneshot::Sender<Result<f64>>) -> BackendRequest,
+    ) -> Result<f64> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        // Never queue behind a slow call: a full queue means the budget is already blown.
+        match self.sender.try_send(build(reply_tx)) {
+            Ok(()) => {}
+            Err(TrySendError::Full(_)) => {
+                return Err(BankaiError::InvalidArgument(
+                    "python backend busy".to_string(),
+                ))
+            }
+            Err(TrySendError::Disconnected(_)) => {
+                return Err(BankaiError::InvalidArgument(
+                    "python backend stopped".to_string(),
+                ))
+            }
+        }
+        match tokio::time::timeout(self.timeout, reply_rx).await {
+            Ok(Ok(result)) => result,
+            Ok(Err(_)) => Err(BankaiError::InvalidArgument(
+                "python backend dropped request".to_string(),
+            )),
+            Err(_) => Err(BankaiError::InvalidArgument(format!(
+                "python call exceeded {}ms budget",
+                self.timeout.as_millis()
+            ))),
+        }
+    }
+}
+
+fn module_file(module_dir: &Path, module_name: &str) -> PathBuf {
+    let mut path = module_dir.to_path_buf();
+    path.extend(module_name.split('.'));
+    path.set_extension("py");
+    path
+}
+
 fn default_python_dir() -> Result<PathBuf> {
     let cwd = std::env::current_dir()?;
     Ok(cwd.join("python"))
@@ -116,3 +465,107 @@ fn ensure_sys_path(py: Python<'_>, module_dir: &Path) -> Result<()> {
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALID_MODULE: &str = "
+def calculate_kelly(win_prob, odds):
+    return KELLY
+
+def true_probability(implied_up, start_price, current_price, inference, volatility_1m, alignment,
+                     probability_scale, probability_max_offset):
+    return 0.5
+
+def validate_signal(inference_json, current_price):
+    return True
+
+def calculate_staleness(signal_ts, candle_end_ts, now_ts):
+    return 0.0
+";
+
+    #[test]
+    fn reload_swaps_valid_module_and_keeps_previous_on_failure() {
+        let dir = std::env::temp_dir().join(format!("bankai_py_reload_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("temp dir");
+        let module_name = "bankai_reload_probe";
+        let path = dir.join(format!("{module_name}.py"));
+        std::fs::write(&path, format!("KELLY = 0.1\n{VALID_MODULE}")).expect("write module");
+
+        PythonHost::initialize();
+        let mut host = PythonHost::new_with_path(module_name, &dir).expect("import module");
+        assert_eq!(host.module_path(), path);
+        assert_eq!(host.calculate_kelly(0.6, 2.0).expect("kelly"), 0.1);
+
+        std::fs::write(
+            &path,
+            "def calculate_kelly(win_prob, odds):\n    return 0.9\n",
+        )
+        .expect("write broken module");
+        assert!(host.reload().is_err());
+        assert_eq!(host.calculate_kelly(0.6, 2.0).expect("kelly"), 0.1);
+
+        std::fs::write(&path, format!("KELLY = 0.2\n{VALID_MODULE}")).expect("write module");
+        host.reload().expect("reload");
+        assert_eq!(host.calculate_kelly(0.6, 2.0).expect("kelly"), 0.2);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn backend_reports_busy_instead_of_queueing() {
+        let dir = std::env::temp_dir().join(format!("bankai_py_busy_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("temp dir");
+        let module_name = "bankai_busy_probe";
+        std::fs::write(
+            dir.join(format!("{module_name}.py")),
+            format!("import time\nKELLY = 0.1\n{VALID_MODULE}")
+                .replace("    return 0.5", "    time.sleep(0.2)\n    return 0.5"),
+        )
+        .expect("write module");
+        let config = PythonStrategyConfig {
+            enabled: true,
+            module: module_name.to_string(),
+            module_dir: dir.to_string_lossy().into_owned(),
+            timeout_ms: 5_000,
+            sizing: false,
+            hot_reload: false,
+        };
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .expect("runtime");
+        let results = runtime.block_on(async {
+            let backend = PythonStrategyBackend::spawn(&config).await.expect("spawn");
+            let execution = ExecutionConfig::default();
+            // One call running plus a full queue leaves no room for the rest.
+            let calls = (0..BACKEND_QUEUE_CAPACITY + 2)
+                .map(|_| backend.true_probability(model_input(), &execution))
+                .collect::<Vec<_>>();
+            futures_util::future::join_all(calls).await
+        });
+        assert!(results
+            .iter()
+            .any(|result| matches!(result, Ok(value) if *value == 0.5)));
+        assert!(results.iter().any(|result| matches!(
+            result,
+            Err(BankaiError::InvalidArgument(message)) if message == "python backend busy"
+        )));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    fn model_input() -> ModelInput {
+        ModelInput {
+            implied_up: 0.5,
+            start_price: 100.0,
+            current_price: 100.0,
+            inference: 101.0,
+            volatility_1m: 0.01,
+            alignment: 1.0,
+            distribution: None,
+        }
+    }
+}
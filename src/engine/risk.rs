@@ -1,6 +1,6 @@
 /**
  * @description
- * Kill switch and risk state tracking for latency, clock drift, and losses.
+ * Kill switch and risk state tracking for latency, clock drift, losses, and source divergence.
  *
  * @dependencies
  * - arc-swap: atomic config updates for kill switch thresholds
@@ -9,11 +9,13 @@
  * - Engine components should respect `is_halted()` and stop trading immediately.
  */
 use arc_swap::ArcSwap;
+use serde::Serialize;
 use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU32, AtomicU64, AtomicU8, Ordering};
 use std::sync::Arc;
 
 use crate::config::TradingConfig;
 use crate::error::{BankaiError, Result};
+use crate::telemetry::metrics;
 
 #[derive(Debug, Clone)]
 pub struct KillSwitchConfig {
@@ -36,7 +38,7 @@ impl KillSwitchConfig {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 #[repr(u8)]
 pub enum HaltReason {
     None = 0,
@@ -44,15 +46,28 @@ pub enum HaltReason {
     ClockDrift = 2,
     ConsecutiveLosses = 3,
     Manual = 4,
+    SourceDivergence = 5,
 }
 
 impl HaltReason {
+    pub fn metric_label(self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Latency => "latency",
+            Self::ClockDrift => "clock_drift",
+            Self::ConsecutiveLosses => "consecutive_losses",
+            Self::Manual => "manual",
+            Self::SourceDivergence => "source_divergence",
+        }
+    }
+
     fn from_u8(value: u8) -> Self {
         match value {
             1 => Self::Latency,
             2 => Self::ClockDrift,
             3 => Self::ConsecutiveLosses,
             4 => Self::Manual,
+            5 => Self::SourceDivergence,
             _ => Self::None,
         }
     }
@@ -80,6 +95,7 @@ pub struct RiskState {
 
 impl RiskState {
     pub fn new(config: KillSwitchConfig) -> Self {
+        metrics::record_kill_switch(false);
         Self {
             config: ArcSwap::from_pointee(config),
             halted: AtomicBool::new(false),
@@ -119,12 +135,35 @@ impl RiskState {
         self.consecutive_losses.store(0, Ordering::Relaxed);
     }
 
+    /// Halts while price sources disagree and lifts the halt once they converge again.
+    pub fn record_source_divergence(&self, diverged: bool) -> bool {
+        if diverged {
+            self.trigger_halt(HaltReason::SourceDivergence);
+        } else if self.halt_reason() == HaltReason::SourceDivergence {
+            self.clear_halt();
+        }
+        self.is_halted()
+    }
+
     pub fn manual_halt(&self) {
         self.trigger_halt(HaltReason::Manual);
     }
 
+    /// Clears an operator halt only; kill-switch halts must recover through their own checks.
+    /// Returns the reason left in place when the halt was not manual.
+    pub fn clear_manual_halt(&self) -> std::result::Result<(), HaltReason> {
+        match self.halt_reason() {
+            HaltReason::Manual | HaltReason::None => {
+                self.clear_halt();
+                Ok(())
+            }
+            reason => Err(reason),
+        }
+    }
+
     pub fn clear_halt(&self) {
         self.halted.store(false, Ordering::SeqCst);
+        metrics::record_kill_switch(false);
         self.halt_reason
             .store(HaltReason::None as u8, Ordering::SeqCst);
         self.latency_breaches.store(0, Ordering::SeqCst);
@@ -193,6 +232,8 @@ impl RiskState {
             .is_ok()
         {
             self.halt_reason.store(reason as u8, Ordering::SeqCst);
+            metrics::record_kill_switch(true);
+            metrics::increment_kill_switch_trip(reason.metric_label());
         }
     }
 }
@@ -286,6 +327,34 @@ mod tests {
         assert_eq!(state.halt_reason(), HaltReason::ConsecutiveLosses);
     }
 
+    #[test]
+    fn it_halts_on_source_divergence_and_recovers() {
+        let state = RiskState::new(test_config());
+
+        assert!(state.record_source_divergence(true));
+        assert_eq!(state.halt_reason(), HaltReason::SourceDivergence);
+        assert!(!state.record_source_divergence(false));
+        assert_eq!(state.halt_reason(), HaltReason::None);
+
+        state.manual_halt();
+        assert!(state.record_source_divergence(false));
+        assert_eq!(state.halt_reason(), HaltReason::Manual);
+    }
+
+    #[test]
+    fn clear_manual_halt_leaves_kill_switch_halts() {
+        let state = RiskState::new(test_config());
+
+        assert!(state.record_clock_drift_ms(75));
+        assert_eq!(state.clear_manual_halt(), Err(HaltReason::ClockDrift));
+        assert!(state.is_halted());
+
+        state.clear_halt();
+        state.manual_halt();
+        assert_eq!(state.clear_manual_halt(), Ok(()));
+        assert!(!state.is_halted());
+    }
+
     #[test]
     fn it_calculates_staleness_ratio_and_flags_stale() {
         let result = evaluate_staleness(1_000, 800, 1_400, 0.4).expect("staleness computed");
@@ -0,0 +1,197 @@
+/**
+ * @description
+ * Fuses Allora inferences from several timeframes of one asset into a single price signal.
+ *
+ * @dependencies
+ * - None (pure math; the trader supplies topic weights, accuracy and signal ages)
+ *
+ * @notes
+ * - Each topic's forecast becomes a log return from the current price, rescaled to the
+ *   primary (catalogue) timeframe so an 8h forecast does not dominate a 15m window.
+ * - Effective weight = configured weight x accuracy factor x staleness decay, where the
+ *   accuracy factor is 2 x Laplace-smoothed hit rate (1.0 with no history) and the decay
+ *   halves every `half_life_ms` of signal age.
+ * - With only the primary topic the fused inference equals that topic's inference.
+ */
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TopicAccuracy {
+    pub hits: f64,
+    pub total: f64,
+}
+
+impl TopicAccuracy {
+    /// Laplace-smoothed directional hit rate; 0.5 before any window has resolved.
+    pub fn hit_rate(&self) -> f64 {
+        (self.hits + 1.0) / (self.total + 2.0)
+    }
+
+    pub fn record(&mut self, hit: bool) {
+        if hit {
+            self.hits += 1.0;
+        }
+        self.total += 1.0;
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FusionInput {
+    pub timeframe: String,
+    pub timeframe_ms: u64,
+    pub inference: f64,
+    pub signal_timestamp_ms: u64,
+    pub weight: f64,
+    pub half_life_ms: u64,
+    pub accuracy: TopicAccuracy,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TopicContribution {
+    pub timeframe: String,
+    pub inference: f64,
+    pub weight: f64,
+    pub accuracy: f64,
+    pub decay: f64,
+    /// Normalized share of the fused return (sums to 1 across contributions).
+    pub share: f64,
+    /// Log return from the current price, rescaled to the primary timeframe.
+    pub scaled_return: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FusedSignal {
+    pub inference: f64,
+    pub contributions: Vec<TopicContribution>,
+}
+
+impl FusedSignal {
+    /// Compact per-topic breakdown for activity logs.
+    pub fn summary(&self) -> String {
+        self.contributions
+            .iter()
+            .map(|topic| {
+                format!(
+                    "{}:w={:.2},acc={:.2},decay={:.2},share={:.2},ret_bps={:.1}",
+                    topic.timeframe,
+                    topic.weight,
+                    topic.accuracy,
+                    topic.decay,
+                    topic.share,
+                    topic.scaled_return * 10_000.0
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+pub fn fuse_signals(
+    current_price: f64,
+    primary_timeframe_ms: u64,
+    now_ms: u64,
+    inputs: &[FusionInput],
+) -> Option<FusedSignal> {
+    if current_price <= 0.0 || primary_timeframe_ms == 0 {
+        return None;
+    }
+    let mut contributions = Vec::new();
+    let mut weight_sum = 0.0;
+    for input in inputs {
+        if input.inference <= 0.0 || input.timeframe_ms == 0 || input.weight <= 0.0 {
+            continue;
+        }
+        let age_ms = now_ms.saturating_sub(input.signal_timestamp_ms);
+        let decay = if input.half_life_ms > 0 {
+            0.5_f64.powf(age_ms as f64 / input.half_life_ms as f64)
+        } else {
+            1.0
+        };
+        let accuracy = input.accuracy.hit_rate();
+        let effective = input.weight * 2.0 * accuracy * decay;
+        if effective <= 0.0 || !effective.is_finite() {
+            continue;
+        }
+        let horizon_scale = primary_timeframe_ms as f64 / input.timeframe_ms as f64;
+        let scaled_return = (input.inference / current_price).ln() * horizon_scale;
+        weight_sum += effective;
+        contributions.push(TopicContribution {
+            timeframe: input.timeframe.clone(),
+            inference: input.inference,
+            weight: effective,
+            accuracy,
+            decay,
+            share: 0.0,
+            scaled_return,
+        });
+    }
+    if contributions.is_empty() || weight_sum <= 0.0 {
+        return None;
+    }
+    let mut fused_return = 0.0;
+    for topic in &mut contributions {
+        topic.share = topic.weight / weight_sum;
+        fused_return += topic.share * topic.scaled_return;
+    }
+    Some(FusedSignal {
+        inference: current_price * fused_return.exp(),
+        contributions,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIVE_MIN_MS: u64 = 5 * 60 * 1_000;
+    const EIGHT_HOURS_MS: u64 = 8 * 60 * 60 * 1_000;
+
+    fn input(timeframe: &str, timeframe_ms: u64, inference: f64, age_ms: u64) -> FusionInput {
+        FusionInput {
+            timeframe: timeframe.to_string(),
+            timeframe_ms,
+            inference,
+            signal_timestamp_ms: 1_000_000 - age_ms,
+            weight: 1.0,
+            half_life_ms: timeframe_ms,
+            accuracy: TopicAccuracy::default(),
+        }
+    }
+
+    #[test]
+    fn single_topic_passes_inference_through() {
+        let fused = fuse_signals(
+            100.0,
+            FIVE_MIN_MS,
+            1_000_000,
+            &[input("5m", FIVE_MIN_MS, 101.0, 0)],
+        )
+        .expect("fused");
+        assert!((fused.inference - 101.0).abs() < 1e-9);
+        assert_eq!(fused.contributions[0].share, 1.0);
+    }
+
+    #[test]
+    fn long_horizon_topic_is_rescaled_and_accuracy_weighted() {
+        let mut slow = input("8h", EIGHT_HOURS_MS, 90.0, 0);
+        let fast = input("5m", FIVE_MIN_MS, 101.0, 0);
+        let fused = fuse_signals(100.0, FIVE_MIN_MS, 1_000_000, &[fast.clone(), slow.clone()])
+            .expect("fused");
+        // The 8h drop is scaled down to 5m, so the 5m rise still dominates.
+        assert!(fused.inference > 100.0);
+
+        slow.accuracy = TopicAccuracy {
+            hits: 1.0,
+            total: 20.0,
+        };
+        let discounted = fuse_signals(100.0, FIVE_MIN_MS, 1_000_000, &[fast, slow]).expect("fused");
+        assert!(discounted.contributions[1].share < fused.contributions[1].share);
+    }
+
+    #[test]
+    fn stale_topics_decay() {
+        let fresh = input("5m", FIVE_MIN_MS, 101.0, 0);
+        let stale = input("5m", FIVE_MIN_MS, 99.0, FIVE_MIN_MS);
+        let fused = fuse_signals(100.0, FIVE_MIN_MS, 1_000_000, &[fresh, stale]).expect("fused");
+        assert!((fused.contributions[1].decay - 0.5).abs() < 1e-9);
+        assert!(fused.inference > 100.0);
+    }
+}
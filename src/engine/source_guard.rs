@@ -0,0 +1,323 @@
+/**
+ * @description
+ * Cross-checks Chainlink against Binance per asset and publishes which source the trader may use.
+ *
+ * @dependencies
+ * - std: RwLock-backed status map shared between the engine core and trader
+ *
+ * @notes
+ * - Freshness is measured on receive time so a silent feed is detected even without new events.
+ * - Divergence either blocks the affected asset or halts trading, depending on config.
+ * - Fallback only kicks in when Chainlink is quiet and Binance is still fresh; each feed has
+ *   its own staleness window since Binance ticks far more often than Chainlink.
+ */
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use crate::config::{Config, DivergenceAction};
+use crate::engine::risk::RiskState;
+use crate::engine::types::ChainlinkMarketUpdate;
+use crate::telemetry::metrics;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PriceSource {
+    Chainlink,
+    Binance,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SourceStatus {
+    /// Chainlink drives trading (also the state when there is nothing to compare).
+    #[default]
+    Primary,
+    /// Chainlink is quiet; Binance updates stand in for it.
+    Fallback,
+    /// Sources disagree beyond the configured threshold; no new intents for the asset.
+    Blocked,
+}
+
+/// Per-asset source status written by the engine core and read by the trader.
+#[derive(Debug, Default)]
+pub struct SourceStatusMap {
+    statuses: RwLock<HashMap<String, SourceStatus>>,
+}
+
+impl SourceStatusMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn status(&self, asset: &str) -> SourceStatus {
+        self.statuses
+            .read()
+            .ok()
+            .and_then(|statuses| statuses.get(&asset.to_ascii_uppercase()).copied())
+            .unwrap_or_default()
+    }
+
+    pub fn snapshot(&self) -> HashMap<String, SourceStatus> {
+        self.statuses
+            .read()
+            .map(|statuses| statuses.clone())
+            .unwrap_or_default()
+    }
+
+    fn set_status(&self, asset: &str, status: SourceStatus) -> SourceStatus {
+        let Ok(mut statuses) = self.statuses.write() else {
+            return SourceStatus::Primary;
+        };
+        statuses
+            .insert(asset.to_ascii_uppercase(), status)
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SourceGuardConfig {
+    pub enabled: bool,
+    pub max_divergence_bps: f64,
+    pub action: DivergenceAction,
+    pub chainlink_stale_ms: u64,
+    pub binance_stale_ms: u64,
+    pub fallback_enabled: bool,
+}
+
+impl SourceGuardConfig {
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            enabled: config.binance.enabled,
+            max_divergence_bps: config.binance.max_divergence_bps,
+            action: config.binance.divergence_action,
+            chainlink_stale_ms: config.binance.chainlink_stale_ms,
+            binance_stale_ms: config.binance.binance_stale_ms,
+            fallback_enabled: config.binance.fallback_enabled,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct PricePoint {
+    price: f64,
+    received_at_ms: u64,
+}
+
+pub struct SourceGuard {
+    statuses: Arc<SourceStatusMap>,
+    risk: Arc<RiskState>,
+    chainlink: HashMap<String, PricePoint>,
+    binance: HashMap<String, PricePoint>,
+}
+
+impl SourceGuard {
+    pub fn new(statuses: Arc<SourceStatusMap>, risk: Arc<RiskState>) -> Self {
+        Self {
+            statuses,
+            risk,
+            chainlink: HashMap::new(),
+            binance: HashMap::new(),
+        }
+    }
+
+    /// Records a price from either feed and re-evaluates the asset.
+    pub fn observe(
+        &mut self,
+        config: &SourceGuardConfig,
+        source: PriceSource,
+        update: &ChainlinkMarketUpdate,
+        now_ms: u64,
+    ) -> SourceStatus {
+        let asset = update.asset.to_ascii_uppercase();
+        if let Some(price) = update.last_price.filter(|price| *price > 0.0) {
+            let point = PricePoint {
+                price,
+                received_at_ms: now_ms,
+            };
+            match source {
+                PriceSource::Chainlink => self.chainlink.insert(asset.clone(), point),
+                PriceSource::Binance => self.binance.insert(asset.clone(), point),
+            };
+        }
+        let status = self.evaluate_asset(config, &asset, now_ms);
+        self.apply_halt(config);
+        status
+    }
+
+    /// Re-evaluates every known asset; catches a feed that has gone silent.
+    pub fn refresh(&mut self, config: &SourceGuardConfig, now_ms: u64) {
+        let assets: Vec<String> = self
+            .chainlink
+            .keys()
+            .chain(self.binance.keys())
+            .cloned()
+            .collect();
+        for asset in assets {
+            self.evaluate_asset(config, &asset, now_ms);
+        }
+        self.apply_halt(config);
+    }
+
+    fn evaluate_asset(
+        &mut self,
+        config: &SourceGuardConfig,
+        asset: &str,
+        now_ms: u64,
+    ) -> SourceStatus {
+        let is_fresh = |point: &&PricePoint, stale_ms: u64| {
+            now_ms.saturating_sub(point.received_at_ms) <= stale_ms
+        };
+        let chainlink = self
+            .chainlink
+            .get(asset)
+            .filter(|point| is_fresh(point, config.chainlink_stale_ms));
+        let binance = self
+            .binance
+            .get(asset)
+            .filter(|point| is_fresh(point, config.binance_stale_ms));
+
+        let mut divergence = None;
+        let status = if !config.enabled {
+            SourceStatus::Primary
+        } else {
+            match (chainlink, binance) {
+                (Some(chainlink), Some(binance)) => {
+                    let bps = divergence_bps(chainlink.price, binance.price);
+                    divergence = Some(bps);
+                    if bps > config.max_divergence_bps {
+                        SourceStatus::Blocked
+                    } else {
+                        SourceStatus::Primary
+                    }
+                }
+                (None, Some(_)) if config.fallback_enabled => SourceStatus::Fallback,
+                _ => SourceStatus::Primary,
+            }
+        };
+
+        let previous = self.statuses.set_status(asset, status);
+        if previous != status {
+            match status {
+                SourceStatus::Blocked => {
+                    metrics::increment_source_divergence();
+                    tracing::warn!(
+                        asset,
+                        divergence_bps = divergence.unwrap_or_default(),
+                        threshold_bps = config.max_divergence_bps,
+                        "chainlink/binance divergence; blocking asset"
+                    );
+                }
+                SourceStatus::Fallback => {
+                    tracing::warn!(asset, "chainlink quiet; falling back to binance");
+                }
+                SourceStatus::Primary => {
+                    tracing::info!(asset, ?previous, "price sources back on chainlink");
+                }
+            }
+        }
+        status
+    }
+
+    fn apply_halt(&self, config: &SourceGuardConfig) {
+        if config.action != DivergenceAction::Halt {
+            self.risk.record_source_divergence(false);
+            return;
+        }
+        let diverged = self
+            .statuses
+            .snapshot()
+            .values()
+            .any(|status| *status == SourceStatus::Blocked);
+        self.risk.record_source_divergence(diverged);
+    }
+}
+
+fn divergence_bps(reference: f64, other: f64) -> f64 {
+    if reference <= 0.0 {
+        return 0.0;
+    }
+    ((other - reference) / reference).abs() * 10_000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::risk::{HaltReason, KillSwitchConfig};
+
+    fn guard_config(action: DivergenceAction) -> SourceGuardConfig {
+        SourceGuardConfig {
+            enabled: true,
+            max_divergence_bps: 50.0,
+            action,
+            chainlink_stale_ms: 1_000,
+            binance_stale_ms: 500,
+            fallback_enabled: true,
+        }
+    }
+
+    fn risk() -> Arc<RiskState> {
+        Arc::new(RiskState::new(KillSwitchConfig {
+            latency_ms: 1_000,
+            latency_consecutive: 1,
+            latency_recovery: 1,
+            clock_drift_ms: 1_000,
+            consecutive_losses: 10,
+        }))
+    }
+
+    fn update(price: f64) -> ChainlinkMarketUpdate {
+        ChainlinkMarketUpdate {
+            asset: "BTC".to_string(),
+            last_price: Some(price),
+            volatility_1m: None,
+            dfo: None,
+            event_time_ms: 0,
+        }
+    }
+
+    #[test]
+    fn it_blocks_or_halts_on_divergence() {
+        let statuses = Arc::new(SourceStatusMap::new());
+        let risk = risk();
+        let mut guard = SourceGuard::new(statuses.clone(), risk.clone());
+        let config = guard_config(DivergenceAction::Halt);
+
+        guard.observe(&config, PriceSource::Chainlink, &update(100_000.0), 0);
+        let status = guard.observe(&config, PriceSource::Binance, &update(100_300.0), 10);
+        assert_eq!(status, SourceStatus::Primary);
+
+        let status = guard.observe(&config, PriceSource::Binance, &update(101_000.0), 20);
+        assert_eq!(status, SourceStatus::Blocked);
+        assert_eq!(statuses.status("btc"), SourceStatus::Blocked);
+        assert_eq!(risk.halt_reason(), HaltReason::SourceDivergence);
+
+        guard.observe(&config, PriceSource::Binance, &update(100_100.0), 30);
+        assert!(!risk.is_halted());
+    }
+
+    #[test]
+    fn it_falls_back_when_chainlink_goes_quiet() {
+        let statuses = Arc::new(SourceStatusMap::new());
+        let mut guard = SourceGuard::new(statuses.clone(), risk());
+        let config = guard_config(DivergenceAction::Block);
+
+        guard.observe(&config, PriceSource::Chainlink, &update(100_000.0), 0);
+        let status = guard.observe(&config, PriceSource::Binance, &update(100_010.0), 1_500);
+        assert_eq!(status, SourceStatus::Fallback);
+
+        guard.observe(&config, PriceSource::Chainlink, &update(100_000.0), 1_600);
+        assert_eq!(statuses.status("BTC"), SourceStatus::Primary);
+    }
+
+    #[test]
+    fn binance_staleness_uses_its_own_window() {
+        let statuses = Arc::new(SourceStatusMap::new());
+        let mut guard = SourceGuard::new(statuses.clone(), risk());
+        let config = guard_config(DivergenceAction::Block);
+
+        guard.observe(&config, PriceSource::Binance, &update(100_010.0), 0);
+        guard.refresh(&config, 400);
+        assert_eq!(statuses.status("BTC"), SourceStatus::Fallback);
+
+        guard.refresh(&config, 700);
+        assert_eq!(statuses.status("BTC"), SourceStatus::Primary);
+    }
+}
@@ -9,6 +9,14 @@
  * @notes
  * - Requires Redis market metadata and Polymarket order books to be available.
  * - Emits TradeIntent only when signals are fresh and within the market window.
+ * - Binance updates only drive evaluation while the source guard reports a Chainlink fallback.
+ * - An optional Python backend overrides true probability and Kelly sizing; any error or
+ *   blown time budget falls back to the Rust model for that evaluation.
+ * - With regret tracking on, emitted intents and those suppressed by edge-floor or
+ *   signal-direction blockers are queued for regret scoring when the window resolves.
+ * - The catalogue timeframe signal gates and aligns each window; every other Allora
+ *   timeframe for the asset is fused into the model inference (see `signal_fusion`),
+ *   and each topic's direction is scored against the window outcome for accuracy weights.
  */
 use arc_swap::ArcSwap;
 use chrono::Utc;
@@ -16,42 +24,37 @@ use chrono_tz::America::New_York;
 use reqwest::Client;
 use std::collections::HashMap;
 use std::sync::Arc;
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::time::Duration;
 use tokio::sync::{broadcast, mpsc};
 
-use crate::config::{Config, ExecutionConfig, FeeConfig};
-use crate::engine::analysis::{analyze_opportunity, AnalysisInput, TradeDecision};
+use crate::accounting::no_money::{record_model_prediction, ModelPrediction};
+use crate::accounting::regret::{record_regret_candidate, RegretCandidate};
+use crate::config::{parse_timeframe_ms, Config, ExecutionConfig, FeeConfig};
+use crate::engine::analysis::{analyze_opportunity, calculate_kelly, AnalysisInput, TradeDecision};
+use crate::engine::clock::{Clock, SystemClock};
+use crate::engine::models::{
+    ModelInput, ModelOutput, ModelRegistry, PriceDistribution, ProbabilityModel, SignalContext,
+    SIGNAL_DIR_DOWN, SIGNAL_DIR_UP,
+};
+use crate::engine::python_host::PythonStrategyBackend;
 use crate::engine::risk::RiskState;
+use crate::engine::signal_fusion::{fuse_signals, FusedSignal, FusionInput, TopicAccuracy};
+use crate::engine::source_guard::{SourceStatus, SourceStatusMap};
 use crate::engine::types::{
     AlloraMarketUpdate, ChainlinkMarketUpdate, MarketUpdate, MarketWindow, TradeIntent, TradeMode,
 };
 use crate::error::{BankaiError, Result};
+use crate::execution::payload_builder::order_price;
 use crate::storage::orderbook::{BookSide, OrderBookStore};
 use crate::storage::redis::RedisManager;
+use crate::telemetry::metrics;
 
 const DEFAULT_TICK_INTERVAL: Duration = Duration::from_secs(5);
 const ACTIVITY_LOG_LIMIT: usize = 50;
-const DEFAULT_SIGNAL_HORIZON_MS: u64 = 5 * 60 * 1_000;
-const SQRT_5: f64 = 2.236_067_977_5;
+const WINDOW_LOG_LIMIT: usize = 200;
 const ORDERBOOK_STALE_MS: u64 = 30_000;
-
-const SIGNAL_DIR_UP: i8 = 1;
-const SIGNAL_DIR_DOWN: i8 = -1;
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum ExecutionModelVersion {
-    V1,
-    V2,
-}
-
-impl ExecutionModelVersion {
-    fn as_str(self) -> &'static str {
-        match self {
-            ExecutionModelVersion::V1 => "v1",
-            ExecutionModelVersion::V2 => "v2",
-        }
-    }
-}
+const PYTHON_MODEL_NAME: &str = "python";
+const TOPIC_SCORE_GRACE_MS: u64 = 120_000;
 
 pub struct TradingEngine {
     config: Arc<ArcSwap<Config>>,
@@ -61,6 +64,11 @@ pub struct TradingEngine {
     intent_tx: mpsc::Sender<TradeIntent>,
     wallet_key: Option<String>,
     gamma_client: Client,
+    clock: Arc<dyn Clock>,
+    sources: Arc<SourceStatusMap>,
+    models: Arc<ModelRegistry>,
+    python: Option<PythonStrategyBackend>,
+    regret_tracking: bool,
 }
 
 impl TradingEngine {
@@ -84,9 +92,44 @@ impl TradingEngine {
             intent_tx,
             wallet_key,
             gamma_client,
+            clock: Arc::new(SystemClock),
+            sources: Arc::new(SourceStatusMap::new()),
+            models: Arc::new(ModelRegistry::default()),
+            python: None,
+            regret_tracking: false,
         }
     }
 
+    /// Replaces the wall-clock time source, e.g. with a SimulatedClock for replays.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Shares the per-asset source status published by the engine core's source guard.
+    pub fn with_source_statuses(mut self, sources: Arc<SourceStatusMap>) -> Self {
+        self.sources = sources;
+        self
+    }
+
+    /// Replaces the built-in v1/v2 probability models, e.g. to add experimental shadows.
+    pub fn with_models(mut self, models: Arc<ModelRegistry>) -> Self {
+        self.models = models;
+        self
+    }
+
+    /// Routes true-probability (and optionally sizing) decisions through a Python module.
+    pub fn with_python_backend(mut self, python: PythonStrategyBackend) -> Self {
+        self.python = Some(python);
+        self
+    }
+
+    /// Queues emitted and blocker-suppressed intents for regret scoring.
+    pub fn with_regret_tracking(mut self) -> Self {
+        self.regret_tracking = true;
+        self
+    }
+
     pub fn spawn(self, receiver: broadcast::Receiver<MarketUpdate>) -> tokio::task::JoinHandle<()> {
         tokio::spawn(async move {
             if let Err(error) = self.run(receiver).await {
@@ -96,7 +139,7 @@ impl TradingEngine {
     }
 
     async fn run(self, mut receiver: broadcast::Receiver<MarketUpdate>) -> Result<()> {
-        let boot_time_ms = now_ms()?;
+        let boot_time_ms = self.clock.now_ms()?;
         let mut state = TraderState::new(boot_time_ms);
         let mut tick = tokio::time::interval(DEFAULT_TICK_INTERVAL);
 
@@ -124,7 +167,7 @@ impl TradingEngine {
         }
     }
 
-    async fn handle_update(&self, state: &mut TraderState, update: MarketUpdate) -> Result<()> {
+    pub async fn handle_update(&self, state: &mut TraderState, update: MarketUpdate) -> Result<()> {
         match update {
             MarketUpdate::Chainlink(update) => {
                 state
@@ -132,6 +175,15 @@ impl TradingEngine {
                     .insert(update.asset.clone(), update.clone());
                 self.evaluate_asset(state, &update.asset).await?;
             }
+            MarketUpdate::Binance(update) => {
+                if self.sources.status(&update.asset) != SourceStatus::Fallback {
+                    return Ok(());
+                }
+                state
+                    .last_chainlink
+                    .insert(update.asset.clone(), update.clone());
+                self.evaluate_asset(state, &update.asset).await?;
+            }
             MarketUpdate::Allora(update) => {
                 let key = format!("{}:{}", update.asset, update.timeframe);
                 state.last_allora.insert(key, update.clone());
@@ -143,12 +195,23 @@ impl TradingEngine {
 
     async fn evaluate_asset(&self, state: &mut TraderState, asset: &str) -> Result<()> {
         let config = self.config.load_full();
-        let now = now_ms()?;
+        let now = self.clock.now_ms()?;
         if self.risk.is_halted() {
             self.log_blocker(state, asset, "risk_halt", "risk halted; skipping", now)
                 .await;
             return Ok(());
         }
+        if self.sources.status(asset) == SourceStatus::Blocked {
+            self.log_blocker(
+                state,
+                asset,
+                "source_divergence",
+                "chainlink/binance divergence; skipping",
+                now,
+            )
+            .await;
+            return Ok(());
+        }
         if !config.execution.enable_trading && !config.execution.no_money_mode {
             self.log_blocker(
                 state,
@@ -200,7 +263,7 @@ impl TradingEngine {
             .unwrap_or(config.execution.min_volatility)
             .max(config.execution.min_volatility);
 
-        let Some(asset_window) = self.redis.get_asset_window(asset).await? else {
+        let Some(asset_window) = self.redis.get_asset_window_at(asset, now).await? else {
             self.log_blocker(
                 state,
                 asset,
@@ -215,6 +278,8 @@ impl TradingEngine {
             start_time_ms: asset_window.start_time_ms,
             end_time_ms: asset_window.end_time_ms,
         };
+        state.current_window.insert(asset.to_string(), window);
+        self.settle_topic_scores(state, asset, now).await;
 
         if window.start_time_ms <= state.boot_time_ms {
             self.log_blocker(
@@ -228,11 +293,13 @@ impl TradingEngine {
             return Ok(());
         }
 
-        let horizon_ms = alignment_horizon_ms(&config, asset);
+        let horizon_ms = config.signal_alignment_horizon_ms(asset);
         let max_align_ms = horizon_ms;
-        let Some(aligned) = select_aligned_5m_signal(
+        let timeframe = config.polymarket.signal_timeframe(asset);
+        let Some(aligned) = select_aligned_signal(
             &state.last_allora,
             asset,
+            timeframe,
             window,
             now,
             horizon_ms,
@@ -244,7 +311,7 @@ impl TradingEngine {
                     state,
                     asset,
                     "signal_missing",
-                    "no 5m signal found in window; skipping",
+                    &format!("no {timeframe} signal found in window; skipping"),
                     now,
                 )
                 .await;
@@ -259,7 +326,7 @@ impl TradingEngine {
                 state,
                 asset,
                 "signal_carry_forward",
-                "carried forward closest 5m signal from before window",
+                &format!("carried forward closest {timeframe} signal from before window"),
                 now,
             )
             .await;
@@ -271,7 +338,7 @@ impl TradingEngine {
                     state,
                     asset,
                     "signal_stale",
-                    "5m signal stale; skipping window",
+                    &format!("{timeframe} signal stale; skipping window"),
                     now,
                 )
                 .await;
@@ -334,6 +401,19 @@ impl TradingEngine {
         let down_token = metadata
             .outcome_down_token_id
             .ok_or_else(|| BankaiError::InvalidArgument("down token id missing".to_string()))?;
+        if self.orderbook.is_degraded(&up_token).await?
+            || self.orderbook.is_degraded(&down_token).await?
+        {
+            self.log_blocker(
+                state,
+                asset,
+                "book_degraded",
+                "order book failed integrity check; waiting for rebuild",
+                now,
+            )
+            .await;
+            return Ok(());
+        }
         let up_fee_bps = self.redis.get_fee_rate_bps(&up_token).await?;
         let down_fee_bps = self.redis.get_fee_rate_bps(&down_token).await?;
         if up_fee_bps.is_none() || down_fee_bps.is_none() {
@@ -367,7 +447,7 @@ impl TradingEngine {
             .check_exit_intent(asset, &asset_window.market_id, &up_token)
             .await?
         {
-            self.log_intent(asset, &exit_intent).await;
+            self.log_intent(asset, window, &exit_intent).await;
             let _ = self.intent_tx.send(exit_intent).await;
             state.last_intent_ms.insert(asset.to_string(), now);
             return Ok(());
@@ -376,7 +456,7 @@ impl TradingEngine {
             .check_exit_intent(asset, &asset_window.market_id, &down_token)
             .await?
         {
-            self.log_intent(asset, &exit_intent).await;
+            self.log_intent(asset, window, &exit_intent).await;
             let _ = self.intent_tx.send(exit_intent).await;
             state.last_intent_ms.insert(asset.to_string(), now);
             return Ok(());
@@ -455,17 +535,41 @@ impl TradingEngine {
         };
         let implied_down = implied_down.unwrap_or_else(|| (1.0 - implied_up).max(0.0));
 
-        let model_version = resolve_model_version(&config.execution);
-        let Some(active_model) = compute_model_output(
-            model_version,
+        let Some(model) = self.models.active(&config.execution) else {
+            self.log_blocker(
+                state,
+                asset,
+                "model_missing",
+                "no probability model registered; skipping",
+                now,
+            )
+            .await;
+            return Ok(());
+        };
+        let fusion = self
+            .fuse_topic_signals(state, &config, asset, &allora, current_price, now)
+            .await;
+        if let Some(fusion) = fusion.as_ref() {
+            state.record_topic_directions(asset, window, start_price, fusion);
+        }
+        let inference = fusion
+            .as_ref()
+            .map(|fusion| fusion.inference)
+            .unwrap_or(allora.inference_value);
+        let model_input = ModelInput {
             implied_up,
             start_price,
             current_price,
-            allora.inference_value,
-            volatility,
-            &config.execution,
+            inference,
+            volatility_1m: volatility,
             alignment,
-        ) else {
+            distribution: PriceDistribution::from_intervals(
+                &allora.confidence_percentiles,
+                &allora.confidence_intervals,
+            )
+            .map(|distribution| distribution.shifted(inference - allora.inference_value)),
+        };
+        let Some(mut active_model) = model.compute(&model_input, &config.execution) else {
             self.log_blocker(
                 state,
                 asset,
@@ -476,49 +580,76 @@ impl TradingEngine {
             .await;
             return Ok(());
         };
-        if model_version == ExecutionModelVersion::V2
-            && config.execution.model_v2_z_min > 0.0
-            && active_model.z_score.abs() < config.execution.model_v2_z_min
-        {
-            self.log_blocker(
+        let mut active_name = model.name();
+        if let Some(python) = self.python.as_ref() {
+            let result = python
+                .true_probability(model_input.clone(), &config.execution)
+                .await;
+            match apply_python_true_up(&mut active_model, result) {
+                Ok(()) => active_name = PYTHON_MODEL_NAME,
+                Err(error) => {
+                    self.log_python_fallback(state, asset, "true_probability", &error, now);
+                }
+            }
+        }
+        let shadow_outputs: Vec<(Arc<dyn ProbabilityModel>, ModelOutput)> = self
+            .models
+            .shadows(&config.execution)
+            .into_iter()
+            .filter_map(|shadow| {
+                let output = shadow.compute(&model_input, &config.execution)?;
+                Some((shadow, output))
+            })
+            .collect();
+        for (shadow, shadow_output) in &shadow_outputs {
+            self.log_model_shadow(
                 state,
                 asset,
-                "v2_signal_below_min",
-                "v2 signal strength below minimum; skipping",
+                &asset_window.market_id,
+                active_name,
+                active_model,
+                shadow.name(),
+                *shadow_output,
+                implied_up,
+                fusion.as_ref(),
                 now,
             )
             .await;
-            return Ok(());
         }
-        if config.execution.model_shadow_mode {
-            let shadow_model = match model_version {
-                ExecutionModelVersion::V1 => ExecutionModelVersion::V2,
-                ExecutionModelVersion::V2 => ExecutionModelVersion::V1,
-            };
-            if let Some(shadow_output) = compute_model_output(
-                shadow_model,
-                implied_up,
-                start_price,
-                current_price,
-                allora.inference_value,
-                volatility,
-                &config.execution,
-                alignment,
-            ) {
-                self.log_model_shadow(
+        if config.execution.no_money_mode && config.execution.model_shadow_mode {
+            let outputs = std::iter::once((active_name, active_model, true)).chain(
+                shadow_outputs
+                    .iter()
+                    .map(|(shadow, output)| (shadow.name(), *output, false)),
+            );
+            for (name, output, active) in outputs {
+                self.record_model_prediction(
                     state,
                     asset,
                     &asset_window.market_id,
-                    model_version,
-                    active_model,
-                    shadow_model,
-                    shadow_output,
+                    window,
+                    name,
+                    output,
+                    active,
                     implied_up,
                     now,
                 )
                 .await;
             }
         }
+        let min_abs_z = model.min_abs_z(&config.execution);
+        if min_abs_z > 0.0 && active_model.z_score.abs() < min_abs_z {
+            self.log_blocker(
+                state,
+                asset,
+                &format!("{}_signal_below_min", model.name()),
+                &format!("{} signal strength below minimum; skipping", model.name()),
+                now,
+            )
+            .await;
+            return Ok(());
+        }
+        let edge_floor_bps = model.edge_floor_bps(&config.execution);
         let signal_context = active_model.signal_context;
         let true_up = active_model.true_up;
         let true_down = (1.0 - true_up).clamp(0.0, 1.0);
@@ -538,14 +669,24 @@ impl TradingEngine {
             &up_fees,
         ) {
             if let Some(mut intent) = result.intent {
-                if model_version == ExecutionModelVersion::V2
-                    && result.edge_bps < config.execution.model_v2_edge_floor_bps
-                {
+                if edge_floor_bps.is_some_and(|floor| result.edge_bps < floor) {
+                    let blocker = format!("{}_edge_floor_up", model.name());
                     self.log_blocker(
                         state,
                         asset,
-                        "v2_edge_floor_up",
-                        "v2 edge floor blocks UP intent",
+                        &blocker,
+                        &format!("{} edge floor blocks UP intent", model.name()),
+                        now,
+                    )
+                    .await;
+                    self.record_regret(
+                        asset,
+                        window,
+                        &intent,
+                        "UP",
+                        active_name,
+                        up_fees.taker_fee_bps,
+                        Some(&blocker),
                         now,
                     )
                     .await;
@@ -563,6 +704,17 @@ impl TradingEngine {
                         now,
                     )
                     .await;
+                    self.record_regret(
+                        asset,
+                        window,
+                        &intent,
+                        "UP",
+                        active_name,
+                        up_fees.taker_fee_bps,
+                        Some("signal_direction_block_up"),
+                        now,
+                    )
+                    .await;
                 } else {
                     if up_fee_missing {
                         match result.decision {
@@ -629,14 +781,24 @@ impl TradingEngine {
             &down_fees,
         ) {
             if let Some(mut intent) = result.intent {
-                if model_version == ExecutionModelVersion::V2
-                    && result.edge_bps < config.execution.model_v2_edge_floor_bps
-                {
+                if edge_floor_bps.is_some_and(|floor| result.edge_bps < floor) {
+                    let blocker = format!("{}_edge_floor_down", model.name());
                     self.log_blocker(
                         state,
                         asset,
-                        "v2_edge_floor_down",
-                        "v2 edge floor blocks DOWN intent",
+                        &blocker,
+                        &format!("{} edge floor blocks DOWN intent", model.name()),
+                        now,
+                    )
+                    .await;
+                    self.record_regret(
+                        asset,
+                        window,
+                        &intent,
+                        "DOWN",
+                        active_name,
+                        down_fees.taker_fee_bps,
+                        Some(&blocker),
                         now,
                     )
                     .await;
@@ -654,6 +816,17 @@ impl TradingEngine {
                         now,
                     )
                     .await;
+                    self.record_regret(
+                        asset,
+                        window,
+                        &intent,
+                        "DOWN",
+                        active_name,
+                        down_fees.taker_fee_bps,
+                        Some("signal_direction_block_down"),
+                        now,
+                    )
+                    .await;
                 } else {
                     if down_fee_missing {
                         match result.decision {
@@ -712,7 +885,21 @@ impl TradingEngine {
             }
         }
 
-        if let Some(intent) = best_intent {
+        if let Some(mut intent) = best_intent {
+            if let Some(python) = self
+                .python
+                .as_ref()
+                .filter(|python| python.sizing_enabled())
+            {
+                if intent.requested_size.is_none() {
+                    match self.python_order_size(python, &config, &intent).await {
+                        Ok(size) => intent.requested_size = Some(size),
+                        Err(error) => {
+                            self.log_python_fallback(state, asset, "calculate_kelly", &error, now);
+                        }
+                    }
+                }
+            }
             if !config.execution.no_money_mode {
                 if let Some(min_size) = metadata.min_order_size {
                     if let Ok(Some(bankroll)) = self.redis.get_float("sys:bankroll:usdc").await {
@@ -736,7 +923,23 @@ impl TradingEngine {
                     }
                 }
             }
-            self.log_intent(asset, &intent).await;
+            self.log_intent(asset, window, &intent).await;
+            let (direction, fees) = if intent.asset_id == up_token {
+                ("UP", &up_fees)
+            } else {
+                ("DOWN", &down_fees)
+            };
+            self.record_regret(
+                asset,
+                window,
+                &intent,
+                direction,
+                active_name,
+                fees.taker_fee_bps,
+                None,
+                now,
+            )
+            .await;
             let _ = self.intent_tx.send(intent).await;
             state.last_intent_ms.insert(asset.to_string(), now);
             state
@@ -763,7 +966,7 @@ impl TradingEngine {
 }
 
 impl TradingEngine {
-    async fn log_intent(&self, asset: &str, intent: &TradeIntent) {
+    async fn log_intent(&self, asset: &str, window: MarketWindow, intent: &TradeIntent) {
         let prefix = log_prefix();
         let side = match intent.side {
             crate::engine::types::TradeSide::Buy => "BUY",
@@ -808,6 +1011,10 @@ impl TradingEngine {
             .redis
             .push_intent_log(&message, ACTIVITY_LOG_LIMIT)
             .await;
+        let _ = self
+            .redis
+            .push_window_log(asset, window.start_time_ms, &message, WINDOW_LOG_LIMIT)
+            .await;
     }
 }
 
@@ -820,18 +1027,18 @@ impl TradingEngine {
         message: &str,
         now_ms: u64,
     ) {
-        let throttle_ms = 30_000;
-        let cache_key = format!("{asset}:{key}");
-        let last = state
-            .last_blocker_alert_ms
-            .get(&cache_key)
-            .copied()
-            .unwrap_or(0);
-        if now_ms.saturating_sub(last) < throttle_ms {
-            return;
+        metrics::increment_trade_blocker(key);
+        let targets = state.record_blocker(asset, key, now_ms);
+        if let Some(window_start_ms) = targets.window_start_ms {
+            let entry = format!("{} [BLOCKER] {key} {message}", log_prefix());
+            let _ = self
+                .redis
+                .push_window_log(asset, window_start_ms, &entry, WINDOW_LOG_LIMIT)
+                .await;
+        }
+        if targets.alert {
+            self.log_alert(asset, message).await;
         }
-        self.log_alert(asset, message).await;
-        state.last_blocker_alert_ms.insert(cache_key, now_ms);
     }
 
     async fn log_alert(&self, asset: &str, message: &str) {
@@ -848,19 +1055,16 @@ impl TradingEngine {
         state: &mut TraderState,
         asset: &str,
         market_id: &str,
-        active_version: ExecutionModelVersion,
+        active_version: &str,
         active: ModelOutput,
-        shadow_version: ExecutionModelVersion,
+        shadow_version: &str,
         shadow: ModelOutput,
         implied_up: f64,
+        fusion: Option<&FusedSignal>,
         now_ms: u64,
     ) {
         let throttle_ms = 30_000;
-        let cache_key = format!(
-            "{asset}:{}:{}",
-            active_version.as_str(),
-            shadow_version.as_str()
-        );
+        let cache_key = format!("{asset}:{active_version}:{shadow_version}");
         let last = state
             .last_model_shadow_log_ms
             .get(&cache_key)
@@ -874,13 +1078,22 @@ impl TradingEngine {
         let active_edge_bps = (active.true_up - implied_up) * 10_000.0;
         let shadow_edge_bps = (shadow.true_up - implied_up) * 10_000.0;
         let prefix = log_prefix();
+        let fusion = fusion
+            .map(|fusion| {
+                format!(
+                    " fused={:.4} topics=[{}]",
+                    fusion.inference,
+                    fusion.summary()
+                )
+            })
+            .unwrap_or_default();
         let entry = format!(
-            "{prefix} [MODEL] {asset} market={market_id} active={} true_up={:.4} edge_bps={:.1} z={:.3} shadow={} true_up={:.4} edge_bps={:.1} z={:.3}",
-            active_version.as_str(),
+            "{prefix} [MODEL] {asset} market={market_id} active={} true_up={:.4} edge_bps={:.1} z={:.3} shadow={} true_up={:.4} edge_bps={:.1} z={:.3}{fusion}",
+            active_version,
             active.true_up,
             active_edge_bps,
             active.z_score,
-            shadow_version.as_str(),
+            shadow_version,
             shadow.true_up,
             shadow_edge_bps,
             shadow.z_score
@@ -891,6 +1104,249 @@ impl TradingEngine {
             .await;
     }
 
+    /// Stores one prediction per model and window for the paper tracker to score at settlement.
+    async fn record_model_prediction(
+        &self,
+        state: &mut TraderState,
+        asset: &str,
+        market_id: &str,
+        window: MarketWindow,
+        model: &str,
+        output: ModelOutput,
+        active: bool,
+        implied_up: f64,
+        now_ms: u64,
+    ) {
+        let cache_key = format!("{asset}:{model}");
+        if state.last_model_prediction_window_ms.get(&cache_key) == Some(&window.start_time_ms) {
+            return;
+        }
+        let prediction = ModelPrediction {
+            model: model.to_string(),
+            active,
+            asset: asset.to_string(),
+            market_id: market_id.to_string(),
+            true_up: output.true_up,
+            implied_up,
+            z_score: output.z_score,
+            start_time_ms: window.start_time_ms,
+            end_time_ms: window.end_time_ms,
+            predicted_at_ms: now_ms,
+        };
+        match record_model_prediction(&self.redis, &prediction).await {
+            Ok(()) => {
+                state
+                    .last_model_prediction_window_ms
+                    .insert(cache_key, window.start_time_ms);
+            }
+            Err(error) => {
+                tracing::warn!(?error, asset, model, "failed to record model prediction");
+            }
+        }
+    }
+
+    async fn python_order_size(
+        &self,
+        python: &PythonStrategyBackend,
+        config: &Config,
+        intent: &TradeIntent,
+    ) -> Result<f64> {
+        // Size at the limit price the order will rest at, not the intent's reference price.
+        let price = order_price(&self.redis, &self.orderbook, intent).await?;
+        if price <= 0.0 || price >= 1.0 {
+            return Err(BankaiError::InvalidArgument(
+                "intent price invalid for sizing".to_string(),
+            ));
+        }
+        let kelly = python
+            .calculate_kelly(intent.true_prob, 1.0 / price)
+            .await?;
+        estimate_order_size(
+            &self.redis,
+            kelly,
+            price,
+            &config.execution,
+            &config.strategy,
+        )
+        .await
+    }
+
+    /// Fuses every known Allora timeframe for `asset`, anchored on the aligned primary signal.
+    async fn fuse_topic_signals(
+        &self,
+        state: &mut TraderState,
+        config: &Config,
+        asset: &str,
+        primary: &AlloraMarketUpdate,
+        current_price: f64,
+        now_ms: u64,
+    ) -> Option<FusedSignal> {
+        let primary_timeframe_ms = parse_timeframe_ms(&primary.timeframe)?;
+        let prefix = format!("{asset}:");
+        let mut updates: Vec<(String, AlloraMarketUpdate)> = state
+            .last_allora
+            .iter()
+            .filter(|(key, update)| {
+                key.starts_with(&prefix)
+                    && update.signal_timestamp_ms <= now_ms
+                    && !update.timeframe.eq_ignore_ascii_case(&primary.timeframe)
+            })
+            .map(|(key, update)| (key.clone(), update.clone()))
+            .collect();
+        updates.sort_by(|a, b| a.0.cmp(&b.0));
+        updates.insert(
+            0,
+            (format!("{asset}:{}", primary.timeframe), primary.clone()),
+        );
+
+        let mut inputs = Vec::with_capacity(updates.len());
+        for (key, update) in updates {
+            let Some(timeframe_ms) = parse_timeframe_ms(&update.timeframe) else {
+                continue;
+            };
+            let topic = config
+                .allora_consumer
+                .as_ref()
+                .and_then(|allora| allora.topic(asset, &update.timeframe));
+            inputs.push(FusionInput {
+                timeframe: update.timeframe.clone(),
+                timeframe_ms,
+                inference: update.inference_value,
+                signal_timestamp_ms: update.signal_timestamp_ms,
+                weight: topic.map(|topic| topic.weight).unwrap_or(1.0),
+                half_life_ms: topic
+                    .and_then(|topic| topic.half_life_secs)
+                    .map(|secs| secs.saturating_mul(1000))
+                    .unwrap_or(timeframe_ms),
+                accuracy: self.topic_accuracy(state, &key).await,
+            });
+        }
+        fuse_signals(current_price, primary_timeframe_ms, now_ms, &inputs)
+    }
+
+    async fn topic_accuracy(&self, state: &mut TraderState, topic: &str) -> TopicAccuracy {
+        if let Some(accuracy) = state.topic_accuracy.get(topic) {
+            return *accuracy;
+        }
+        let accuracy = match self.redis.get_topic_accuracy(topic).await {
+            Ok(Some((hits, total))) => TopicAccuracy { hits, total },
+            Ok(None) => TopicAccuracy::default(),
+            Err(error) => {
+                tracing::warn!(?error, topic, "failed to load topic accuracy");
+                TopicAccuracy::default()
+            }
+        };
+        state.topic_accuracy.insert(topic.to_string(), accuracy);
+        accuracy
+    }
+
+    /// Scores each topic's recorded direction once the window's end price is known.
+    async fn settle_topic_scores(&self, state: &mut TraderState, asset: &str, now_ms: u64) {
+        let due: Vec<PendingTopicScore> = state
+            .pending_topic_scores
+            .iter()
+            .filter(|pending| pending.asset == asset && pending.window.end_time_ms <= now_ms)
+            .cloned()
+            .collect();
+        for pending in due {
+            let end_price = match self
+                .redis
+                .get_asset_end_price_window(asset, pending.window.end_time_ms)
+                .await
+            {
+                Ok(Some((_, price))) => Some(price),
+                Ok(None) => None,
+                Err(error) => {
+                    tracing::warn!(?error, asset, "failed to read window end price");
+                    None
+                }
+            };
+            let expired = now_ms.saturating_sub(pending.window.end_time_ms) > TOPIC_SCORE_GRACE_MS;
+            if end_price.is_none() && !expired {
+                continue;
+            }
+            state
+                .pending_topic_scores
+                .retain(|entry| !(entry.asset == pending.asset && entry.window == pending.window));
+            let Some(end_price) = end_price else {
+                continue;
+            };
+            let went_up = end_price >= pending.start_price;
+            for (topic, predicted_up) in &pending.topics {
+                let hit = *predicted_up == went_up;
+                let mut accuracy = self.topic_accuracy(state, topic).await;
+                accuracy.record(hit);
+                state.topic_accuracy.insert(topic.clone(), accuracy);
+                if let Err(error) = self.redis.record_topic_outcome(topic, hit).await {
+                    tracing::warn!(?error, topic = %topic, "failed to record topic outcome");
+                }
+            }
+        }
+    }
+
+    async fn record_regret(
+        &self,
+        asset: &str,
+        window: MarketWindow,
+        intent: &TradeIntent,
+        direction: &str,
+        strategy: &str,
+        fee_bps: f64,
+        blocker: Option<&str>,
+        now_ms: u64,
+    ) {
+        if !self.regret_tracking {
+            return;
+        }
+        let candidate = RegretCandidate {
+            asset: asset.to_string(),
+            market_id: intent.market_id.clone(),
+            direction: direction.to_string(),
+            strategy: strategy.to_string(),
+            mode: match intent.mode {
+                TradeMode::Ladder => "LADDER".to_string(),
+                TradeMode::Snipe => "SNIPE".to_string(),
+            },
+            blocker: blocker.map(str::to_string),
+            implied_prob: intent.implied_prob,
+            true_prob: intent.true_prob,
+            fee_bps,
+            start_time_ms: window.start_time_ms,
+            end_time_ms: window.end_time_ms,
+            recorded_at_ms: now_ms,
+        };
+        if let Err(error) = record_regret_candidate(&self.redis, &candidate).await {
+            tracing::warn!(?error, asset, "failed to record regret candidate");
+        }
+    }
+
+    fn log_python_fallback(
+        &self,
+        state: &mut TraderState,
+        asset: &str,
+        call: &str,
+        error: &BankaiError,
+        now_ms: u64,
+    ) {
+        metrics::increment_python_fallback();
+        let cache_key = format!("{asset}:{call}");
+        let last = state
+            .last_python_fallback_ms
+            .get(&cache_key)
+            .copied()
+            .unwrap_or(0);
+        if now_ms.saturating_sub(last) < 30_000 {
+            return;
+        }
+        state.last_python_fallback_ms.insert(cache_key, now_ms);
+        tracing::warn!(
+            ?error,
+            asset,
+            call,
+            "python strategy call failed; using rust model"
+        );
+    }
+
     async fn check_exit_intent(
         &self,
         _asset: &str,
@@ -953,7 +1409,7 @@ impl TradingEngine {
 
         let edge = (current_price - entry_price) / entry_price;
         let edge_bps = edge * 10_000.0;
-        let now = now_ms()?;
+        let now = self.clock.now_ms()?;
 
         Ok(Some(TradeIntent {
             market_id: market_id.to_string(),
@@ -988,7 +1444,7 @@ impl TradingEngine {
     }
 }
 
-struct TraderState {
+pub struct TraderState {
     last_chainlink: HashMap<String, ChainlinkMarketUpdate>,
     last_allora: HashMap<String, AlloraMarketUpdate>,
     last_intent_ms: HashMap<String, u64>,
@@ -1001,11 +1457,63 @@ struct TraderState {
     last_no_intent_alert_ms: HashMap<String, u64>,
     last_blocker_alert_ms: HashMap<String, u64>,
     last_model_shadow_log_ms: HashMap<String, u64>,
+    last_model_prediction_window_ms: HashMap<String, u64>,
+    last_python_fallback_ms: HashMap<String, u64>,
+    blocker_counts: HashMap<String, u64>,
+    /// Last resolved window per asset; blockers raised inside it go to its timeline.
+    current_window: HashMap<String, MarketWindow>,
+    /// Directional hit counts per Allora topic (`ASSET:timeframe`), mirrored in Redis.
+    topic_accuracy: HashMap<String, TopicAccuracy>,
+    pending_topic_scores: Vec<PendingTopicScore>,
     boot_time_ms: u64,
 }
 
+/// Latest per-topic direction calls for one window, scored when it resolves.
+#[derive(Debug, Clone)]
+struct PendingTopicScore {
+    asset: String,
+    window: MarketWindow,
+    start_price: f64,
+    /// (topic key, predicted UP).
+    topics: Vec<(String, bool)>,
+}
+
+/// Where one blocker occurrence is logged.
+#[derive(Debug, PartialEq, Eq)]
+struct BlockerLogTargets {
+    /// Window timeline that records every occurrence.
+    window_start_ms: Option<u64>,
+    /// Whether the throttled activity-log alert fires.
+    alert: bool,
+}
+
 impl TraderState {
-    fn new(boot_time_ms: u64) -> Self {
+    /// Counts a blocker; only the activity-log alert is throttled per asset and key.
+    fn record_blocker(&mut self, asset: &str, key: &str, now_ms: u64) -> BlockerLogTargets {
+        const THROTTLE_MS: u64 = 30_000;
+        *self.blocker_counts.entry(key.to_string()).or_insert(0) += 1;
+        let window_start_ms = self
+            .current_window
+            .get(asset)
+            .filter(|window| now_ms < window.end_time_ms)
+            .map(|window| window.start_time_ms);
+        let cache_key = format!("{asset}:{key}");
+        let last = self
+            .last_blocker_alert_ms
+            .get(&cache_key)
+            .copied()
+            .unwrap_or(0);
+        let alert = now_ms.saturating_sub(last) >= THROTTLE_MS;
+        if alert {
+            self.last_blocker_alert_ms.insert(cache_key, now_ms);
+        }
+        BlockerLogTargets {
+            window_start_ms,
+            alert,
+        }
+    }
+
+    pub fn new(boot_time_ms: u64) -> Self {
         Self {
             last_chainlink: HashMap::new(),
             last_allora: HashMap::new(),
@@ -1019,9 +1527,53 @@ impl TraderState {
             last_no_intent_alert_ms: HashMap::new(),
             last_blocker_alert_ms: HashMap::new(),
             last_model_shadow_log_ms: HashMap::new(),
+            last_model_prediction_window_ms: HashMap::new(),
+            last_python_fallback_ms: HashMap::new(),
+            blocker_counts: HashMap::new(),
+            current_window: HashMap::new(),
+            topic_accuracy: HashMap::new(),
+            pending_topic_scores: Vec::new(),
             boot_time_ms,
         }
     }
+
+    fn record_topic_directions(
+        &mut self,
+        asset: &str,
+        window: MarketWindow,
+        start_price: f64,
+        fusion: &FusedSignal,
+    ) {
+        let topics = fusion
+            .contributions
+            .iter()
+            .map(|topic| {
+                (
+                    format!("{asset}:{}", topic.timeframe),
+                    topic.inference >= start_price,
+                )
+            })
+            .collect();
+        let pending = PendingTopicScore {
+            asset: asset.to_string(),
+            window,
+            start_price,
+            topics,
+        };
+        match self
+            .pending_topic_scores
+            .iter_mut()
+            .find(|entry| entry.asset == asset && entry.window == window)
+        {
+            Some(entry) => *entry = pending,
+            None => self.pending_topic_scores.push(pending),
+        }
+    }
+
+    /// Blocker hits keyed by reason since the state was created.
+    pub fn blocker_counts(&self) -> &HashMap<String, u64> {
+        &self.blocker_counts
+    }
 }
 
 struct AlignedSignal {
@@ -1030,22 +1582,10 @@ struct AlignedSignal {
     carried_forward: bool,
 }
 
-#[derive(Debug, Clone, Copy)]
-struct SignalContext {
-    direction: i8,
-    confidence: f64,
-}
-
-#[derive(Debug, Clone, Copy)]
-struct ModelOutput {
-    true_up: f64,
-    signal_context: Option<SignalContext>,
-    z_score: f64,
-}
-
-fn select_aligned_5m_signal(
+fn select_aligned_signal(
     updates: &HashMap<String, AlloraMarketUpdate>,
     asset: &str,
+    timeframe: &str,
     window: MarketWindow,
     now_ms: u64,
     horizon_ms: u64,
@@ -1059,7 +1599,7 @@ fn select_aligned_5m_signal(
         if !key.starts_with(&format!("{asset}:")) {
             continue;
         }
-        if !update.timeframe.trim().eq_ignore_ascii_case("5m") {
+        if !update.timeframe.trim().eq_ignore_ascii_case(timeframe) {
             continue;
         }
         if update.signal_timestamp_ms > now_ms {
@@ -1117,169 +1657,6 @@ fn resolve_chainlink_price(update: &ChainlinkMarketUpdate) -> Option<f64> {
     }
 }
 
-fn alignment_horizon_ms(config: &Config, asset: &str) -> u64 {
-    let base_ms = config
-        .execution
-        .signal_alignment_max_secs
-        .saturating_mul(1000);
-    let sol_ms = config
-        .execution
-        .signal_alignment_max_secs_sol
-        .saturating_mul(1000);
-    let selected = if asset.eq_ignore_ascii_case("SOL") && sol_ms > 0 {
-        sol_ms
-    } else {
-        base_ms
-    };
-    if selected > 0 {
-        selected
-    } else {
-        DEFAULT_SIGNAL_HORIZON_MS
-    }
-}
-
-fn resolve_model_version(execution: &ExecutionConfig) -> ExecutionModelVersion {
-    match execution.model_version.trim().to_ascii_lowercase().as_str() {
-        "v2" => ExecutionModelVersion::V2,
-        _ => ExecutionModelVersion::V1,
-    }
-}
-
-#[allow(clippy::too_many_arguments)]
-fn compute_model_output(
-    model: ExecutionModelVersion,
-    market_up_prob: f64,
-    start_price: f64,
-    current_price: f64,
-    predicted_price: f64,
-    volatility_1m: f64,
-    execution: &ExecutionConfig,
-    alignment: f64,
-) -> Option<ModelOutput> {
-    match model {
-        ExecutionModelVersion::V1 => {
-            let z = compute_signal_z_score(
-                start_price,
-                predicted_price,
-                volatility_1m,
-                execution.probability_scale,
-            )
-            .unwrap_or(0.0);
-            Some(ModelOutput {
-                true_up: compute_true_probability_5m(
-                    start_price,
-                    predicted_price,
-                    volatility_1m,
-                    execution.probability_scale,
-                    execution.probability_max_offset,
-                    alignment,
-                ),
-                signal_context: compute_signal_context(
-                    start_price,
-                    predicted_price,
-                    volatility_1m,
-                    execution.probability_scale,
-                    alignment,
-                ),
-                z_score: z,
-            })
-        }
-        ExecutionModelVersion::V2 => {
-            let z = compute_signal_z_score(
-                current_price,
-                predicted_price,
-                volatility_1m,
-                execution.probability_scale,
-            )?;
-            Some(ModelOutput {
-                true_up: compute_true_probability_v2(
-                    market_up_prob,
-                    z,
-                    execution.model_v2_k,
-                    alignment,
-                ),
-                signal_context: compute_signal_context(
-                    current_price,
-                    predicted_price,
-                    volatility_1m,
-                    execution.probability_scale,
-                    alignment,
-                ),
-                z_score: z,
-            })
-        }
-    }
-}
-
-fn compute_signal_context(
-    current_price: f64,
-    predicted_price: f64,
-    volatility_1m: f64,
-    scale: f64,
-    alignment: f64,
-) -> Option<SignalContext> {
-    if current_price <= 0.0 {
-        return None;
-    }
-    let delta = (predicted_price - current_price) / current_price;
-    let volatility_5m = (volatility_1m * SQRT_5).max(1e-9);
-    let z = delta / volatility_5m;
-    let z_scaled = z * scale;
-    let confidence = z_scaled.abs().tanh() * alignment;
-    let direction = if z_scaled > 0.0 {
-        SIGNAL_DIR_UP
-    } else if z_scaled < 0.0 {
-        SIGNAL_DIR_DOWN
-    } else {
-        0
-    };
-    Some(SignalContext {
-        direction,
-        confidence: confidence.clamp(0.0, 1.0),
-    })
-}
-
-fn compute_signal_z_score(
-    current_price: f64,
-    predicted_price: f64,
-    volatility_1m: f64,
-    scale: f64,
-) -> Option<f64> {
-    if current_price <= 0.0 {
-        return None;
-    }
-    let delta = (predicted_price - current_price) / current_price;
-    let volatility_5m = (volatility_1m * SQRT_5).max(1e-9);
-    let z = delta / volatility_5m;
-    Some(z * scale)
-}
-
-fn compute_true_probability_v2(market_up_prob: f64, z_score: f64, k: f64, alignment: f64) -> f64 {
-    let prior = clamp_probability(market_up_prob);
-    let update = z_score * k * alignment;
-    let posterior_logit = logit(prior) + update;
-    sigmoid(posterior_logit).clamp(0.01, 0.99)
-}
-
-fn clamp_probability(value: f64) -> f64 {
-    value.clamp(0.01, 0.99)
-}
-
-fn logit(value: f64) -> f64 {
-    let p = clamp_probability(value);
-    (p / (1.0 - p)).ln()
-}
-
-fn sigmoid(value: f64) -> f64 {
-    if value >= 0.0 {
-        let exp = (-value).exp();
-        1.0 / (1.0 + exp)
-    } else {
-        let exp = value.exp();
-        exp / (1.0 + exp)
-    }
-}
-
 fn signal_allows_direction(
     execution: &ExecutionConfig,
     signal: Option<&SignalContext>,
@@ -1292,6 +1669,10 @@ fn signal_allows_direction(
     let Some(signal) = signal else {
         return false;
     };
+    if execution.signal_confidence_min > 0.0 && signal.confidence < execution.signal_confidence_min
+    {
+        return false;
+    }
     if signal.direction == 0 || signal.direction == expected_direction {
         return true;
     }
@@ -1302,24 +1683,6 @@ fn signal_allows_direction(
         && signal.confidence >= execution.contrarian_confidence_min
 }
 
-fn compute_true_probability_5m(
-    current_price: f64,
-    predicted_price: f64,
-    volatility_1m: f64,
-    scale: f64,
-    max_offset: f64,
-    alignment: f64,
-) -> f64 {
-    if current_price <= 0.0 {
-        return 0.5;
-    }
-    let delta = (predicted_price - current_price) / current_price;
-    let volatility_5m = (volatility_1m * SQRT_5).max(1e-9);
-    let z = delta / volatility_5m;
-    let offset = (z * scale).tanh() * max_offset * alignment;
-    (0.5 + offset).clamp(0.01, 0.99)
-}
-
 fn fee_config_for_token(config: &Config, fee_rate_bps: Option<f64>) -> (FeeConfig, bool) {
     let mut fees = config.fees.clone();
     if let Some(value) = fee_rate_bps {
@@ -1340,30 +1703,29 @@ async fn estimate_snipe_vwap(
     if mid_price <= 0.0 {
         return Ok(None);
     }
-    let size = estimate_order_size(
-        redis,
-        true_prob,
-        mid_price,
-        &config.execution,
-        &config.strategy,
-    )
-    .await?;
+    let kelly = calculate_kelly(true_prob, 1.0 / mid_price);
+    let size =
+        estimate_order_size(redis, kelly, mid_price, &config.execution, &config.strategy).await?;
     let vwap = orderbook
         .vwap_for_size(token_id, BookSide::Ask, size, 50)
         .await?;
     Ok(vwap.map(|value| value.avg_price))
 }
 
+/// The Python probability replaces the Rust model's only when the call succeeded in budget.
+fn apply_python_true_up(model: &mut ModelOutput, python: Result<f64>) -> Result<()> {
+    model.true_up = python?.clamp(0.01, 0.99);
+    Ok(())
+}
+
 async fn estimate_order_size(
     redis: &RedisManager,
-    true_prob: f64,
+    kelly: f64,
     price: f64,
     execution: &crate::config::ExecutionConfig,
     strategy: &crate::config::StrategyConfig,
 ) -> Result<f64> {
     let bankroll = redis.get_float("sys:bankroll:usdc").await?;
-    let odds = if price > 0.0 { 1.0 / price } else { 0.0 };
-    let kelly = calculate_kelly(true_prob, odds);
     let target = if let Some(bankroll) = bankroll {
         bankroll * strategy.kelly_fraction * kelly
     } else {
@@ -1381,19 +1743,6 @@ async fn estimate_order_size(
     Ok(notional / price)
 }
 
-fn calculate_kelly(win_prob: f64, odds: f64) -> f64 {
-    if win_prob <= 0.0 || win_prob >= 1.0 {
-        return 0.0;
-    }
-    if odds <= 1.0 {
-        return 0.0;
-    }
-    let payout = odds - 1.0;
-    let loss_prob = 1.0 - win_prob;
-    let kelly = (win_prob * payout - loss_prob) / payout;
-    kelly.clamp(0.0, 1.0)
-}
-
 async fn is_orderbook_stale(
     orderbook: &OrderBookStore,
     token_id: &str,
@@ -1458,11 +1807,6 @@ async fn fetch_outcomes_from_gamma(
     }
 }
 
-fn now_ms() -> Result<u64> {
-    let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
-    Ok(now.as_millis() as u64)
-}
-
 fn log_prefix() -> String {
     let now = Utc::now();
     let et = now.with_timezone(&New_York);
@@ -1475,8 +1819,10 @@ mod tests {
 
     #[test]
     fn signal_direction_gate_allows_when_disabled() {
-        let mut execution = ExecutionConfig::default();
-        execution.signal_direction_gate = false;
+        let execution = ExecutionConfig {
+            signal_direction_gate: false,
+            ..Default::default()
+        };
         let signal = SignalContext {
             direction: SIGNAL_DIR_DOWN,
             confidence: 0.9,
@@ -1491,9 +1837,11 @@ mod tests {
 
     #[test]
     fn signal_direction_gate_blocks_contrarian_without_override() {
-        let mut execution = ExecutionConfig::default();
-        execution.signal_direction_gate = true;
-        execution.contrarian_min_edge_bps = 0.0;
+        let execution = ExecutionConfig {
+            signal_direction_gate: true,
+            contrarian_min_edge_bps: 0.0,
+            ..Default::default()
+        };
         let signal = SignalContext {
             direction: SIGNAL_DIR_DOWN,
             confidence: 0.9,
@@ -1506,12 +1854,43 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn signal_direction_gate_blocks_low_confidence() {
+        let execution = ExecutionConfig {
+            signal_direction_gate: true,
+            signal_confidence_min: 0.3,
+            ..Default::default()
+        };
+        let weak = SignalContext {
+            direction: SIGNAL_DIR_UP,
+            confidence: 0.2,
+        };
+        assert!(!signal_allows_direction(
+            &execution,
+            Some(&weak),
+            SIGNAL_DIR_UP,
+            100.0
+        ));
+        let strong = SignalContext {
+            direction: SIGNAL_DIR_UP,
+            confidence: 0.5,
+        };
+        assert!(signal_allows_direction(
+            &execution,
+            Some(&strong),
+            SIGNAL_DIR_UP,
+            100.0
+        ));
+    }
+
     #[test]
     fn signal_direction_gate_allows_contrarian_with_override() {
-        let mut execution = ExecutionConfig::default();
-        execution.signal_direction_gate = true;
-        execution.contrarian_min_edge_bps = 1500.0;
-        execution.contrarian_confidence_min = 0.7;
+        let execution = ExecutionConfig {
+            signal_direction_gate: true,
+            contrarian_min_edge_bps: 1500.0,
+            contrarian_confidence_min: 0.7,
+            ..Default::default()
+        };
         let signal = SignalContext {
             direction: SIGNAL_DIR_DOWN,
             confidence: 0.9,
@@ -1524,36 +1903,114 @@ mod tests {
         ));
     }
 
-    #[test]
-    fn resolve_model_version_defaults_to_v1() {
-        let mut execution = ExecutionConfig::default();
-        execution.model_version = "unknown".to_string();
-        assert_eq!(resolve_model_version(&execution), ExecutionModelVersion::V1);
-        execution.model_version = "v2".to_string();
-        assert_eq!(resolve_model_version(&execution), ExecutionModelVersion::V2);
-    }
+    const FALLBACK_MODULE: &str = "
+import time
 
-    #[test]
-    fn v2_probability_moves_with_signal_direction() {
-        let up = compute_true_probability_v2(0.55, 1.2, 0.5, 1.0);
-        let down = compute_true_probability_v2(0.55, -1.2, 0.5, 1.0);
-        assert!(up > 0.55);
-        assert!(down < 0.55);
-    }
+def true_probability(implied_up, start_price, current_price, inference, volatility_1m, alignment,
+                     probability_scale, probability_max_offset):
+    if inference > start_price:
+        time.sleep(0.5)
+        return 0.9
+    raise ValueError('model failed')
+
+def calculate_kelly(win_prob, odds):
+    return 0.1
+";
 
     #[test]
-    fn v2_alignment_scales_adjustment() {
-        let full_align = compute_true_probability_v2(0.60, 1.0, 0.7, 1.0);
-        let weak_align = compute_true_probability_v2(0.60, 1.0, 0.7, 0.2);
-        assert!(full_align > weak_align);
-        assert!(weak_align > 0.60);
+    fn python_timeout_and_error_fall_back_to_rust_probability() {
+        let dir = std::env::temp_dir().join(format!("bankai_py_fallback_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("temp dir");
+        let module_name = "bankai_fallback_probe";
+        std::fs::write(dir.join(format!("{module_name}.py")), FALLBACK_MODULE)
+            .expect("write module");
+        let config = crate::config::PythonStrategyConfig {
+            enabled: true,
+            module: module_name.to_string(),
+            module_dir: dir.to_string_lossy().into_owned(),
+            timeout_ms: 50,
+            sizing: false,
+            hot_reload: false,
+        };
+        let input = |inference: f64| ModelInput {
+            implied_up: 0.5,
+            start_price: 100.0,
+            current_price: 100.0,
+            inference,
+            volatility_1m: 0.01,
+            alignment: 1.0,
+            distribution: None,
+        };
+        let rust = ModelOutput {
+            true_up: 0.62,
+            signal_context: None,
+            z_score: 0.4,
+        };
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .expect("runtime");
+        let (timed_out, failed) = runtime.block_on(async {
+            let python = PythonStrategyBackend::spawn(&config).await.expect("spawn");
+            let execution = ExecutionConfig::default();
+            let timed_out = python.true_probability(input(101.0), &execution).await;
+            // Let the slow call drain so the error case is not reported as busy.
+            tokio::time::sleep(Duration::from_millis(600)).await;
+            let failed = python.true_probability(input(99.0), &execution).await;
+            (timed_out, failed)
+        });
+
+        for result in [timed_out, failed] {
+            let mut active = rust;
+            assert!(apply_python_true_up(&mut active, result).is_err());
+            assert_eq!(active.true_up, 0.62);
+        }
+        let mut active = rust;
+        apply_python_true_up(&mut active, Ok(1.4)).expect("python probability");
+        assert_eq!(active.true_up, 0.99);
+
+        let _ = std::fs::remove_dir_all(&dir);
     }
 
     #[test]
-    fn v2_uses_market_prior() {
-        let z = 0.2;
-        let low_prior = compute_true_probability_v2(0.35, z, 0.5, 1.0);
-        let high_prior = compute_true_probability_v2(0.75, z, 0.5, 1.0);
-        assert!(high_prior > low_prior);
+    fn every_blocker_reaches_the_window_log_while_alerts_throttle() {
+        const T0: u64 = 1_700_000_000_000;
+        let mut state = TraderState::new(T0);
+        state.current_window.insert(
+            "BTC".to_string(),
+            MarketWindow {
+                start_time_ms: T0,
+                end_time_ms: T0 + 900_000,
+            },
+        );
+
+        let first = state.record_blocker("BTC", "stale_book", T0 + 10_000);
+        let second = state.record_blocker("BTC", "stale_book", T0 + 25_000);
+        assert_eq!(
+            first,
+            BlockerLogTargets {
+                window_start_ms: Some(T0),
+                alert: true,
+            }
+        );
+        assert_eq!(
+            second,
+            BlockerLogTargets {
+                window_start_ms: Some(T0),
+                alert: false,
+            }
+        );
+        assert_eq!(state.blocker_counts.get("stale_book"), Some(&2));
+
+        state.current_window.insert(
+            "BTC".to_string(),
+            MarketWindow {
+                start_time_ms: T0 + 900_000,
+                end_time_ms: T0 + 1_800_000,
+            },
+        );
+        let rolled = state.record_blocker("BTC", "stale_book", T0 + 905_000);
+        assert_eq!(rolled.window_start_ms, Some(T0 + 900_000));
     }
 }
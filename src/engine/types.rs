@@ -3,14 +3,19 @@
  * Shared engine data types for cross-module messaging.
  *
  * @dependencies
- * - None
+ * - serde: replay serialization for oracle updates
  *
  * @notes
  * - Keep payloads lightweight for hot-path delivery.
+ * - Oracle updates are serializable so recorded feeds can be replayed.
  */
+use serde::{Deserialize, Serialize};
+
 #[derive(Debug, Clone)]
 pub enum MarketUpdate {
     Chainlink(ChainlinkMarketUpdate),
+    /// Binance reference price; same payload shape as Chainlink so it can stand in as a fallback.
+    Binance(ChainlinkMarketUpdate),
     Allora(AlloraMarketUpdate),
 }
 
@@ -26,7 +31,7 @@ pub enum TradeSide {
     Sell,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct MarketWindow {
     pub start_time_ms: u64,
     pub end_time_ms: u64,
@@ -48,7 +53,7 @@ pub struct TradeIntent {
     pub requested_size: Option<f64>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChainlinkMarketUpdate {
     pub asset: String,
     pub last_price: Option<f64>,
@@ -57,15 +62,22 @@ pub struct ChainlinkMarketUpdate {
     pub event_time_ms: u64,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AlloraMarketUpdate {
     pub topic_id: u64,
     pub inference_value: f64,
     pub inference_raw: Option<String>,
     pub token_decimals: Option<u32>,
     pub signature: Option<String>,
+    /// Signature check result; `None` when verification is off.
+    #[serde(default)]
+    pub signature_verified: Option<bool>,
     pub request_id: Option<String>,
+    /// Predicted prices at `confidence_percentiles`, normalized like `inference_value`.
     pub confidence_intervals: Vec<f64>,
+    /// Percentiles (0-100) for each `confidence_intervals` value; empty when not published.
+    #[serde(default)]
+    pub confidence_percentiles: Vec<f64>,
     pub signal_timestamp_ms: u64,
     pub received_at_ms: u64,
     pub asset: String,
@@ -30,6 +30,8 @@ pub enum BankaiError {
     Base64(#[from] base64::DecodeError),
     #[error("http error: {0}")]
     Http(#[from] reqwest::Error),
+    #[error("http server error: {0}")]
+    HttpServer(#[from] hyper::Error),
     #[error("redis error: {0}")]
     Redis(#[from] redis::RedisError),
     #[error("database error: {0}")]
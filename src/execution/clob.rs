@@ -0,0 +1,1030 @@
+/**
+ * @purpose
+ * Authenticated Polymarket CLOB client shared by order posting, cancels, trade and
+ * open-order reconciliation, and fee-rate lookups.
+ *
+ * @dependencies
+ * - reqwest: HTTP client
+ * - hmac/sha2/base64: L2 request signatures
+ * - arc-swap: credentials swapped in place after re-derivation
+ * - tokio: rate limiter waits and retry backoff
+ * - metrics: per-endpoint latency, outcome, retry and throttling counters
+ *
+ * @notes
+ * - L2 headers are signed per attempt, so a retry never reuses a stale timestamp.
+ * - Each endpoint class draws from its own token bucket; clones share the buckets, so build
+ *   one client at startup and hand out clones.
+ * - Reads and cancels retry on transient `RelayerErrorKind`s. Order posts are sent once;
+ *   the orchestrator owns order retries and rail failover.
+ * - API keys are derived (or created) from the wallet with an L1 ClobAuth signature. With a
+ *   credential refresh attached, a 401 re-derives the key once, swaps it in for every clone,
+ *   writes it back to `secrets.enc`, and replays the request.
+ */
+use arc_swap::ArcSwapOption;
+use base64::engine::general_purpose;
+use base64::Engine as _;
+use hmac::{Hmac, Mac};
+use reqwest::header::{HeaderMap, HeaderValue};
+use reqwest::{Client, Method, StatusCode, Url};
+use secrecy::ExposeSecret;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::Value;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::config::{ClobRateLimitConfig, Config};
+use crate::error::{BankaiError, Result};
+use crate::execution::relayer::{
+    apply_builder_headers, duration_to_ms, extract_request_id, read_response_body, status_class,
+    RelayerBuilderAuth, RelayerError, RelayerResponse, RelayerResult,
+};
+use crate::execution::signer::Eip712Signer;
+use crate::security::{Secrets, SecretsFile, SecretsPayload};
+use crate::telemetry::metrics;
+
+type HmacSha256 = Hmac<sha2::Sha256>;
+
+const DEFAULT_ORDER_PATH: &str = "/order";
+const DEFAULT_CANCEL_ORDER_PATH: &str = "/order";
+const DEFAULT_CANCEL_MARKET_PATH: &str = "/cancel-market-orders";
+const DEFAULT_CANCEL_ALL_PATH: &str = "/cancel-all";
+const DEFAULT_ORDERS_PATH: &str = "/data/orders";
+const DEFAULT_TRADES_PATH: &str = "/data/trades";
+const DEFAULT_FEE_RATE_PATH: &str = "/fee-rate";
+const DEFAULT_API_KEY_PATH: &str = "/auth/api-key";
+const DEFAULT_DERIVE_API_KEY_PATH: &str = "/auth/derive-api-key";
+const RATE_LIMIT_WINDOW_SECS: f64 = 10.0;
+
+const HEADER_POLY_ADDRESS: &str = "POLY_ADDRESS";
+const HEADER_POLY_API_KEY: &str = "POLY_API_KEY";
+const HEADER_POLY_NONCE: &str = "POLY_NONCE";
+const HEADER_POLY_PASSPHRASE: &str = "POLY_PASSPHRASE";
+const HEADER_POLY_SIGNATURE: &str = "POLY_SIGNATURE";
+const HEADER_POLY_TIMESTAMP: &str = "POLY_TIMESTAMP";
+
+const ENV_CLOB_BASE_URL: &str = "POLYMARKET_CLOB_URL";
+const ENV_CANCEL_MARKET_PATH: &str = "POLYMARKET_CANCEL_MARKET_PATH";
+const ENV_CANCEL_ORDER_PATH: &str = "POLYMARKET_CANCEL_ORDER_PATH";
+const ENV_CANCEL_ALL_PATH: &str = "POLYMARKET_CANCEL_ALL_PATH";
+const ENV_ORDERS_PATH: &str = "POLYMARKET_ORDERS_PATH";
+const ENV_OPEN_ORDERS_PATH: &str = "POLYMARKET_OPEN_ORDERS_PATH";
+const ENV_TRADES_PATH: &str = "POLYMARKET_TRADES_PATH";
+
+/// CLOB endpoints routed through the client; each maps to a rate-limit class.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClobEndpoint {
+    PostOrder,
+    CancelOrder,
+    CancelMarket,
+    CancelAll,
+    OpenOrders,
+    Trades,
+    FeeRate,
+    CreateApiKey,
+    DeriveApiKey,
+    DeleteApiKey,
+}
+
+impl ClobEndpoint {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ClobEndpoint::PostOrder => "post_order",
+            ClobEndpoint::CancelOrder => "cancel_order",
+            ClobEndpoint::CancelMarket => "cancel_market",
+            ClobEndpoint::CancelAll => "cancel_all",
+            ClobEndpoint::OpenOrders => "open_orders",
+            ClobEndpoint::Trades => "trades",
+            ClobEndpoint::FeeRate => "fee_rate",
+            ClobEndpoint::CreateApiKey => "create_api_key",
+            ClobEndpoint::DeriveApiKey => "derive_api_key",
+            ClobEndpoint::DeleteApiKey => "delete_api_key",
+        }
+    }
+
+    fn method(self) -> Method {
+        match self {
+            ClobEndpoint::PostOrder | ClobEndpoint::CreateApiKey => Method::POST,
+            ClobEndpoint::CancelOrder
+            | ClobEndpoint::CancelMarket
+            | ClobEndpoint::CancelAll
+            | ClobEndpoint::DeleteApiKey => Method::DELETE,
+            ClobEndpoint::OpenOrders
+            | ClobEndpoint::Trades
+            | ClobEndpoint::FeeRate
+            | ClobEndpoint::DeriveApiKey => Method::GET,
+        }
+    }
+
+    /// Whether the endpoint is signed with L2 API credentials.
+    fn authenticated(self) -> bool {
+        !matches!(
+            self,
+            ClobEndpoint::FeeRate | ClobEndpoint::CreateApiKey | ClobEndpoint::DeriveApiKey
+        )
+    }
+
+    fn retryable(self) -> bool {
+        !matches!(self, ClobEndpoint::PostOrder | ClobEndpoint::CreateApiKey)
+    }
+}
+
+/// Runtime settings for the CLOB client: endpoint paths, timeouts, retries and rate limits.
+#[derive(Debug, Clone)]
+pub struct ClobClientConfig {
+    pub base_url: String,
+    pub order_path: String,
+    pub cancel_order_path: String,
+    pub cancel_market_path: String,
+    pub cancel_all_path: String,
+    pub orders_path: String,
+    pub trades_path: String,
+    pub fee_rate_path: String,
+    pub api_key_path: String,
+    pub derive_api_key_path: String,
+    pub request_timeout: Duration,
+    pub order_timeout: Duration,
+    pub max_retries: u32,
+    pub backoff_ms: u64,
+    pub backoff_max_ms: u64,
+    pub rate_limits: ClobRateLimitConfig,
+}
+
+impl ClobClientConfig {
+    pub fn new(base_url: String) -> Self {
+        Self::from_parts(base_url, &crate::config::ClobConfig::default())
+    }
+
+    /// Builds the config from `clob` settings, with `POLYMARKET_*` env overrides for the
+    /// base URL and endpoint paths.
+    pub fn from_config(config: &Config) -> Self {
+        let base_url = read_env_value(ENV_CLOB_BASE_URL)
+            .unwrap_or_else(|| config.endpoints.relayer_http.clone());
+        let mut clob = Self::from_parts(base_url, &config.clob);
+        if let Some(path) = read_env_value(ENV_CANCEL_MARKET_PATH) {
+            clob.cancel_market_path = path;
+        }
+        if let Some(path) = read_env_value(ENV_CANCEL_ORDER_PATH) {
+            clob.cancel_order_path = path;
+        }
+        if let Some(path) = read_env_value(ENV_CANCEL_ALL_PATH) {
+            clob.cancel_all_path = path;
+        }
+        if let Some(path) =
+            read_env_value(ENV_ORDERS_PATH).or_else(|| read_env_value(ENV_OPEN_ORDERS_PATH))
+        {
+            clob.orders_path = path;
+        }
+        if let Some(path) = read_env_value(ENV_TRADES_PATH) {
+            clob.trades_path = path;
+        }
+        clob
+    }
+
+    fn from_parts(base_url: String, clob: &crate::config::ClobConfig) -> Self {
+        Self {
+            base_url,
+            order_path: DEFAULT_ORDER_PATH.to_string(),
+            cancel_order_path: DEFAULT_CANCEL_ORDER_PATH.to_string(),
+            cancel_market_path: DEFAULT_CANCEL_MARKET_PATH.to_string(),
+            cancel_all_path: DEFAULT_CANCEL_ALL_PATH.to_string(),
+            orders_path: DEFAULT_ORDERS_PATH.to_string(),
+            trades_path: DEFAULT_TRADES_PATH.to_string(),
+            fee_rate_path: DEFAULT_FEE_RATE_PATH.to_string(),
+            api_key_path: DEFAULT_API_KEY_PATH.to_string(),
+            derive_api_key_path: DEFAULT_DERIVE_API_KEY_PATH.to_string(),
+            request_timeout: Duration::from_millis(clob.request_timeout_ms),
+            order_timeout: Duration::from_millis(clob.order_timeout_ms),
+            max_retries: clob.max_retries,
+            backoff_ms: clob.backoff_ms,
+            backoff_max_ms: clob.backoff_max_ms,
+            rate_limits: clob.rate_limits.clone(),
+        }
+    }
+
+    fn path(&self, endpoint: ClobEndpoint) -> &str {
+        match endpoint {
+            ClobEndpoint::PostOrder => &self.order_path,
+            ClobEndpoint::CancelOrder => &self.cancel_order_path,
+            ClobEndpoint::CancelMarket => &self.cancel_market_path,
+            ClobEndpoint::CancelAll => &self.cancel_all_path,
+            ClobEndpoint::OpenOrders => &self.orders_path,
+            ClobEndpoint::Trades => &self.trades_path,
+            ClobEndpoint::FeeRate => &self.fee_rate_path,
+            ClobEndpoint::CreateApiKey | ClobEndpoint::DeleteApiKey => &self.api_key_path,
+            ClobEndpoint::DeriveApiKey => &self.derive_api_key_path,
+        }
+    }
+}
+
+/// L2 API credentials bound to the wallet address sent in `POLY_ADDRESS`.
+#[derive(Clone)]
+pub struct ClobCredentials {
+    pub address: String,
+    pub api_key: String,
+    pub api_secret: String,
+    pub api_passphrase: String,
+}
+
+impl ClobCredentials {
+    /// Returns `None` unless key, secret and passphrase are all present and non-empty.
+    pub fn from_secrets(secrets: &Secrets, address: &str) -> Option<Self> {
+        let read = |value: Option<&secrecy::SecretString>| {
+            value
+                .map(|value| value.expose_secret().trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+        Some(Self {
+            address: address.to_string(),
+            api_key: read(secrets.polymarket_api_key.as_ref())?,
+            api_secret: read(secrets.polymarket_api_secret.as_ref())?,
+            api_passphrase: read(secrets.polymarket_api_passphrase.as_ref())?,
+        })
+    }
+
+    /// Stores the key, secret and passphrase into a secrets payload.
+    pub fn write_to(&self, payload: &mut SecretsPayload) {
+        payload.polymarket_api_key = Some(self.api_key.clone());
+        payload.polymarket_api_secret = Some(self.api_secret.clone());
+        payload.polymarket_api_passphrase = Some(self.api_passphrase.clone());
+    }
+
+    /// Builds the five `POLY_*` L2 headers for one request.
+    pub fn l2_headers(&self, method: &str, path: &str, body: &str) -> Result<HeaderMap> {
+        let timestamp = current_unix_timestamp();
+        let signature = build_hmac_signature(&self.api_secret, timestamp, method, path, body)?;
+
+        let mut headers = HeaderMap::new();
+        headers.insert(HEADER_POLY_ADDRESS, header_value(&self.address)?);
+        headers.insert(HEADER_POLY_API_KEY, header_value(&self.api_key)?);
+        headers.insert(HEADER_POLY_PASSPHRASE, header_value(&self.api_passphrase)?);
+        headers.insert(HEADER_POLY_SIGNATURE, header_value(&signature)?);
+        headers.insert(HEADER_POLY_TIMESTAMP, header_value(&timestamp.to_string())?);
+        Ok(headers)
+    }
+}
+
+/// Builds the four `POLY_*` L1 headers from a fresh ClobAuth signature.
+pub async fn l1_headers(signer: &Eip712Signer, nonce: u64) -> Result<HeaderMap> {
+    let timestamp = current_unix_timestamp().to_string();
+    let signature = signer.sign_clob_auth(&timestamp, nonce).await?;
+
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        HEADER_POLY_ADDRESS,
+        header_value(&signer.checksum_address())?,
+    );
+    headers.insert(
+        HEADER_POLY_SIGNATURE,
+        header_value(&format!("0x{signature}"))?,
+    );
+    headers.insert(HEADER_POLY_TIMESTAMP, header_value(&timestamp)?);
+    headers.insert(HEADER_POLY_NONCE, header_value(&nonce.to_string())?);
+    Ok(headers)
+}
+
+impl fmt::Debug for ClobCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClobCredentials")
+            .field("address", &self.address)
+            .field("api_key", &self.api_key)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CancelResponse {
+    #[serde(default)]
+    pub canceled: Vec<String>,
+    #[serde(default)]
+    pub not_canceled: Value,
+}
+
+#[derive(Deserialize)]
+struct ApiKeyResponse {
+    #[serde(rename = "apiKey")]
+    api_key: String,
+    secret: String,
+    passphrase: String,
+}
+
+impl ApiKeyResponse {
+    fn into_credentials(self, address: String) -> Result<ClobCredentials> {
+        if self.api_key.trim().is_empty()
+            || self.secret.trim().is_empty()
+            || self.passphrase.trim().is_empty()
+        {
+            return Err(BankaiError::Rpc(
+                "clob api key response is missing fields".to_string(),
+            ));
+        }
+        Ok(ClobCredentials {
+            address,
+            api_key: self.api_key,
+            api_secret: self.secret,
+            api_passphrase: self.passphrase,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct FeeRateResponse {
+    #[serde(rename = "fee_rate_bps")]
+    fee_rate_bps: Option<f64>,
+    #[serde(rename = "base_fee")]
+    base_fee: Option<f64>,
+}
+
+/// Wallet signer and secrets file used to re-derive API credentials after a 401.
+#[derive(Debug)]
+struct CredentialRefresh {
+    signer: Eip712Signer,
+    nonce: u64,
+    secrets: Option<SecretsFile>,
+    lock: tokio::sync::Mutex<()>,
+}
+
+/// L1 auth for the API-key endpoints; the ClobAuth signature is taken per attempt.
+#[derive(Clone, Copy)]
+struct L1Auth<'a> {
+    signer: &'a Eip712Signer,
+    nonce: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct ClobClient {
+    config: Arc<ClobClientConfig>,
+    client: Client,
+    credentials: Arc<ArcSwapOption<ClobCredentials>>,
+    refresh: Option<Arc<CredentialRefresh>>,
+    limiter: Arc<ClobRateLimiter>,
+}
+
+impl ClobClient {
+    pub fn new(config: ClobClientConfig, credentials: Option<ClobCredentials>) -> Result<Self> {
+        if config.base_url.trim().is_empty() {
+            return Err(BankaiError::InvalidArgument(
+                "clob base url is required".to_string(),
+            ));
+        }
+        Url::parse(config.base_url.trim())
+            .map_err(|err| BankaiError::InvalidArgument(format!("invalid clob base url: {err}")))?;
+        let client = Client::builder().timeout(config.request_timeout).build()?;
+        let limiter = Arc::new(ClobRateLimiter::new(&config.rate_limits));
+        Ok(Self {
+            config: Arc::new(config),
+            client,
+            credentials: Arc::new(ArcSwapOption::new(credentials.map(Arc::new))),
+            refresh: None,
+            limiter,
+        })
+    }
+
+    /// Re-derives credentials with `signer` when an authenticated request returns 401,
+    /// persisting them to `secrets` when given.
+    pub fn with_credential_refresh(
+        mut self,
+        signer: Eip712Signer,
+        nonce: u64,
+        secrets: Option<SecretsFile>,
+    ) -> Self {
+        self.refresh = Some(Arc::new(CredentialRefresh {
+            signer,
+            nonce,
+            secrets,
+            lock: tokio::sync::Mutex::new(()),
+        }));
+        self
+    }
+
+    pub fn config(&self) -> &ClobClientConfig {
+        &self.config
+    }
+
+    pub fn has_credentials(&self) -> bool {
+        self.credentials.load().is_some()
+    }
+
+    pub fn credentials(&self) -> Option<Arc<ClobCredentials>> {
+        self.credentials.load_full()
+    }
+
+    /// Current API key; changes after a re-derivation.
+    pub fn api_key(&self) -> Option<String> {
+        self.credentials
+            .load()
+            .as_ref()
+            .map(|credentials| credentials.api_key.clone())
+    }
+
+    /// Replaces the credentials used by this client and all of its clones.
+    pub fn set_credentials(&self, credentials: ClobCredentials) {
+        self.credentials.store(Some(Arc::new(credentials)));
+    }
+
+    /// Creates a new API key for the wallet at `nonce`.
+    pub async fn create_api_key(
+        &self,
+        signer: &Eip712Signer,
+        nonce: u64,
+    ) -> Result<ClobCredentials> {
+        self.api_key_request(ClobEndpoint::CreateApiKey, signer, nonce)
+            .await
+    }
+
+    /// Derives the existing API key for the wallet at `nonce`.
+    pub async fn derive_api_key(
+        &self,
+        signer: &Eip712Signer,
+        nonce: u64,
+    ) -> Result<ClobCredentials> {
+        self.api_key_request(ClobEndpoint::DeriveApiKey, signer, nonce)
+            .await
+    }
+
+    /// Derives the key at `nonce`, creating one when none exists yet.
+    pub async fn derive_or_create_api_key(
+        &self,
+        signer: &Eip712Signer,
+        nonce: u64,
+    ) -> Result<ClobCredentials> {
+        match self.derive_api_key(signer, nonce).await {
+            Ok(credentials) => Ok(credentials),
+            Err(error) => {
+                tracing::info!(?error, nonce, "clob api key derive failed; creating");
+                self.create_api_key(signer, nonce).await
+            }
+        }
+    }
+
+    /// Revokes the API key currently held by the client.
+    pub async fn delete_api_key(&self) -> Result<Value> {
+        self.request_json(ClobEndpoint::DeleteApiKey, &[], String::new())
+            .await
+    }
+
+    /// Posts a signed order. Sent once; failures carry a `RelayerErrorKind` for the
+    /// orchestrator's retry and failover decisions.
+    pub async fn post_order(
+        &self,
+        payload: &Value,
+        builder: Option<&RelayerBuilderAuth>,
+    ) -> RelayerResult<RelayerResponse> {
+        let body = serde_json::to_string(payload)
+            .map_err(|err| RelayerError::invalid_request(format!("payload json error: {err}")))?;
+        self.execute(ClobEndpoint::PostOrder, &[], body, builder, None)
+            .await
+    }
+
+    pub async fn cancel_order(&self, order_id: &str) -> Result<CancelResponse> {
+        if order_id.trim().is_empty() {
+            return Err(BankaiError::InvalidArgument(
+                "order_id is required".to_string(),
+            ));
+        }
+        let body = serde_json::json!({ "orderID": order_id }).to_string();
+        self.request_json(ClobEndpoint::CancelOrder, &[], body)
+            .await
+    }
+
+    pub async fn cancel_market_orders(
+        &self,
+        market_id: &str,
+        asset_id: &str,
+    ) -> Result<CancelResponse> {
+        let body = serde_json::json!({
+            "market": market_id,
+            "asset_id": asset_id,
+        })
+        .to_string();
+        self.request_json(ClobEndpoint::CancelMarket, &[], body)
+            .await
+    }
+
+    /// Cancels every open order for the authenticated account.
+    pub async fn cancel_all(&self) -> Result<CancelResponse> {
+        self.request_json(ClobEndpoint::CancelAll, &[], String::new())
+            .await
+    }
+
+    /// Fetches open orders, optionally filtered by token and status.
+    pub async fn open_orders<T: DeserializeOwned>(
+        &self,
+        asset_id: Option<&str>,
+        status: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<T>> {
+        let mut query = Vec::new();
+        if let Some(asset_id) = asset_id.map(str::trim).filter(|value| !value.is_empty()) {
+            query.push(("asset_id", asset_id.to_string()));
+        }
+        if let Some(status) = status {
+            query.push(("status", status.to_string()));
+        }
+        if limit > 0 {
+            query.push(("limit", limit.to_string()));
+        }
+        self.request_json(ClobEndpoint::OpenOrders, &query, String::new())
+            .await
+    }
+
+    /// Fetches account trades as maker or taker since `after` (unix seconds).
+    pub async fn trades<T: DeserializeOwned>(
+        &self,
+        maker: Option<&str>,
+        taker: Option<&str>,
+        after: u64,
+        limit: usize,
+    ) -> Result<Vec<T>> {
+        let mut query = Vec::new();
+        if let Some(maker) = maker.map(str::trim).filter(|value| !value.is_empty()) {
+            query.push(("maker", maker.to_string()));
+        }
+        if let Some(taker) = taker.map(str::trim).filter(|value| !value.is_empty()) {
+            query.push(("taker", taker.to_string()));
+        }
+        if after > 0 {
+            query.push(("after", after.to_string()));
+        }
+        if limit > 0 {
+            query.push(("limit", limit.to_string()));
+        }
+        self.request_json(ClobEndpoint::Trades, &query, String::new())
+            .await
+    }
+
+    /// Returns the token's fee rate; a response with only `base_fee` means a fee-free market.
+    pub async fn fee_rate_bps(&self, token_id: &str) -> Result<Option<f64>> {
+        let token_id = token_id.trim();
+        if token_id.is_empty() {
+            return Ok(None);
+        }
+        let query = [("token_id", token_id.to_string())];
+        let body: FeeRateResponse = self
+            .request_json(ClobEndpoint::FeeRate, &query, String::new())
+            .await?;
+        if let Some(fee_rate_bps) = body.fee_rate_bps {
+            return Ok(Some(fee_rate_bps));
+        }
+        if body.base_fee.is_some() {
+            return Ok(Some(0.0));
+        }
+        Ok(None)
+    }
+
+    async fn api_key_request(
+        &self,
+        endpoint: ClobEndpoint,
+        signer: &Eip712Signer,
+        nonce: u64,
+    ) -> Result<ClobCredentials> {
+        let l1 = L1Auth { signer, nonce };
+        let response = self
+            .execute(endpoint, &[], String::new(), None, Some(l1))
+            .await
+            .map_err(|error| rpc_error(endpoint, error))?;
+        let body: ApiKeyResponse = serde_json::from_value(response.body)?;
+        body.into_credentials(signer.checksum_address())
+    }
+
+    async fn request_json<T: DeserializeOwned>(
+        &self,
+        endpoint: ClobEndpoint,
+        query: &[(&str, String)],
+        body: String,
+    ) -> Result<T> {
+        // With a refresh attached, an unauthenticated request draws a 401 and derives the key.
+        if endpoint.authenticated() && !self.has_credentials() && self.refresh.is_none() {
+            return Err(BankaiError::InvalidArgument(format!(
+                "clob {} requires api credentials",
+                endpoint.as_str()
+            )));
+        }
+        let response = self
+            .execute(endpoint, query, body, None, None)
+            .await
+            .map_err(|error| rpc_error(endpoint, error))?;
+        Ok(serde_json::from_value(response.body)?)
+    }
+
+    async fn execute(
+        &self,
+        endpoint: ClobEndpoint,
+        query: &[(&str, String)],
+        body: String,
+        builder: Option<&RelayerBuilderAuth>,
+        l1: Option<L1Auth<'_>>,
+    ) -> RelayerResult<RelayerResponse> {
+        let mut attempt = 0u32;
+        let mut refreshed = false;
+        loop {
+            let api_key = self.api_key();
+            let result = self.execute_once(endpoint, query, &body, builder, l1).await;
+            match result {
+                Err(error)
+                    if !refreshed
+                        && endpoint.authenticated()
+                        && error.status == Some(StatusCode::UNAUTHORIZED)
+                        && self.refresh.is_some() =>
+                {
+                    refreshed = true;
+                    // Boxed: the refresh goes back through `execute` for the L1 endpoints.
+                    if !Box::pin(self.refresh_credentials(api_key.as_deref())).await {
+                        return Err(error);
+                    }
+                }
+                Err(error)
+                    if endpoint.retryable()
+                        && error.should_failover()
+                        && attempt < self.config.max_retries =>
+                {
+                    let delay = backoff_delay_ms(
+                        self.config.backoff_ms,
+                        self.config.backoff_max_ms,
+                        attempt,
+                    );
+                    tracing::debug!(
+                        endpoint = endpoint.as_str(),
+                        attempt = attempt + 1,
+                        delay_ms = delay,
+                        error = %error.message,
+                        "clob request retry"
+                    );
+                    metrics::increment_clob_retry(endpoint.as_str());
+                    tokio::time::sleep(Duration::from_millis(delay)).await;
+                    attempt += 1;
+                }
+                other => return other,
+            }
+        }
+    }
+
+    /// Re-derives credentials after a 401 and swaps them in. Concurrent callers wait on the
+    /// same refresh; returns false when the request should not be replayed.
+    async fn refresh_credentials(&self, stale_key: Option<&str>) -> bool {
+        let Some(refresh) = self.refresh.as_ref() else {
+            return false;
+        };
+        let _guard = refresh.lock.lock().await;
+        if self.api_key().as_deref() != stale_key {
+            return true;
+        }
+
+        let credentials = match self
+            .derive_or_create_api_key(&refresh.signer, refresh.nonce)
+            .await
+        {
+            Ok(credentials) => credentials,
+            Err(error) => {
+                tracing::error!(?error, "clob api credential refresh failed");
+                metrics::increment_clob_credential_refresh("error");
+                return false;
+            }
+        };
+        if stale_key == Some(credentials.api_key.as_str()) {
+            tracing::error!("clob rejected api key and re-derivation returned the same key");
+            metrics::increment_clob_credential_refresh("unchanged");
+            return false;
+        }
+
+        tracing::warn!(api_key = %credentials.api_key, "clob api credentials re-derived after 401");
+        metrics::increment_clob_credential_refresh("ok");
+        if let Some(secrets) = refresh.secrets.clone() {
+            let persisted = credentials.clone();
+            let result = tokio::task::spawn_blocking(move || {
+                secrets.update(|payload| persisted.write_to(payload))
+            })
+            .await;
+            match result {
+                Ok(Ok(())) => tracing::info!("clob api credentials saved to secrets file"),
+                Ok(Err(error)) => {
+                    tracing::error!(?error, "failed to save re-derived clob api credentials")
+                }
+                Err(error) => {
+                    tracing::error!(?error, "failed to save re-derived clob api credentials")
+                }
+            }
+        }
+        self.set_credentials(credentials);
+        true
+    }
+
+    async fn execute_once(
+        &self,
+        endpoint: ClobEndpoint,
+        query: &[(&str, String)],
+        body: &str,
+        builder: Option<&RelayerBuilderAuth>,
+        l1: Option<L1Auth<'_>>,
+    ) -> RelayerResult<RelayerResponse> {
+        let url = self.endpoint_url(endpoint, query)?;
+        let request_path = build_request_path(&url);
+        let method = endpoint.method();
+
+        // Wait for the token before signing so throttled requests carry fresh timestamps.
+        if self.limiter.acquire(endpoint).await {
+            metrics::increment_clob_rate_limited(endpoint.as_str());
+        }
+        let mut headers = HeaderMap::new();
+        if endpoint.authenticated() {
+            if let Some(credentials) = self.credentials.load().as_ref() {
+                headers = credentials
+                    .l2_headers(method.as_str(), &request_path, body)
+                    .map_err(|err| RelayerError::invalid_request(err.to_string()))?;
+            }
+        } else if let Some(l1) = l1 {
+            headers = l1_headers(l1.signer, l1.nonce)
+                .await
+                .map_err(|err| RelayerError::invalid_request(err.to_string()))?;
+        }
+        if let Some(builder) = builder {
+            apply_builder_headers(&mut headers, builder, method.as_str(), &request_path, body)?;
+        }
+
+        let mut request = self.client.request(method, url).headers(headers);
+        if !body.is_empty() {
+            request = request
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(body.to_string());
+        }
+        if endpoint == ClobEndpoint::PostOrder {
+            request = request.timeout(self.config.order_timeout);
+        }
+
+        let start = Instant::now();
+        let response = request.send().await;
+        let latency_ms = duration_to_ms(start.elapsed());
+        if endpoint == ClobEndpoint::PostOrder {
+            metrics::record_latency_ms(latency_ms as f64);
+        }
+
+        let response = match response {
+            Ok(response) => response,
+            Err(error) => {
+                let error = RelayerError::from_request_error(error, latency_ms);
+                self.record(endpoint, error.kind.as_str(), latency_ms);
+                return Err(error);
+            }
+        };
+
+        let status = response.status();
+        self.record(endpoint, status_class(status), latency_ms);
+        let request_id = extract_request_id(response.headers());
+        let body = read_response_body(response, latency_ms).await?;
+
+        if status.is_success() {
+            Ok(RelayerResponse {
+                status,
+                body,
+                latency_ms,
+                request_id,
+            })
+        } else {
+            Err(RelayerError::from_status(status, body, latency_ms))
+        }
+    }
+
+    fn record(&self, endpoint: ClobEndpoint, outcome: &'static str, latency_ms: u64) {
+        metrics::record_clob_request(endpoint.as_str(), outcome, latency_ms as f64);
+        if endpoint == ClobEndpoint::PostOrder {
+            metrics::record_relayer_latency_ms(outcome, latency_ms as f64);
+        }
+    }
+
+    fn endpoint_url(&self, endpoint: ClobEndpoint, query: &[(&str, String)]) -> RelayerResult<Url> {
+        let mut url = Url::parse(self.config.base_url.trim()).map_err(|err| {
+            RelayerError::invalid_request(format!("invalid clob base url: {err}"))
+        })?;
+        let base_path = url.path().trim_end_matches('/').to_string();
+        let path = self.config.path(endpoint).trim().trim_start_matches('/');
+        url.set_path(&format!("{base_path}/{path}"));
+        if !query.is_empty() {
+            let mut pairs = url.query_pairs_mut();
+            for (key, value) in query {
+                pairs.append_pair(key, value);
+            }
+        }
+        Ok(url)
+    }
+}
+
+/// Per-endpoint-class token buckets refilled continuously over a 10 second window.
+#[derive(Debug)]
+struct ClobRateLimiter {
+    post_order: Option<TokenBucket>,
+    cancel: Option<TokenBucket>,
+    cancel_all: Option<TokenBucket>,
+    data: Option<TokenBucket>,
+    fee_rate: Option<TokenBucket>,
+}
+
+impl ClobRateLimiter {
+    fn new(limits: &ClobRateLimitConfig) -> Self {
+        Self {
+            post_order: TokenBucket::per_window(limits.post_order_per_10s),
+            cancel: TokenBucket::per_window(limits.cancel_per_10s),
+            cancel_all: TokenBucket::per_window(limits.cancel_all_per_10s),
+            data: TokenBucket::per_window(limits.data_per_10s),
+            fee_rate: TokenBucket::per_window(limits.fee_rate_per_10s),
+        }
+    }
+
+    /// Waits for a token; returns true when the request had to wait.
+    async fn acquire(&self, endpoint: ClobEndpoint) -> bool {
+        let bucket = match endpoint {
+            ClobEndpoint::PostOrder => &self.post_order,
+            ClobEndpoint::CancelOrder | ClobEndpoint::CancelMarket => &self.cancel,
+            ClobEndpoint::CancelAll => &self.cancel_all,
+            ClobEndpoint::OpenOrders | ClobEndpoint::Trades => &self.data,
+            ClobEndpoint::FeeRate => &self.fee_rate,
+            ClobEndpoint::CreateApiKey
+            | ClobEndpoint::DeriveApiKey
+            | ClobEndpoint::DeleteApiKey => return false,
+        };
+        let Some(bucket) = bucket.as_ref() else {
+            return false;
+        };
+        let mut waited = false;
+        loop {
+            match bucket.try_take(Instant::now()) {
+                None => return waited,
+                Some(wait) => {
+                    waited = true;
+                    tokio::time::sleep(wait).await;
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    capacity: f64,
+    refill_per_sec: f64,
+    state: Mutex<BucketState>,
+}
+
+#[derive(Debug)]
+struct BucketState {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl TokenBucket {
+    fn per_window(limit: u32) -> Option<Self> {
+        if limit == 0 {
+            return None;
+        }
+        let capacity = f64::from(limit);
+        Some(Self {
+            capacity,
+            refill_per_sec: capacity / RATE_LIMIT_WINDOW_SECS,
+            state: Mutex::new(BucketState {
+                tokens: capacity,
+                updated_at: Instant::now(),
+            }),
+        })
+    }
+
+    /// Takes a token, or returns how long to wait before one is available.
+    fn try_take(&self, now: Instant) -> Option<Duration> {
+        let mut state = self.state.lock().unwrap_or_else(|err| err.into_inner());
+        let elapsed = now
+            .saturating_duration_since(state.updated_at)
+            .as_secs_f64();
+        state.tokens = (state.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+        state.updated_at = now;
+        if state.tokens >= 1.0 {
+            state.tokens -= 1.0;
+            return None;
+        }
+        let missing = 1.0 - state.tokens;
+        Some(Duration::from_secs_f64(missing / self.refill_per_sec))
+    }
+}
+
+/// Polymarket L2 signature: URL-safe base64 HMAC-SHA256 over `timestamp + method + path + body`
+/// keyed with the base64-decoded API secret.
+pub fn build_hmac_signature(
+    secret: &str,
+    timestamp: u64,
+    method: &str,
+    path: &str,
+    body: &str,
+) -> Result<String> {
+    let key = general_purpose::STANDARD
+        .decode(secret)
+        .map_err(|err| BankaiError::InvalidArgument(format!("api secret decode error: {err}")))?;
+    let message = format!("{timestamp}{method}{path}{body}");
+    let mut mac = HmacSha256::new_from_slice(&key)
+        .map_err(|_| BankaiError::InvalidArgument("api secret is invalid for hmac".to_string()))?;
+    mac.update(message.as_bytes());
+    let result = mac.finalize().into_bytes();
+    let signature = general_purpose::STANDARD.encode(result);
+    Ok(signature.replace('+', "-").replace('/', "_"))
+}
+
+fn rpc_error(endpoint: ClobEndpoint, error: RelayerError) -> BankaiError {
+    BankaiError::Rpc(format!(
+        "clob {} failed ({}): {}",
+        endpoint.as_str(),
+        error.kind.as_str(),
+        error.message
+    ))
+}
+
+fn build_request_path(url: &Url) -> String {
+    match url.query() {
+        Some(query) => format!("{}?{}", url.path(), query),
+        None => url.path().to_string(),
+    }
+}
+
+fn header_value(value: &str) -> Result<HeaderValue> {
+    HeaderValue::from_str(value).map_err(|_| {
+        BankaiError::InvalidArgument("header value contains invalid characters".to_string())
+    })
+}
+
+fn backoff_delay_ms(base_ms: u64, max_ms: u64, attempt: u32) -> u64 {
+    let exp = 2u64.saturating_pow(attempt.min(16));
+    let base = base_ms.saturating_mul(exp);
+    let jitter = now_ms() % base_ms.max(1);
+    base.saturating_add(jitter).min(max_ms.max(base_ms))
+}
+
+fn read_env_value(key: &str) -> Option<String> {
+    std::env::var(key)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+fn current_unix_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token_bucket_waits_once_burst_is_spent() {
+        let bucket = TokenBucket::per_window(2).expect("bucket");
+        let start = Instant::now();
+        assert!(bucket.try_take(start).is_none());
+        assert!(bucket.try_take(start).is_none());
+        let wait = bucket.try_take(start).expect("should wait");
+        assert_eq!(wait, Duration::from_secs(5));
+        assert!(bucket.try_take(start + Duration::from_secs(5)).is_none());
+        assert!(TokenBucket::per_window(0).is_none());
+    }
+
+    #[test]
+    fn api_key_response_requires_all_fields() {
+        let body: ApiKeyResponse = serde_json::from_value(serde_json::json!({
+            "apiKey": "key",
+            "secret": "c2VjcmV0",
+            "passphrase": "pass"
+        }))
+        .expect("response");
+        let credentials = body
+            .into_credentials("0xabc".to_string())
+            .expect("credentials");
+        assert_eq!(credentials.api_key, "key");
+        assert_eq!(credentials.api_passphrase, "pass");
+
+        let empty: ApiKeyResponse = serde_json::from_value(serde_json::json!({
+            "apiKey": "key",
+            "secret": "",
+            "passphrase": "pass"
+        }))
+        .expect("response");
+        assert!(empty.into_credentials("0xabc".to_string()).is_err());
+    }
+
+    #[test]
+    fn hmac_signature_is_url_safe_and_stable() {
+        let secret = general_purpose::STANDARD.encode(b"bankai-test-secret");
+        let first = build_hmac_signature(&secret, 1_700_000_000, "GET", "/data/orders", "")
+            .expect("signature");
+        let second = build_hmac_signature(&secret, 1_700_000_000, "GET", "/data/orders", "")
+            .expect("signature");
+        assert_eq!(first, second);
+        assert!(!first.contains('+') && !first.contains('/'));
+        let other = build_hmac_signature(&secret, 1_700_000_001, "GET", "/data/orders", "")
+            .expect("signature");
+        assert_ne!(first, other);
+    }
+}
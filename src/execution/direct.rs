@@ -13,7 +13,10 @@
  */
 use ethers_core::abi::{Abi, Token};
 use ethers_core::types::transaction::eip2718::TypedTransaction;
-use ethers_core::types::{Address, Bytes, Eip1559TransactionRequest, H256, U256};
+use ethers_core::types::{
+    Address, Bytes, Eip1559TransactionRequest, TransactionReceipt, H256, U256,
+};
+use ethers_core::utils::keccak256;
 use ethers_providers::{Http, Middleware, Provider};
 use ethers_signers::{LocalWallet, Signer};
 use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
@@ -25,6 +28,7 @@ use std::path::Path;
 use std::str::FromStr;
 use std::time::Duration;
 
+use crate::config::Config;
 use crate::error::{BankaiError, Result};
 use crate::security::Secrets;
 
@@ -61,6 +65,28 @@ impl DirectExecutionConfig {
             private_rpc_headers: HashMap::new(),
         }
     }
+
+    /// Builds the client config from the `direct` section; the RPC falls back to
+    /// `endpoints.polygon_rpc` when no dedicated (private) RPC is configured.
+    pub fn from_config(config: &Config, exchange_address: String, chain_id: u64) -> Self {
+        let direct = &config.direct;
+        let rpc_url = direct
+            .rpc_url
+            .clone()
+            .filter(|value| !value.trim().is_empty())
+            .unwrap_or_else(|| config.endpoints.polygon_rpc.clone());
+        Self {
+            rpc_url,
+            exchange_address,
+            chain_id,
+            request_timeout: Duration::from_millis(direct.request_timeout_ms.max(1)),
+            abi_path: direct.abi_path.clone(),
+            gas_limit: direct.gas_limit.map(U256::from),
+            max_fee_per_gas_gwei: direct.max_fee_per_gas_gwei,
+            max_priority_fee_gwei: direct.max_priority_fee_gwei,
+            private_rpc_headers: direct.private_rpc_headers.clone(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -186,6 +212,13 @@ pub struct DirectExecutionResult {
     pub max_priority_fee_per_gas: U256,
 }
 
+#[derive(Debug, Clone)]
+pub struct SignedDirectTx {
+    pub raw: Bytes,
+    pub result: DirectExecutionResult,
+}
+
+#[derive(Clone)]
 pub struct DirectExecutionClient {
     config: DirectExecutionConfig,
     provider: Provider<Http>,
@@ -240,6 +273,13 @@ impl DirectExecutionClient {
             .map_err(|err| BankaiError::Rpc(format!("nonce fetch failed: {err}")))
     }
 
+    pub async fn transaction_receipt(&self, tx_hash: H256) -> Result<Option<TransactionReceipt>> {
+        self.provider
+            .get_transaction_receipt(tx_hash)
+            .await
+            .map_err(|err| BankaiError::Rpc(format!("receipt fetch failed: {err}")))
+    }
+
     pub fn encode_fill_orders(&self, request: &FillOrdersRequest) -> Result<Bytes> {
         let function = self.exchange_abi.function("fillOrders").map_err(|err| {
             BankaiError::InvalidArgument(format!("fillOrders function missing from ABI: {err}"))
@@ -264,6 +304,18 @@ impl DirectExecutionClient {
         call_data: Bytes,
         options: &DirectCallOptions,
     ) -> Result<DirectExecutionResult> {
+        let signed = self.sign_call_data(call_data, options).await?;
+        self.broadcast(&signed).await?;
+        Ok(signed.result)
+    }
+
+    /// Builds and signs the transaction without sending it; nothing reaches the network
+    /// from here, so a failure leaves the nonce unused.
+    pub async fn sign_call_data(
+        &self,
+        call_data: Bytes,
+        options: &DirectCallOptions,
+    ) -> Result<SignedDirectTx> {
         let value = options.value.unwrap_or_default();
         let tx = Eip1559TransactionRequest {
             from: Some(self.wallet.address()),
@@ -294,20 +346,27 @@ impl DirectExecutionClient {
             .await
             .map_err(|err| BankaiError::Crypto(format!("transaction signing failed: {err}")))?;
 
-        let rlp = typed_tx.rlp_signed(&signature);
+        let raw = typed_tx.rlp_signed(&signature);
+        Ok(SignedDirectTx {
+            result: DirectExecutionResult {
+                tx_hash: H256::from(keccak256(&raw)),
+                nonce,
+                gas_limit,
+                max_fee_per_gas,
+                max_priority_fee_per_gas,
+            },
+            raw,
+        })
+    }
+
+    /// Sends a signed transaction. An error here does not prove the node never saw it.
+    pub async fn broadcast(&self, signed: &SignedDirectTx) -> Result<H256> {
         let pending = self
             .provider
-            .send_raw_transaction(rlp)
+            .send_raw_transaction(signed.raw.clone())
             .await
             .map_err(|err| BankaiError::Rpc(format!("send raw transaction failed: {err}")))?;
-
-        Ok(DirectExecutionResult {
-            tx_hash: pending.tx_hash(),
-            nonce,
-            gas_limit,
-            max_fee_per_gas,
-            max_priority_fee_per_gas,
-        })
+        Ok(pending.tx_hash())
     }
 }
 
@@ -9,10 +9,12 @@
  * - Keep execution helpers focused on signing and chain state.
  */
 pub mod allowances;
-pub mod cancel;
+pub mod clob;
 pub mod direct;
 pub mod nonce;
 pub mod orchestrator;
+pub mod order_sweeper;
 pub mod payload_builder;
 pub mod relayer;
 pub mod signer;
+pub mod tx_monitor;
@@ -10,6 +10,7 @@
  * @notes
  * - Uses Redis Lua scripts for atomic nonce reservation and bump updates.
  * - Pending nonces are tracked for RBF-style resubmission decisions.
+ * - The last signed submission per nonce is kept so a stuck tx can be re-sent with bumped fees.
  */
 use redis::AsyncCommands;
 use redis::Script;
@@ -22,6 +23,7 @@ use crate::storage::redis::RedisManager;
 const NONCE_KEY_PREFIX: &str = "sys:nonce:";
 const PENDING_SUFFIX: &str = ":pending";
 const LOCK_SUFFIX: &str = ":lock";
+const SUBMISSIONS_SUFFIX: &str = ":txs";
 
 const RESERVE_NONCE_SCRIPT: &str = r#"
 local key = KEYS[1]
@@ -54,6 +56,19 @@ redis.call("HSET", pending_key, nonce, encoded)
 return encoded
 "#;
 
+const RELEASE_UNSENT_SCRIPT: &str = r#"
+local key = KEYS[1]
+local pending_key = KEYS[2]
+local nonce = tonumber(ARGV[1])
+redis.call("HDEL", pending_key, ARGV[1])
+local current = redis.call("GET", key)
+if current and tonumber(current) == nonce + 1 then
+  redis.call("SET", key, nonce)
+  return 1
+end
+return 0
+"#;
+
 const RELEASE_LOCK_SCRIPT: &str = r#"
 if redis.call("GET", KEYS[1]) == ARGV[1] then
   return redis.call("DEL", KEYS[1])
@@ -104,6 +119,17 @@ impl PendingNonceEntry {
     }
 }
 
+/// Calldata and fees of the latest transaction broadcast for a nonce.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NonceSubmission {
+    /// Every hash broadcast for the nonce, oldest first; any of them may be the one mined.
+    pub tx_hashes: Vec<String>,
+    pub call_data: String,
+    pub gas_limit: String,
+    pub max_fee_per_gas: String,
+    pub max_priority_fee_per_gas: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct NonceManager {
     redis: RedisManager,
@@ -176,11 +202,51 @@ impl NonceManager {
 
     pub async fn mark_confirmed(&self, nonce: u64) -> Result<()> {
         let pending_key = pending_key(&self.address);
+        let submissions_key = submissions_key(&self.address);
         let mut conn = self.redis.connection();
-        conn.hdel::<_, _, ()>(pending_key, nonce).await?;
+        redis::pipe()
+            .hdel(pending_key, nonce)
+            .ignore()
+            .hdel(submissions_key, nonce)
+            .ignore()
+            .query_async::<_, ()>(&mut conn)
+            .await?;
         Ok(())
     }
 
+    /// Drops a reservation whose transaction never reached the network. The counter is
+    /// rolled back only if no later nonce was handed out, so no gap is left behind.
+    pub async fn release_unsent(&self, nonce: u64) -> Result<bool> {
+        let key = nonce_key(&self.address);
+        let pending_key = pending_key(&self.address);
+        let mut conn = self.redis.connection();
+        let script = Script::new(RELEASE_UNSENT_SCRIPT);
+        let rolled_back: i32 = script
+            .key(key)
+            .key(pending_key)
+            .arg(nonce)
+            .invoke_async(&mut conn)
+            .await?;
+        Ok(rolled_back == 1)
+    }
+
+    pub async fn record_submission(&self, nonce: u64, submission: &NonceSubmission) -> Result<()> {
+        let submissions_key = submissions_key(&self.address);
+        let payload = serde_json::to_string(submission)?;
+        let mut conn = self.redis.connection();
+        conn.hset::<_, _, _, ()>(submissions_key, nonce, payload)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn submission(&self, nonce: u64) -> Result<Option<NonceSubmission>> {
+        let submissions_key = submissions_key(&self.address);
+        let mut conn = self.redis.connection();
+        let raw: Option<String> = conn.hget(submissions_key, nonce).await?;
+        raw.map(|raw| serde_json::from_str(&raw).map_err(Into::into))
+            .transpose()
+    }
+
     pub async fn record_bump(&self, nonce: u64, now_ms: u64) -> Result<Option<PendingNonceEntry>> {
         let pending_key = pending_key(&self.address);
         let mut conn = self.redis.connection();
@@ -298,6 +364,10 @@ fn pending_key(address: &str) -> String {
     format!("{NONCE_KEY_PREFIX}{address}{PENDING_SUFFIX}")
 }
 
+fn submissions_key(address: &str) -> String {
+    format!("{NONCE_KEY_PREFIX}{address}{SUBMISSIONS_SUFFIX}")
+}
+
 fn lock_key(address: &str) -> String {
     format!("{NONCE_KEY_PREFIX}{address}{LOCK_SUFFIX}")
 }
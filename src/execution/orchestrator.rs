@@ -11,20 +11,27 @@
  * @notes
  * - Requires an ExecutionPayloadBuilder to translate TradeIntent into rail payloads.
  * - Relayer timeouts trigger failover; relayer errors marked as failover-safe do as well.
+ * - Accepted relayer orders are registered with the order lifecycle sweeper when attached.
+ * - With a live config attached, `no_money_mode` follows runtime toggles instead of the
+ *   startup value.
  */
+use arc_swap::ArcSwap;
 use serde_json::{json, Map, Value};
 use std::sync::Arc;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tokio::sync::mpsc;
 
 use crate::accounting::no_money::{record_no_money_intent, PaperSimConfig};
+use crate::config::Config;
+use crate::engine::orders::{OrderInit, OrderType};
 use crate::engine::types::{TradeIntent, TradeMode, TradeSide};
 use crate::error::{BankaiError, Result};
-use crate::execution::cancel::CancelClient;
+use crate::execution::clob::ClobClient;
 use crate::execution::direct::{DirectExecutionClient, DirectExecutionResult, FillOrdersRequest};
-use crate::execution::nonce::NonceManager;
+use crate::execution::nonce::{NonceManager, NonceSubmission};
+use crate::execution::order_sweeper::OrderLifecycleHandle;
 use crate::execution::relayer::{
-    RelayerAuth, RelayerClient, RelayerError, RelayerErrorKind, RelayerResponse,
+    RelayerBuilderAuth, RelayerError, RelayerErrorKind, RelayerResponse,
 };
 use crate::storage::database::{DatabaseManager, TradeExecutionLog};
 use crate::storage::redis::RedisManager;
@@ -46,6 +53,7 @@ pub struct ExecutionOrchestratorConfig {
     pub idempotency_ttl_secs: u64,
     pub cancel_before_replace: bool,
     pub no_money_mode: bool,
+    pub ladder_max_age_ms: u64,
 }
 
 impl Default for ExecutionOrchestratorConfig {
@@ -59,6 +67,7 @@ impl Default for ExecutionOrchestratorConfig {
             idempotency_ttl_secs: 30,
             cancel_before_replace: true,
             no_money_mode: false,
+            ladder_max_age_ms: 0,
         }
     }
 }
@@ -66,7 +75,7 @@ impl Default for ExecutionOrchestratorConfig {
 #[derive(Debug, Clone)]
 pub struct ExecutionPayloads {
     pub relayer_payload: Value,
-    pub relayer_auth: Option<RelayerAuth>,
+    pub builder_auth: Option<RelayerBuilderAuth>,
     pub direct_request: Option<FillOrdersRequest>,
     pub fees_paid: f64,
     pub metadata: Option<Value>,
@@ -105,23 +114,25 @@ pub struct ExecutionReport {
 
 pub struct ExecutionOrchestrator {
     config: ExecutionOrchestratorConfig,
-    relayer: RelayerClient,
+    relayer: ClobClient,
     direct: Option<DirectExecutionClient>,
-    cancel_client: Option<CancelClient>,
+    cancel_client: Option<ClobClient>,
     database: Option<DatabaseManager>,
     nonce_manager: Option<NonceManager>,
     activity_redis: Option<RedisManager>,
     wallet_key: Option<String>,
     builder: Arc<dyn ExecutionPayloadBuilder>,
     paper_sim: Option<PaperSimConfig>,
+    lifecycle: Option<OrderLifecycleHandle>,
+    live_config: Option<Arc<ArcSwap<Config>>>,
 }
 
 impl ExecutionOrchestrator {
     pub fn new(
         config: ExecutionOrchestratorConfig,
-        relayer: RelayerClient,
+        relayer: ClobClient,
         direct: Option<DirectExecutionClient>,
-        cancel_client: Option<CancelClient>,
+        cancel_client: Option<ClobClient>,
         database: Option<DatabaseManager>,
         nonce_manager: Option<NonceManager>,
         activity_redis: Option<RedisManager>,
@@ -151,9 +162,28 @@ impl ExecutionOrchestrator {
             wallet_key,
             builder,
             paper_sim,
+            lifecycle: None,
+            live_config: None,
         })
     }
 
+    pub fn with_order_lifecycle(mut self, lifecycle: OrderLifecycleHandle) -> Self {
+        self.lifecycle = Some(lifecycle);
+        self
+    }
+
+    pub fn with_live_config(mut self, config: Arc<ArcSwap<Config>>) -> Self {
+        self.live_config = Some(config);
+        self
+    }
+
+    fn no_money_mode(&self) -> bool {
+        self.live_config
+            .as_ref()
+            .map(|config| config.load().execution.no_money_mode)
+            .unwrap_or(self.config.no_money_mode)
+    }
+
     pub fn spawn(self, mut receiver: mpsc::Receiver<TradeIntent>) -> tokio::task::JoinHandle<()> {
         tokio::spawn(async move {
             while let Some(intent) = receiver.recv().await {
@@ -179,7 +209,7 @@ impl ExecutionOrchestrator {
             }
         }
 
-        if self.config.no_money_mode {
+        if self.no_money_mode() {
             if let Some(redis) = self.activity_redis.as_ref() {
                 if let Some(sim) = self.paper_sim.as_ref() {
                     let _ = record_no_money_intent(redis, &intent, sim).await;
@@ -277,6 +307,20 @@ impl ExecutionOrchestrator {
 
         match relayer_result {
             Ok(response) => {
+                if let (Some(lifecycle), Some(order_id)) =
+                    (self.lifecycle.as_ref(), extract_order_id(&response.body))
+                {
+                    let now = now_ms().unwrap_or(0);
+                    if let Some(init) = lifecycle_order_init(
+                        intent,
+                        payloads,
+                        order_id,
+                        now,
+                        self.config.ladder_max_age_ms,
+                    ) {
+                        lifecycle.order_placed(init);
+                    }
+                }
                 if let (Some(redis), Some(wallet_key)) =
                     (self.activity_redis.as_ref(), self.wallet_key.as_ref())
                 {
@@ -382,7 +426,7 @@ impl ExecutionOrchestrator {
         let timeout = self.config.relayer_timeout;
         let future = self
             .relayer
-            .post_order(&payloads.relayer_payload, payloads.relayer_auth.as_ref());
+            .post_order(&payloads.relayer_payload, payloads.builder_auth.as_ref());
         match tokio::time::timeout(timeout, future).await {
             Ok(result) => result,
             Err(_) => Err(RelayerError {
@@ -434,12 +478,48 @@ impl ExecutionOrchestrator {
         let direct = self.direct.as_ref().ok_or_else(|| {
             BankaiError::InvalidArgument("direct execution client missing".to_string())
         })?;
-        let mut request = request;
-        if let Some(manager) = self.nonce_manager.as_ref() {
-            let nonce = self.ensure_reserved_nonce(manager).await?;
-            request.options.nonce = Some(nonce.into());
+        let Some(manager) = self.nonce_manager.as_ref() else {
+            return direct.send_fill_orders(&request).await;
+        };
+        let call_data = direct.encode_fill_orders(&request)?;
+        let nonce = self.ensure_reserved_nonce(manager).await?;
+        let mut options = request.options.clone();
+        options.nonce = Some(nonce.into());
+        let signed = match direct.sign_call_data(call_data.clone(), &options).await {
+            Ok(signed) => signed,
+            Err(error) => {
+                if let Err(release_error) = manager.release_unsent(nonce).await {
+                    tracing::warn!(?release_error, nonce, "failed to release unsent nonce");
+                }
+                return Err(error);
+            }
+        };
+        let result = signed.result.clone();
+        // Record before broadcasting: a failed send may still have reached the mempool, so the
+        // nonce stays pending and tx_monitor resolves it against receipts and the chain nonce.
+        let submission = NonceSubmission {
+            tx_hashes: vec![format!("{:?}", result.tx_hash)],
+            call_data: format!("{call_data}"),
+            gas_limit: result.gas_limit.to_string(),
+            max_fee_per_gas: result.max_fee_per_gas.to_string(),
+            max_priority_fee_per_gas: result.max_priority_fee_per_gas.to_string(),
+        };
+        if let Err(error) = manager.record_submission(nonce, &submission).await {
+            tracing::warn!(
+                ?error,
+                nonce,
+                "failed to record direct submission; rbf disabled for nonce"
+            );
         }
-        direct.send_fill_orders(&request).await
+        if let Err(error) = direct.broadcast(&signed).await {
+            tracing::warn!(
+                ?error,
+                nonce,
+                "direct broadcast failed; nonce left pending for tx monitor"
+            );
+            return Err(error);
+        }
+        Ok(result)
     }
 
     async fn ensure_reserved_nonce(&self, manager: &NonceManager) -> Result<u64> {
@@ -612,7 +692,7 @@ fn relayer_success_metadata(response: &RelayerResponse) -> Value {
 fn relayer_error_metadata(error: &RelayerError) -> Value {
     json!({
         "status": "error",
-        "kind": relayer_error_kind_label(error.kind),
+        "kind": error.kind.as_str(),
         "message": error.message.clone(),
         "http_status": error.status.map(|status| status.as_u16()),
         "latency_ms": error.latency_ms,
@@ -638,18 +718,6 @@ fn direct_error_metadata(error: &BankaiError) -> Value {
     })
 }
 
-fn relayer_error_kind_label(kind: RelayerErrorKind) -> &'static str {
-    match kind {
-        RelayerErrorKind::Timeout => "timeout",
-        RelayerErrorKind::Transport => "transport",
-        RelayerErrorKind::Congestion => "congestion",
-        RelayerErrorKind::Server => "server",
-        RelayerErrorKind::Client => "client",
-        RelayerErrorKind::InvalidRequest => "invalid_request",
-        RelayerErrorKind::InvalidResponse => "invalid_response",
-    }
-}
-
 fn trade_mode_label(mode: TradeMode) -> &'static str {
     match mode {
         TradeMode::Ladder => "LADDER",
@@ -710,6 +778,69 @@ fn extract_order_id(body: &Value) -> Option<String> {
     None
 }
 
+/// Builds the lifecycle record for an accepted order; ladder orders get an earlier sweep
+/// deadline than their exchange expiry when `ladder_max_age_ms` is set.
+fn lifecycle_order_init(
+    intent: &TradeIntent,
+    payloads: &ExecutionPayloads,
+    order_id: String,
+    now_ms: u64,
+    ladder_max_age_ms: u64,
+) -> Option<OrderInit> {
+    let requested_qty = payloads
+        .metadata
+        .as_ref()
+        .and_then(|context| context.get("size"))
+        .and_then(|value| value.as_f64())?;
+    let order_type = payloads
+        .relayer_payload
+        .get("orderType")
+        .and_then(|value| value.as_str())
+        .unwrap_or("GTC")
+        .to_ascii_uppercase();
+    let expiration_ms = payloads
+        .relayer_payload
+        .get("order")
+        .and_then(|order| order.get("expiration"))
+        .and_then(|value| value.as_str())
+        .and_then(|value| value.parse::<u64>().ok())
+        .filter(|value| *value > 0)
+        .map(|secs| secs.saturating_mul(1000));
+
+    let order_type = match order_type.as_str() {
+        "FOK" => OrderType::Fok,
+        "FAK" => OrderType::Fak,
+        "IOC" => OrderType::Ioc,
+        _ => {
+            let mut deadline = if order_type == "GTD" {
+                expiration_ms
+            } else {
+                None
+            };
+            if intent.mode == TradeMode::Ladder && ladder_max_age_ms > 0 {
+                let max_age = now_ms.saturating_add(ladder_max_age_ms);
+                deadline = Some(deadline.map_or(max_age, |value| value.min(max_age)));
+            }
+            match deadline {
+                Some(expires_at_ms) => OrderType::Gtd {
+                    expires_at_ms: expires_at_ms.max(now_ms + 1),
+                },
+                None => OrderType::Gtc,
+            }
+        }
+    };
+
+    Some(OrderInit {
+        order_id,
+        market_id: intent.market_id.clone(),
+        asset_id: intent.asset_id.clone(),
+        mode: intent.mode,
+        requested_qty,
+        created_at_ms: now_ms,
+        order_type,
+    })
+}
+
 fn is_within_window(timestamp_ms: u64, window: crate::engine::types::MarketWindow) -> bool {
     timestamp_ms >= window.start_time_ms && timestamp_ms <= window.end_time_ms
 }
@@ -727,3 +858,63 @@ fn u256_to_u64(value: ethers_core::types::U256, label: &str) -> Result<u64> {
     }
     Ok(value.as_u64())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ladder_intent() -> TradeIntent {
+        TradeIntent {
+            market_id: "m1".to_string(),
+            asset_id: "t1".to_string(),
+            side: TradeSide::Buy,
+            mode: TradeMode::Ladder,
+            implied_prob: 0.5,
+            true_prob: 0.6,
+            edge: 0.1,
+            edge_bps: 1_000.0,
+            spread_offset_bps: 0.0,
+            timestamp_ms: 0,
+            market_window: None,
+            requested_size: None,
+        }
+    }
+
+    #[test]
+    fn lifecycle_init_caps_ladder_expiry_at_max_age() {
+        let payloads = ExecutionPayloads {
+            relayer_payload: json!({
+                "order": { "expiration": "2000" },
+                "orderType": "GTD",
+            }),
+            builder_auth: None,
+            direct_request: None,
+            fees_paid: 0.0,
+            metadata: Some(json!({ "size": 10.0 })),
+        };
+        let init = lifecycle_order_init(
+            &ladder_intent(),
+            &payloads,
+            "o1".to_string(),
+            1_000_000,
+            60_000,
+        )
+        .expect("init");
+        assert_eq!(
+            init.order_type,
+            OrderType::Gtd {
+                expires_at_ms: 1_060_000
+            }
+        );
+
+        let init =
+            lifecycle_order_init(&ladder_intent(), &payloads, "o2".to_string(), 1_000_000, 0)
+                .expect("init");
+        assert_eq!(
+            init.order_type,
+            OrderType::Gtd {
+                expires_at_ms: 2_000_000
+            }
+        );
+    }
+}
@@ -0,0 +1,318 @@
+/**
+ * @description
+ * Live order lifecycle sweeper that feeds OrderLifecycleManager and executes its cancel actions.
+ *
+ * @dependencies
+ * - tokio: lifecycle event channel and sweep interval
+ * - execution::clob: CLOB cancels for swept orders
+ *
+ * @notes
+ * - The orchestrator reports placed orders; the user websocket reports matches and closes.
+ * - Ladder markets are marked degraded when their book goes stale, fails an integrity check,
+ *   or risk halts trading.
+ * - Failed cancels return to the active set and are retried on the next sweep.
+ * - Sweeps only run while live; with a live config attached, `no_money_mode` follows runtime
+ *   toggles. Lifecycle events are still applied in no-money mode.
+ */
+use arc_swap::ArcSwap;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::mpsc;
+
+use crate::config::Config;
+use crate::engine::orders::{
+    OrderAction, OrderInit, OrderLifecycleConfig, OrderLifecycleManager, OrderStatus,
+};
+use crate::engine::risk::RiskState;
+use crate::engine::types::TradeMode;
+use crate::error::Result;
+use crate::execution::clob::ClobClient;
+use crate::storage::redis::RedisManager;
+
+const EARLY_EVENT_TTL_MS: u64 = 60_000;
+
+#[derive(Debug, Clone)]
+pub enum OrderLifecycleEvent {
+    Placed(OrderInit),
+    Matched {
+        order_id: String,
+        filled_qty: f64,
+        event_time_ms: u64,
+    },
+    Closed {
+        order_id: String,
+        event_time_ms: u64,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct OrderLifecycleHandle {
+    sender: mpsc::UnboundedSender<OrderLifecycleEvent>,
+}
+
+impl OrderLifecycleHandle {
+    pub fn order_placed(&self, init: OrderInit) {
+        let _ = self.sender.send(OrderLifecycleEvent::Placed(init));
+    }
+
+    pub fn order_matched(&self, order_id: &str, filled_qty: f64, event_time_ms: u64) {
+        let _ = self.sender.send(OrderLifecycleEvent::Matched {
+            order_id: order_id.to_string(),
+            filled_qty,
+            event_time_ms,
+        });
+    }
+
+    pub fn order_closed(&self, order_id: &str, event_time_ms: u64) {
+        let _ = self.sender.send(OrderLifecycleEvent::Closed {
+            order_id: order_id.to_string(),
+            event_time_ms,
+        });
+    }
+}
+
+pub fn order_lifecycle_channel() -> (
+    OrderLifecycleHandle,
+    mpsc::UnboundedReceiver<OrderLifecycleEvent>,
+) {
+    let (sender, receiver) = mpsc::unbounded_channel();
+    (OrderLifecycleHandle { sender }, receiver)
+}
+
+#[derive(Debug, Clone)]
+pub struct OrderSweeperConfig {
+    pub sweep_interval: Duration,
+    pub lifecycle: OrderLifecycleConfig,
+    pub degraded_book_stale_ms: u64,
+    pub no_money_mode: bool,
+}
+
+impl OrderSweeperConfig {
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            sweep_interval: Duration::from_millis(config.execution.order_sweep_interval_ms.max(50)),
+            lifecycle: OrderLifecycleConfig::from_execution(&config.execution),
+            degraded_book_stale_ms: config.execution.degraded_book_stale_ms,
+            no_money_mode: config.execution.no_money_mode,
+        }
+    }
+}
+
+/// User-ws state that arrived before the orchestrator registered the order.
+#[derive(Debug, Clone, Copy, Default)]
+struct EarlyEvent {
+    filled_qty: Option<f64>,
+    closed: bool,
+    received_at_ms: u64,
+}
+
+pub struct OrderSweeper {
+    config: OrderSweeperConfig,
+    manager: OrderLifecycleManager,
+    receiver: mpsc::UnboundedReceiver<OrderLifecycleEvent>,
+    cancel_client: ClobClient,
+    redis: RedisManager,
+    risk: Arc<RiskState>,
+    early_events: HashMap<String, EarlyEvent>,
+    live_config: Option<Arc<ArcSwap<Config>>>,
+}
+
+impl OrderSweeper {
+    pub fn new(
+        config: OrderSweeperConfig,
+        receiver: mpsc::UnboundedReceiver<OrderLifecycleEvent>,
+        cancel_client: ClobClient,
+        redis: RedisManager,
+        risk: Arc<RiskState>,
+    ) -> Self {
+        let manager = OrderLifecycleManager::new(config.lifecycle.clone());
+        Self {
+            config,
+            manager,
+            receiver,
+            cancel_client,
+            redis,
+            risk,
+            early_events: HashMap::new(),
+            live_config: None,
+        }
+    }
+
+    pub fn with_live_config(mut self, config: Arc<ArcSwap<Config>>) -> Self {
+        self.live_config = Some(config);
+        self
+    }
+
+    fn no_money_mode(&self) -> bool {
+        self.live_config
+            .as_ref()
+            .map(|config| config.load().execution.no_money_mode)
+            .unwrap_or(self.config.no_money_mode)
+    }
+
+    pub fn spawn(self) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            if let Err(error) = self.run().await {
+                tracing::error!(?error, "order sweeper stopped");
+            }
+        })
+    }
+
+    async fn run(mut self) -> Result<()> {
+        let mut interval = tokio::time::interval(self.config.sweep_interval);
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {
+                    if self.no_money_mode() {
+                        continue;
+                    }
+                    let now = now_ms()?;
+                    self.sweep(now).await;
+                }
+                event = self.receiver.recv() => {
+                    match event {
+                        Some(event) => self.apply_event(event, now_ms()?),
+                        None => return Ok(()),
+                    }
+                }
+            }
+        }
+    }
+
+    fn apply_event(&mut self, event: OrderLifecycleEvent, now: u64) {
+        match event {
+            OrderLifecycleEvent::Placed(init) => {
+                let order_id = init.order_id.clone();
+                let created_at_ms = init.created_at_ms;
+                if let Err(error) = self.manager.track_order(init) {
+                    tracing::warn!(?error, order_id = %order_id, "failed to track order");
+                    return;
+                }
+                if let Some(early) = self.early_events.remove(&order_id) {
+                    if let Some(filled_qty) = early.filled_qty {
+                        self.manager
+                            .update_fill(&order_id, filled_qty, created_at_ms);
+                    }
+                    if early.closed {
+                        self.manager.mark_cancelled(&order_id, created_at_ms);
+                    }
+                }
+            }
+            OrderLifecycleEvent::Matched {
+                order_id,
+                filled_qty,
+                event_time_ms,
+            } => {
+                if self.manager.is_tracked(&order_id) {
+                    self.manager
+                        .update_fill(&order_id, filled_qty, event_time_ms);
+                } else {
+                    let entry = self.early_events.entry(order_id).or_default();
+                    entry.filled_qty = Some(filled_qty);
+                    entry.received_at_ms = now;
+                }
+            }
+            OrderLifecycleEvent::Closed {
+                order_id,
+                event_time_ms,
+            } => {
+                if self.manager.is_tracked(&order_id) {
+                    self.manager.mark_cancelled(&order_id, event_time_ms);
+                } else {
+                    let entry = self.early_events.entry(order_id).or_default();
+                    entry.closed = true;
+                    entry.received_at_ms = now;
+                }
+            }
+        }
+    }
+
+    async fn sweep(&mut self, now: u64) {
+        self.refresh_degraded_markets(now).await;
+        for action in self.manager.sweep_actions(now) {
+            match action {
+                OrderAction::Cancel(request) => {
+                    match self.cancel_client.cancel_order(&request.order_id).await {
+                        Ok(response) => {
+                            tracing::info!(
+                                order_id = %request.order_id,
+                                reason = ?request.reason,
+                                cancelled = response.canceled.len(),
+                                "order sweeper cancelled order"
+                            );
+                            self.manager.mark_cancelled(&request.order_id, now);
+                        }
+                        Err(error) => {
+                            tracing::warn!(
+                                ?error,
+                                order_id = %request.order_id,
+                                reason = ?request.reason,
+                                "order sweeper cancel failed; will retry"
+                            );
+                            self.manager.release_pending_cancel(&request.order_id, now);
+                        }
+                    }
+                }
+                OrderAction::CheckFill(check) => {
+                    // Fills arrive through the user websocket; once the check delay has passed
+                    // the manager decides on the next sweep whether the remainder is cancelled.
+                    self.manager.mark_fok_checked(&check.order_id, now);
+                }
+            }
+        }
+        self.manager.purge_completed();
+        self.early_events
+            .retain(|_, early| now.saturating_sub(early.received_at_ms) < EARLY_EVENT_TTL_MS);
+    }
+
+    async fn refresh_degraded_markets(&mut self, now: u64) {
+        let mut markets: HashMap<String, HashSet<String>> = HashMap::new();
+        for order in self.manager.snapshot() {
+            if order.mode == TradeMode::Ladder && order.status == OrderStatus::Active {
+                markets
+                    .entry(order.market_id)
+                    .or_default()
+                    .insert(order.asset_id);
+            }
+        }
+        let halted = self.risk.is_halted();
+        for (market_id, assets) in markets {
+            let mut degraded = halted;
+            for asset_id in &assets {
+                if degraded {
+                    break;
+                }
+                degraded = match self.redis.get_orderbook_update_ms(asset_id).await {
+                    Ok(Some(updated_at_ms)) => {
+                        now.saturating_sub(updated_at_ms) > self.config.degraded_book_stale_ms
+                    }
+                    Ok(None) => true,
+                    Err(error) => {
+                        tracing::warn!(?error, asset_id = %asset_id, "order sweeper book check failed");
+                        false
+                    }
+                };
+                if !degraded {
+                    degraded = match self.redis.get_orderbook_degraded(asset_id).await {
+                        Ok(reason) => reason.is_some(),
+                        Err(error) => {
+                            tracing::warn!(?error, asset_id = %asset_id, "order sweeper book check failed");
+                            false
+                        }
+                    };
+                }
+            }
+            if degraded {
+                self.manager.mark_market_degraded(&market_id, now);
+            } else {
+                self.manager.clear_market_degraded(&market_id);
+            }
+        }
+    }
+}
+
+fn now_ms() -> Result<u64> {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
+    Ok(now.as_millis() as u64)
+}
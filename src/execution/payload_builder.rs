@@ -8,21 +8,25 @@
  * - serde_json: relayer payload construction
  *
  * @notes
- * - Builds signed EIP-712 orders and relayer HMAC auth headers.
+ * - Builds signed EIP-712 orders; L2 auth headers are added by `ClobClient` at send time.
+ * - The order `owner` is read from the client per order so re-derived API keys apply
+ *   without a restart.
+ * - When Rail B is enabled the signed order is also packaged as a direct fillOrders request.
  * - Applies max slippage/impact constraints for taker (Snipe) orders.
  */
 use arc_swap::ArcSwap;
-use ethers_core::types::{Address, U256};
-use secrecy::ExposeSecret;
+use ethers_core::types::{Address, Bytes, U256};
 use serde_json::json;
 use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use crate::config::Config;
+use crate::engine::analysis::calculate_kelly;
 use crate::engine::types::{TradeIntent, TradeMode, TradeSide};
 use crate::error::{BankaiError, Result};
+use crate::execution::clob::ClobClient;
+use crate::execution::direct::{ExchangeOrder, FillOrdersRequest};
 use crate::execution::orchestrator::{ExecutionPayloadBuilder, ExecutionPayloads};
-use crate::execution::relayer::RelayerAuth;
 use crate::execution::signer::{Eip712Signer, OrderSignaturePayload};
 use crate::security::Secrets;
 use crate::storage::orderbook::{BookSide, OrderBookStore};
@@ -30,7 +34,6 @@ use crate::storage::redis::RedisManager;
 
 const USDC_DECIMALS: u32 = 6;
 const LOT_SIZE_DECIMALS: u32 = 2;
-const ORDER_PATH: &str = "/order";
 const VWAP_LEVEL_LIMIT: usize = 50;
 const SNIPE_FEE_SIZE_SCALE_DENOM_BPS: f64 = 2000.0;
 const SNIPE_FEE_SIZE_MIN_SCALE: f64 = 0.25;
@@ -45,10 +48,7 @@ pub struct PolymarketPayloadBuilder {
     orderbook: OrderBookStore,
     signer: Eip712Signer,
     exchange_address: Address,
-    api_key: String,
-    api_passphrase: String,
-    api_secret: String,
-    order_path: String,
+    clob: ClobClient,
 }
 
 impl PolymarketPayloadBuilder {
@@ -57,33 +57,15 @@ impl PolymarketPayloadBuilder {
         redis: RedisManager,
         orderbook: OrderBookStore,
         secrets: &Secrets,
+        clob: ClobClient,
         exchange_address: Address,
         chain_id: u64,
     ) -> Result<Self> {
-        let api_key = secrets
-            .polymarket_api_key
-            .as_ref()
-            .map(|value| value.expose_secret().trim().to_string())
-            .filter(|value| !value.is_empty())
-            .ok_or_else(|| {
-                BankaiError::InvalidArgument("polymarket api key missing".to_string())
-            })?;
-        let api_passphrase = secrets
-            .polymarket_api_passphrase
-            .as_ref()
-            .map(|value| value.expose_secret().trim().to_string())
-            .filter(|value| !value.is_empty())
-            .ok_or_else(|| {
-                BankaiError::InvalidArgument("polymarket api passphrase missing".to_string())
-            })?;
-        let api_secret = secrets
-            .polymarket_api_secret
-            .as_ref()
-            .map(|value| value.expose_secret().trim().to_string())
-            .filter(|value| !value.is_empty())
-            .ok_or_else(|| {
-                BankaiError::InvalidArgument("polymarket api secret missing".to_string())
-            })?;
+        if !clob.has_credentials() {
+            return Err(BankaiError::InvalidArgument(
+                "polymarket api key missing".to_string(),
+            ));
+        }
         let signer = Eip712Signer::from_secrets(secrets, chain_id)?;
 
         Ok(Self {
@@ -92,10 +74,7 @@ impl PolymarketPayloadBuilder {
             orderbook,
             signer,
             exchange_address,
-            api_key,
-            api_passphrase,
-            api_secret,
-            order_path: ORDER_PATH.to_string(),
+            clob,
         })
     }
 
@@ -109,164 +88,14 @@ impl PolymarketPayloadBuilder {
         }
 
         let token_id = parse_token_id(&intent.asset_id)?;
-        let book = fetch_book_quotes(&self.orderbook, &intent.asset_id).await?;
-        let metadata = self.redis.get_market_metadata(&intent.market_id).await?;
-        let fee_rate_bps = self
-            .redis
-            .get_fee_rate_bps(&intent.asset_id)
-            .await?
-            .or(metadata.fee_rate_bps)
-            .unwrap_or(config.fees.taker_fee_bps);
-        let min_tick_size = metadata.min_tick_size.unwrap_or(0.001);
-
-        let price = match (intent.side, intent.mode) {
-            (TradeSide::Buy, TradeMode::Ladder) => {
-                let offset = intent.spread_offset_bps / 10_000.0;
-                let base = book.mid * (1.0 - offset);
-                let max_price = (book.best_ask - min_tick_size).max(0.0);
-                let price = base.min(max_price).max(min_tick_size);
-                round_down_to_tick(price, min_tick_size)
-            }
-            (TradeSide::Buy, TradeMode::Snipe) => round_down_to_tick(book.best_ask, min_tick_size),
-            (TradeSide::Sell, TradeMode::Ladder) => {
-                let offset = intent.spread_offset_bps / 10_000.0;
-                let base = book.mid * (1.0 + offset);
-                let min_price = (book.best_bid + min_tick_size).min(1.0);
-                let price = base.max(min_price).min(1.0);
-                round_down_to_tick(price, min_tick_size)
-            }
-            (TradeSide::Sell, TradeMode::Snipe) => round_down_to_tick(book.best_bid, min_tick_size),
-        };
-
-        if price <= 0.0 || price >= 1.0 {
-            return Err(BankaiError::InvalidArgument(
-                "computed order price invalid".to_string(),
-            ));
-        }
-
-        let mut size = if let Some(requested) = intent.requested_size {
-            requested
-        } else if intent.side == TradeSide::Sell {
-            return Err(BankaiError::InvalidArgument(
-                "sell intent missing requested size".to_string(),
-            ));
-        } else {
-            compute_order_size(
-                &self.redis,
-                intent.true_prob,
-                price,
-                execution.default_order_usdc,
-                execution.min_order_usdc,
-                execution.max_order_usdc,
-                config.strategy.kelly_fraction,
-            )
-            .await?
-        };
-
-        if intent.mode == TradeMode::Snipe && fee_rate_bps > 0.0 {
-            let price_curve = (4.0 * price * (1.0 - price)).clamp(0.0, 1.0);
-            let effective_fee_bps = fee_rate_bps * (1.0 + (SNIPE_FEE_CURVE_ALPHA * price_curve));
-            let scale = (1.0 - (effective_fee_bps / SNIPE_FEE_SIZE_SCALE_DENOM_BPS))
-                .clamp(SNIPE_FEE_SIZE_MIN_SCALE, 1.0);
-            size *= scale;
-        }
-
-        if let Some(min_size) = metadata.min_order_size {
-            if size < min_size {
-                let notional = min_size * price;
-                if notional > execution.max_order_usdc {
-                    return Err(BankaiError::InvalidArgument(
-                        "min order size exceeds max order notional".to_string(),
-                    ));
-                }
-                size = min_size;
-            }
-        }
-
-        size = round_down(size, LOT_SIZE_DECIMALS);
-        if size <= 0.0 {
-            return Err(BankaiError::InvalidArgument(
-                "order size too small after rounding".to_string(),
-            ));
-        }
-
-        if intent.mode == TradeMode::Snipe {
-            let min_size_guard = metadata.min_order_size.unwrap_or(0.0);
-            let mut attempts = 0u8;
-            let (side, best_price, mid_price) = match intent.side {
-                TradeSide::Buy => (BookSide::Ask, book.best_ask, book.mid),
-                TradeSide::Sell => (BookSide::Bid, book.best_bid, book.mid),
-            };
-            loop {
-                let vwap = self
-                    .orderbook
-                    .vwap_for_size(&intent.asset_id, side, size, VWAP_LEVEL_LIMIT)
-                    .await?
-                    .ok_or_else(|| {
-                        BankaiError::InvalidArgument("insufficient order book depth".to_string())
-                    })?;
-                let slippage_bps = if intent.side == TradeSide::Buy {
-                    ((vwap.avg_price - best_price) / best_price) * 10_000.0
-                } else {
-                    ((best_price - vwap.avg_price) / best_price) * 10_000.0
-                };
-                let impact_bps = if intent.side == TradeSide::Buy {
-                    ((vwap.avg_price - mid_price) / mid_price) * 10_000.0
-                } else {
-                    ((mid_price - vwap.avg_price) / mid_price) * 10_000.0
-                };
-                let mut scale: f64 = 1.0;
-                if slippage_bps > execution.max_slippage_bps {
-                    scale = scale.min(execution.max_slippage_bps / slippage_bps);
-                }
-                if impact_bps > execution.max_impact_bps {
-                    scale = scale.min(execution.max_impact_bps / impact_bps);
-                }
-                if slippage_bps > 0.0 {
-                    let slip_scale = (1.0 - (slippage_bps / SNIPE_SLIPPAGE_SIZE_SCALE_DENOM_BPS))
-                        .clamp(SNIPE_SLIPPAGE_SIZE_MIN_SCALE, 1.0);
-                    scale = scale.min(slip_scale);
-                }
-                if scale >= 0.999 || attempts >= 3 {
-                    if slippage_bps > execution.max_slippage_bps
-                        || impact_bps > execution.max_impact_bps
-                    {
-                        tracing::warn!(
-                            slippage_bps = slippage_bps,
-                            impact_bps = impact_bps,
-                            size = size,
-                            "snipe slippage/impact above guard; proceeding with reduced size"
-                        );
-                    }
-                    break;
-                }
-                let new_size = (size * scale).max(min_size_guard);
-                if (new_size - size).abs() < 1e-6 {
-                    break;
-                }
-                size = new_size;
-                attempts += 1;
-            }
-        }
-
-        if let Some(min_size) = metadata.min_order_size {
-            if size < min_size {
-                let notional = min_size * price;
-                if notional > execution.max_order_usdc {
-                    return Err(BankaiError::InvalidArgument(
-                        "min order size exceeds max order notional".to_string(),
-                    ));
-                }
-                size = min_size;
-            }
-        }
-
-        size = round_down(size, LOT_SIZE_DECIMALS);
-        if size <= 0.0 {
-            return Err(BankaiError::InvalidArgument(
-                "order size too small after rounding".to_string(),
-            ));
-        }
+        let OrderPlan {
+            price,
+            size,
+            fee_rate_bps,
+            best_bid,
+            best_ask,
+            mid,
+        } = plan_order(&config, &self.redis, &self.orderbook, intent).await?;
 
         let (maker_amount, taker_amount, side) = match intent.side {
             TradeSide::Buy => (price * size, size, 0u8),
@@ -307,6 +136,7 @@ impl PolymarketPayloadBuilder {
             .order_typed_data(&order, self.exchange_address)?;
         let signature = self.signer.sign_typed_data(&typed_data).await?;
         let signature_hex = signature.to_string();
+        let signature_bytes = Bytes::from(signature.to_vec());
 
         Ok(OrderBuildResult {
             token_id,
@@ -316,11 +146,12 @@ impl PolymarketPayloadBuilder {
             taker_amount,
             order,
             signature: signature_hex,
+            signature_bytes,
             order_type,
             fee_rate_bps,
-            best_bid: book.best_bid,
-            best_ask: book.best_ask,
-            mid: book.mid,
+            best_bid,
+            best_ask,
+            mid,
         })
     }
 }
@@ -328,6 +159,9 @@ impl PolymarketPayloadBuilder {
 #[async_trait::async_trait]
 impl ExecutionPayloadBuilder for PolymarketPayloadBuilder {
     async fn build_payloads(&self, intent: &TradeIntent) -> Result<ExecutionPayloads> {
+        let api_key = self.clob.api_key().ok_or_else(|| {
+            BankaiError::InvalidArgument("polymarket api key missing".to_string())
+        })?;
         let order = self.build_order_payload(intent).await?;
         let order_json = json!({
             "salt": order.order.salt.to_string(),
@@ -346,7 +180,7 @@ impl ExecutionPayloadBuilder for PolymarketPayloadBuilder {
         });
         let relayer_payload = json!({
             "order": order_json,
-            "owner": self.api_key.clone(),
+            "owner": api_key,
             "orderType": order.order_type,
         });
         let relayer_payload = if intent.mode == TradeMode::Ladder
@@ -361,19 +195,6 @@ impl ExecutionPayloadBuilder for PolymarketPayloadBuilder {
             relayer_payload
         };
 
-        let body = serde_json::to_string(&relayer_payload)?;
-        let timestamp = current_unix_timestamp();
-        let signature =
-            build_hmac_signature(&self.api_secret, timestamp, "POST", &self.order_path, &body)?;
-        let auth = RelayerAuth {
-            address: format!("{}", self.signer.address()),
-            api_key: self.api_key.clone(),
-            passphrase: self.api_passphrase.clone(),
-            signature,
-            timestamp: timestamp.to_string(),
-            builder: None,
-        };
-
         let metadata = json!({
             "asset_id": intent.asset_id.as_str(),
             "token_id": order.token_id.to_string(),
@@ -390,16 +211,246 @@ impl ExecutionPayloadBuilder for PolymarketPayloadBuilder {
             "mid": order.mid,
         });
 
+        let direct_request = if self.config.load_full().direct.enabled {
+            Some(direct_fill_request(&order))
+        } else {
+            None
+        };
+
         Ok(ExecutionPayloads {
             relayer_payload,
-            relayer_auth: Some(auth),
-            direct_request: None,
+            builder_auth: None,
+            direct_request,
             fees_paid: estimate_fee_paid(order.fee_rate_bps, order.price, order.size),
             metadata: Some(metadata),
         })
     }
 }
 
+/// Rail B fallback: the same signed order submitted to CTFExchange.fillOrders,
+/// filled for its full maker amount.
+fn direct_fill_request(order: &OrderBuildResult) -> FillOrdersRequest {
+    let exchange_order = ExchangeOrder {
+        salt: order.order.salt,
+        maker: order.order.maker,
+        signer: order.order.signer,
+        taker: order.order.taker,
+        token_id: order.order.token_id,
+        maker_amount: order.order.maker_amount,
+        taker_amount: order.order.taker_amount,
+        expiration: order.order.expiration,
+        nonce: order.order.nonce,
+        fee_rate_bps: order.order.fee_rate_bps,
+        side: order.order.side,
+        signature_type: order.order.signature_type,
+        signature: order.signature_bytes.clone(),
+    };
+    FillOrdersRequest::new(vec![exchange_order], vec![order.order.maker_amount])
+}
+
+/// The limit price `plan_order` will place `intent` at, for sizing before a plan is built.
+pub async fn order_price(
+    redis: &RedisManager,
+    orderbook: &OrderBookStore,
+    intent: &TradeIntent,
+) -> Result<f64> {
+    let book = fetch_book_quotes(orderbook, &intent.asset_id).await?;
+    let metadata = redis.get_market_metadata(&intent.market_id).await?;
+    let min_tick_size = metadata.min_tick_size.unwrap_or(0.001);
+    Ok(price_for_intent(intent, &book, min_tick_size))
+}
+
+/// Ladders rest `spread_offset_bps` away from mid without crossing; snipes take the touch.
+fn price_for_intent(intent: &TradeIntent, book: &BookQuotes, min_tick_size: f64) -> f64 {
+    match (intent.side, intent.mode) {
+        (TradeSide::Buy, TradeMode::Ladder) => {
+            let offset = intent.spread_offset_bps / 10_000.0;
+            let base = book.mid * (1.0 - offset);
+            let max_price = (book.best_ask - min_tick_size).max(0.0);
+            let price = base.min(max_price).max(min_tick_size);
+            round_down_to_tick(price, min_tick_size)
+        }
+        (TradeSide::Buy, TradeMode::Snipe) => round_down_to_tick(book.best_ask, min_tick_size),
+        (TradeSide::Sell, TradeMode::Ladder) => {
+            let offset = intent.spread_offset_bps / 10_000.0;
+            let base = book.mid * (1.0 + offset);
+            let min_price = (book.best_bid + min_tick_size).min(1.0);
+            let price = base.max(min_price).min(1.0);
+            round_down_to_tick(price, min_tick_size)
+        }
+        (TradeSide::Sell, TradeMode::Snipe) => round_down_to_tick(book.best_bid, min_tick_size),
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct OrderPlan {
+    pub price: f64,
+    pub size: f64,
+    pub fee_rate_bps: f64,
+    pub best_bid: f64,
+    pub best_ask: f64,
+    pub mid: f64,
+}
+
+/// Prices and sizes an intent against the current book without signing, so the
+/// live builder and the backtester share the same sizing rules.
+pub async fn plan_order(
+    config: &Config,
+    redis: &RedisManager,
+    orderbook: &OrderBookStore,
+    intent: &TradeIntent,
+) -> Result<OrderPlan> {
+    let execution = &config.execution;
+    let book = fetch_book_quotes(orderbook, &intent.asset_id).await?;
+    let metadata = redis.get_market_metadata(&intent.market_id).await?;
+    let fee_rate_bps = redis
+        .get_fee_rate_bps(&intent.asset_id)
+        .await?
+        .or(metadata.fee_rate_bps)
+        .unwrap_or(config.fees.taker_fee_bps);
+    let min_tick_size = metadata.min_tick_size.unwrap_or(0.001);
+
+    let price = price_for_intent(intent, &book, min_tick_size);
+
+    if price <= 0.0 || price >= 1.0 {
+        return Err(BankaiError::InvalidArgument(
+            "computed order price invalid".to_string(),
+        ));
+    }
+
+    let mut size = if let Some(requested) = intent.requested_size {
+        requested
+    } else if intent.side == TradeSide::Sell {
+        return Err(BankaiError::InvalidArgument(
+            "sell intent missing requested size".to_string(),
+        ));
+    } else {
+        compute_order_size(
+            redis,
+            intent.true_prob,
+            price,
+            execution.default_order_usdc,
+            execution.min_order_usdc,
+            execution.max_order_usdc,
+            config.strategy.kelly_fraction,
+        )
+        .await?
+    };
+
+    if intent.mode == TradeMode::Snipe && fee_rate_bps > 0.0 {
+        let price_curve = (4.0 * price * (1.0 - price)).clamp(0.0, 1.0);
+        let effective_fee_bps = fee_rate_bps * (1.0 + (SNIPE_FEE_CURVE_ALPHA * price_curve));
+        let scale = (1.0 - (effective_fee_bps / SNIPE_FEE_SIZE_SCALE_DENOM_BPS))
+            .clamp(SNIPE_FEE_SIZE_MIN_SCALE, 1.0);
+        size *= scale;
+    }
+
+    if let Some(min_size) = metadata.min_order_size {
+        if size < min_size {
+            let notional = min_size * price;
+            if notional > execution.max_order_usdc {
+                return Err(BankaiError::InvalidArgument(
+                    "min order size exceeds max order notional".to_string(),
+                ));
+            }
+            size = min_size;
+        }
+    }
+
+    size = round_down(size, LOT_SIZE_DECIMALS);
+    if size <= 0.0 {
+        return Err(BankaiError::InvalidArgument(
+            "order size too small after rounding".to_string(),
+        ));
+    }
+
+    if intent.mode == TradeMode::Snipe {
+        let min_size_guard = metadata.min_order_size.unwrap_or(0.0);
+        let mut attempts = 0u8;
+        let (side, best_price, mid_price) = match intent.side {
+            TradeSide::Buy => (BookSide::Ask, book.best_ask, book.mid),
+            TradeSide::Sell => (BookSide::Bid, book.best_bid, book.mid),
+        };
+        loop {
+            let vwap = orderbook
+                .vwap_for_size(&intent.asset_id, side, size, VWAP_LEVEL_LIMIT)
+                .await?
+                .ok_or_else(|| {
+                    BankaiError::InvalidArgument("insufficient order book depth".to_string())
+                })?;
+            let slippage_bps = if intent.side == TradeSide::Buy {
+                ((vwap.avg_price - best_price) / best_price) * 10_000.0
+            } else {
+                ((best_price - vwap.avg_price) / best_price) * 10_000.0
+            };
+            let impact_bps = if intent.side == TradeSide::Buy {
+                ((vwap.avg_price - mid_price) / mid_price) * 10_000.0
+            } else {
+                ((mid_price - vwap.avg_price) / mid_price) * 10_000.0
+            };
+            let mut scale: f64 = 1.0;
+            if slippage_bps > execution.max_slippage_bps {
+                scale = scale.min(execution.max_slippage_bps / slippage_bps);
+            }
+            if impact_bps > execution.max_impact_bps {
+                scale = scale.min(execution.max_impact_bps / impact_bps);
+            }
+            if slippage_bps > 0.0 {
+                let slip_scale = (1.0 - (slippage_bps / SNIPE_SLIPPAGE_SIZE_SCALE_DENOM_BPS))
+                    .clamp(SNIPE_SLIPPAGE_SIZE_MIN_SCALE, 1.0);
+                scale = scale.min(slip_scale);
+            }
+            if scale >= 0.999 || attempts >= 3 {
+                if slippage_bps > execution.max_slippage_bps
+                    || impact_bps > execution.max_impact_bps
+                {
+                    tracing::warn!(
+                        slippage_bps = slippage_bps,
+                        impact_bps = impact_bps,
+                        size = size,
+                        "snipe slippage/impact above guard; proceeding with reduced size"
+                    );
+                }
+                break;
+            }
+            let new_size = (size * scale).max(min_size_guard);
+            if (new_size - size).abs() < 1e-6 {
+                break;
+            }
+            size = new_size;
+            attempts += 1;
+        }
+    }
+
+    if let Some(min_size) = metadata.min_order_size {
+        if size < min_size {
+            let notional = min_size * price;
+            if notional > execution.max_order_usdc {
+                return Err(BankaiError::InvalidArgument(
+                    "min order size exceeds max order notional".to_string(),
+                ));
+            }
+            size = min_size;
+        }
+    }
+
+    size = round_down(size, LOT_SIZE_DECIMALS);
+    if size <= 0.0 {
+        return Err(BankaiError::InvalidArgument(
+            "order size too small after rounding".to_string(),
+        ));
+    }
+
+    Ok(OrderPlan {
+        price,
+        size,
+        fee_rate_bps,
+        best_bid: book.best_bid,
+        best_ask: book.best_ask,
+        mid: book.mid,
+    })
+}
+
 struct OrderBuildResult {
     token_id: U256,
     price: f64,
@@ -408,6 +459,7 @@ struct OrderBuildResult {
     taker_amount: U256,
     order: OrderSignaturePayload,
     signature: String,
+    signature_bytes: Bytes,
     order_type: String,
     fee_rate_bps: f64,
     best_bid: f64,
@@ -480,19 +532,6 @@ async fn compute_order_size(
     Ok(notional / price)
 }
 
-fn calculate_kelly(win_prob: f64, odds: f64) -> f64 {
-    if win_prob <= 0.0 || win_prob >= 1.0 {
-        return 0.0;
-    }
-    if odds <= 1.0 {
-        return 0.0;
-    }
-    let payout = odds - 1.0;
-    let loss_prob = 1.0 - win_prob;
-    let kelly = (win_prob * payout - loss_prob) / payout;
-    kelly.clamp(0.0, 1.0)
-}
-
 fn round_down(value: f64, decimals: u32) -> f64 {
     if decimals == 0 {
         return value.floor();
@@ -577,30 +616,6 @@ fn current_unix_timestamp() -> u64 {
     duration.as_secs()
 }
 
-fn build_hmac_signature(
-    secret: &str,
-    timestamp: u64,
-    method: &str,
-    path: &str,
-    body: &str,
-) -> Result<String> {
-    use base64::engine::general_purpose;
-    use base64::Engine as _;
-    use hmac::{Hmac, Mac};
-    type HmacSha256 = Hmac<sha2::Sha256>;
-
-    let key = general_purpose::STANDARD
-        .decode(secret)
-        .map_err(|err| BankaiError::InvalidArgument(format!("api secret decode error: {err}")))?;
-    let message = format!("{timestamp}{method}{path}{body}");
-    let mut mac = HmacSha256::new_from_slice(&key)
-        .map_err(|_| BankaiError::InvalidArgument("api secret is invalid for hmac".to_string()))?;
-    mac.update(message.as_bytes());
-    let result = mac.finalize().into_bytes();
-    let signature = general_purpose::STANDARD.encode(result);
-    Ok(signature.replace('+', "-").replace('/', "_"))
-}
-
 #[cfg(test)]
 mod tests_end {
     use super::*;
@@ -9,9 +9,12 @@
  *
  * @notes
  * - ClobAuth signatures follow the Polymarket CLOB auth domain specification.
+ * - Addresses go into typed data and auth headers in checksummed form; `Address`'s `Display`
+ *   abbreviates the hex and does not parse back.
  */
 use ethers_core::types::transaction::eip712::TypedData;
 use ethers_core::types::{Address, Signature, U256};
+use ethers_core::utils::to_checksum;
 use ethers_signers::{LocalWallet, Signer};
 use secrecy::{ExposeSecret, SecretString};
 use serde_json::json;
@@ -75,6 +78,11 @@ impl Eip712Signer {
         self.wallet.address()
     }
 
+    /// EIP-55 checksummed wallet address, as sent in `POLY_ADDRESS`.
+    pub fn checksum_address(&self) -> String {
+        to_checksum(&self.wallet.address(), None)
+    }
+
     pub fn chain_id(&self) -> u64 {
         self.chain_id
     }
@@ -148,7 +156,7 @@ fn build_clob_auth_typed_data(
             "chainId": chain_id
         },
         "message": {
-            "address": format!("{address}"),
+            "address": to_checksum(&address, None),
             "timestamp": timestamp,
             "nonce": nonce,
             "message": CLOB_AUTH_MESSAGE
@@ -0,0 +1,343 @@
+/**
+ * @description
+ * Rail B transaction monitor: confirms pending nonces via receipts and replaces stuck transactions.
+ *
+ * @dependencies
+ * - execution::direct: receipts, chain nonce, and raw resubmission
+ * - execution::nonce: pending nonce bookkeeping in Redis
+ *
+ * @notes
+ * - A nonce is confirmed when any broadcast hash has a receipt, or when the chain nonce has
+ *   moved past it (mined by a transaction this process never saw).
+ * - Replacements reuse the original calldata and gas limit with fees bumped by `rbf_bump_pct`.
+ *   A fee cap that leaves either fee short of the node's 10% replacement minimum skips the
+ *   replacement rather than sending one that would be rejected as underpriced.
+ * - Nonces that hit `rbf_max_bumps` are reported once and then left alone.
+ */
+use ethers_core::types::{Bytes, H256, U256};
+use std::collections::HashSet;
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::config::Config;
+use crate::error::{BankaiError, Result};
+use crate::execution::direct::{DirectCallOptions, DirectExecutionClient};
+use crate::execution::nonce::{NonceManager, NonceManagerConfig, NonceSubmission};
+use crate::telemetry::metrics;
+
+const MIN_RBF_BUMP_PCT: f64 = 10.0;
+const WEI_PER_GWEI: u64 = 1_000_000_000;
+
+#[derive(Debug, Clone)]
+pub struct TxMonitorConfig {
+    pub poll_interval: Duration,
+    pub bump_pct: f64,
+    pub max_bumps: u32,
+    pub max_fee_per_gas_wei: Option<U256>,
+}
+
+impl TxMonitorConfig {
+    pub fn from_config(config: &Config) -> Self {
+        let direct = &config.direct;
+        Self {
+            poll_interval: Duration::from_millis(direct.monitor_interval_ms.max(250)),
+            // Nodes reject replacements that bump fees by less than 10%.
+            bump_pct: direct.rbf_bump_pct.max(MIN_RBF_BUMP_PCT),
+            max_bumps: direct.rbf_max_bumps,
+            max_fee_per_gas_wei: direct
+                .rbf_max_fee_per_gas_gwei
+                .map(|gwei| U256::from(gwei) * U256::from(WEI_PER_GWEI)),
+        }
+    }
+}
+
+pub fn nonce_manager_config(config: &Config) -> NonceManagerConfig {
+    NonceManagerConfig {
+        stuck_timeout_ms: config.direct.nonce_stuck_timeout_ms,
+        bump_cooldown_ms: config.direct.nonce_bump_cooldown_ms,
+        lock_ttl_ms: config.direct.nonce_lock_ttl_ms,
+    }
+}
+
+/// Seeds the Redis nonce from the chain, or moves it forward if the chain is ahead
+/// (e.g. the wallet was used elsewhere while the bot was down).
+pub async fn initialize_nonce(
+    direct: &DirectExecutionClient,
+    manager: &NonceManager,
+) -> Result<u64> {
+    let chain_nonce = u256_to_u64(direct.fetch_chain_nonce().await?)?;
+    if manager.initialize_if_missing(chain_nonce).await? {
+        return Ok(chain_nonce);
+    }
+    match manager.get_next_nonce().await? {
+        Some(stored) if stored >= chain_nonce => Ok(stored),
+        stored => {
+            tracing::warn!(?stored, chain_nonce, "stored nonce behind chain; resyncing");
+            manager.set_next_nonce(chain_nonce).await?;
+            Ok(chain_nonce)
+        }
+    }
+}
+
+pub struct DirectTxMonitor {
+    config: TxMonitorConfig,
+    direct: DirectExecutionClient,
+    nonce_manager: NonceManager,
+    /// Stuck nonces already reported as out of bumps.
+    abandoned: Mutex<HashSet<u64>>,
+}
+
+impl DirectTxMonitor {
+    pub fn new(
+        config: TxMonitorConfig,
+        direct: DirectExecutionClient,
+        nonce_manager: NonceManager,
+    ) -> Self {
+        Self {
+            config,
+            direct,
+            nonce_manager,
+            abandoned: Mutex::new(HashSet::new()),
+        }
+    }
+
+    pub fn spawn(self) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            if let Err(error) = self.run().await {
+                tracing::error!(?error, "direct tx monitor stopped");
+            }
+        })
+    }
+
+    async fn run(self) -> Result<()> {
+        let mut interval = tokio::time::interval(self.config.poll_interval);
+        loop {
+            interval.tick().await;
+            if let Err(error) = self.poll().await {
+                tracing::warn!(?error, "direct tx monitor poll failed");
+            }
+        }
+    }
+
+    async fn poll(&self) -> Result<()> {
+        let pending = self.nonce_manager.pending_nonces().await?;
+        if pending.is_empty() {
+            return Ok(());
+        }
+        let chain_nonce = u256_to_u64(self.direct.fetch_chain_nonce().await?)?;
+        for entry in &pending {
+            let submission = self.nonce_manager.submission(entry.nonce).await?;
+            self.confirm_if_mined(entry.nonce, chain_nonce, submission.as_ref())
+                .await?;
+        }
+
+        if let Ok(mut abandoned) = self.abandoned.lock() {
+            abandoned.retain(|nonce| *nonce >= chain_nonce);
+        }
+
+        let now = now_ms()?;
+        for entry in self.nonce_manager.stale_nonces(now).await? {
+            if entry.nonce < chain_nonce {
+                continue;
+            }
+            let Some(submission) = self.nonce_manager.submission(entry.nonce).await? else {
+                tracing::warn!(
+                    nonce = entry.nonce,
+                    "stuck nonce has no recorded submission; cannot replace"
+                );
+                continue;
+            };
+            if entry.bump_count >= self.config.max_bumps {
+                let first_report = self
+                    .abandoned
+                    .lock()
+                    .map(|mut abandoned| abandoned.insert(entry.nonce))
+                    .unwrap_or(false);
+                if first_report {
+                    tracing::warn!(
+                        nonce = entry.nonce,
+                        bumps = entry.bump_count,
+                        "stuck nonce reached max fee bumps; no further replacements"
+                    );
+                }
+                continue;
+            }
+            if let Err(error) = self.replace(entry.nonce, submission, now).await {
+                tracing::warn!(?error, nonce = entry.nonce, "direct tx replacement failed");
+            }
+        }
+        Ok(())
+    }
+
+    async fn confirm_if_mined(
+        &self,
+        nonce: u64,
+        chain_nonce: u64,
+        submission: Option<&NonceSubmission>,
+    ) -> Result<()> {
+        let tx_hashes = submission
+            .map(|submission| submission.tx_hashes.as_slice())
+            .unwrap_or_default();
+        for raw_hash in tx_hashes.iter().rev() {
+            let tx_hash = H256::from_str(raw_hash)
+                .map_err(|_| BankaiError::InvalidArgument(format!("invalid tx hash {raw_hash}")))?;
+            if let Some(receipt) = self.direct.transaction_receipt(tx_hash).await? {
+                let succeeded = receipt.status.map(|status| status.as_u64() == 1);
+                if succeeded == Some(false) {
+                    tracing::warn!(nonce, tx_hash = %raw_hash, "direct tx mined but reverted");
+                } else {
+                    tracing::info!(
+                        nonce,
+                        tx_hash = %raw_hash,
+                        block = ?receipt.block_number,
+                        "direct tx confirmed"
+                    );
+                }
+                return self.nonce_manager.mark_confirmed(nonce).await;
+            }
+        }
+        if nonce < chain_nonce {
+            tracing::warn!(
+                nonce,
+                "nonce consumed on chain without a receipt for a tracked hash"
+            );
+            self.nonce_manager.mark_confirmed(nonce).await?;
+        }
+        Ok(())
+    }
+
+    async fn replace(&self, nonce: u64, mut submission: NonceSubmission, now: u64) -> Result<()> {
+        let call_data = Bytes::from_str(&submission.call_data)
+            .map_err(|_| BankaiError::InvalidArgument("invalid stored calldata".to_string()))?;
+        let gas_limit = parse_u256(&submission.gas_limit, "gas limit")?;
+        let Some(max_fee) = self.bump(parse_u256(&submission.max_fee_per_gas, "max fee")?) else {
+            tracing::warn!(nonce, "stuck nonce already at rbf fee cap; not replacing");
+            return Ok(());
+        };
+        let Some(max_priority) = capped_bump(
+            parse_u256(&submission.max_priority_fee_per_gas, "priority fee")?,
+            self.config.bump_pct,
+            Some(max_fee),
+        ) else {
+            tracing::warn!(
+                nonce,
+                "priority fee cannot be bumped under the max fee; not replacing"
+            );
+            return Ok(());
+        };
+
+        let options = DirectCallOptions {
+            nonce: Some(nonce.into()),
+            gas_limit: Some(gas_limit),
+            max_fee_per_gas_wei: Some(max_fee),
+            max_priority_fee_per_gas_wei: Some(max_priority),
+            value: None,
+        };
+        let result = self.direct.send_call_data(call_data, &options).await?;
+        self.nonce_manager.record_bump(nonce, now).await?;
+        metrics::increment_direct_tx_replaced();
+
+        let tx_hash = format!("{:?}", result.tx_hash);
+        tracing::warn!(
+            nonce,
+            tx_hash = %tx_hash,
+            max_fee_per_gas = %result.max_fee_per_gas,
+            "replaced stuck direct tx"
+        );
+        submission.tx_hashes.push(tx_hash);
+        submission.max_fee_per_gas = result.max_fee_per_gas.to_string();
+        submission.max_priority_fee_per_gas = result.max_priority_fee_per_gas.to_string();
+        self.nonce_manager
+            .record_submission(nonce, &submission)
+            .await
+    }
+
+    /// Returns `None` when the fee cap leaves no room for a valid replacement.
+    fn bump(&self, max_fee: U256) -> Option<U256> {
+        capped_bump(
+            max_fee,
+            self.config.bump_pct,
+            self.config.max_fee_per_gas_wei,
+        )
+    }
+}
+
+fn bump_fee(fee: U256, bump_pct: f64) -> U256 {
+    let basis_points = U256::from((bump_pct * 100.0).round().max(0.0) as u64);
+    let bumped = fee + fee * basis_points / U256::from(10_000u64);
+    // Round up so tiny fees still clear the replacement threshold.
+    if bumped == fee {
+        fee + U256::one()
+    } else {
+        bumped
+    }
+}
+
+/// Bumps `fee` under `cap`, or `None` when the capped fee would fall below the node's
+/// replacement minimum.
+fn capped_bump(fee: U256, bump_pct: f64, cap: Option<U256>) -> Option<U256> {
+    let bumped = bump_fee(fee, bump_pct);
+    let bumped = match cap {
+        Some(cap) => bumped.min(cap),
+        None => bumped,
+    };
+    (bumped >= bump_fee(fee, MIN_RBF_BUMP_PCT)).then_some(bumped)
+}
+
+fn parse_u256(value: &str, label: &str) -> Result<U256> {
+    U256::from_dec_str(value)
+        .map_err(|_| BankaiError::InvalidArgument(format!("invalid stored {label}")))
+}
+
+fn u256_to_u64(value: U256) -> Result<u64> {
+    if value > U256::from(u64::MAX) {
+        return Err(BankaiError::InvalidArgument(
+            "chain nonce exceeds u64".to_string(),
+        ));
+    }
+    Ok(value.as_u64())
+}
+
+fn now_ms() -> Result<u64> {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
+    Ok(now.as_millis() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bump_fee_clears_replacement_threshold() {
+        let fee = U256::from(30_000_000_000u64);
+        assert_eq!(bump_fee(fee, 15.0), U256::from(34_500_000_000u64));
+        assert_eq!(bump_fee(U256::from(1u64), 10.0), U256::from(2u64));
+    }
+
+    #[test]
+    fn capped_bump_reports_no_bump_at_cap() {
+        let fee = U256::from(100u64);
+        assert_eq!(capped_bump(fee, 15.0, None), Some(U256::from(115u64)));
+        assert_eq!(
+            capped_bump(fee, 15.0, Some(U256::from(112u64))),
+            Some(U256::from(112u64))
+        );
+        assert_eq!(
+            capped_bump(fee, 15.0, Some(U256::from(110u64))),
+            Some(U256::from(110u64))
+        );
+        assert_eq!(capped_bump(fee, 10.0, Some(U256::from(105u64))), None);
+        assert_eq!(capped_bump(fee, 10.0, Some(fee)), None);
+        // The priority fee is capped by the bumped max fee under the same rule.
+        assert_eq!(
+            capped_bump(U256::from(100u64), 15.0, Some(U256::from(230u64))),
+            Some(U256::from(115u64))
+        );
+        assert_eq!(
+            capped_bump(U256::from(100u64), 15.0, Some(U256::from(109u64))),
+            None
+        );
+        assert_eq!(capped_bump(fee, 10.0, Some(U256::from(50u64))), None);
+    }
+}
@@ -11,6 +11,7 @@
 
 use arc_swap::ArcSwap;
 use bankai_terminal::accounting::bankroll_refresh::BankrollRefresher;
+use bankai_terminal::accounting::meta_brain::MetaBrain;
 use bankai_terminal::accounting::no_money::spawn_no_money_tracker;
 use bankai_terminal::accounting::no_money::PaperSimConfig;
 use bankai_terminal::accounting::open_orders_refresh::OpenOrdersRefresher;
@@ -33,20 +34,35 @@ use bankai_terminal::accounting::recovery::StartupRecovery;
 use bankai_terminal::accounting::redemption::{
     RedemptionClient, RedemptionConfig, RedemptionListener, RedisPositionResolver,
 };
+use bankai_terminal::accounting::regret::RegretTracker;
 use bankai_terminal::config::{Config, ConfigManager};
+use bankai_terminal::engine::control::{ControlCommand, ControlPlane};
 use bankai_terminal::engine::core::EngineCore;
+use bankai_terminal::engine::python_host::PythonStrategyBackend;
 use bankai_terminal::engine::risk::{KillSwitchConfig, RiskState};
+use bankai_terminal::engine::source_guard::SourceStatusMap;
 use bankai_terminal::engine::trader::TradingEngine;
 use bankai_terminal::engine::types::MarketUpdate;
 use bankai_terminal::error::Result;
 use bankai_terminal::execution::allowances::AllowanceManager;
+use bankai_terminal::execution::clob::{ClobClient, ClobClientConfig, ClobCredentials};
+use bankai_terminal::execution::direct::{DirectExecutionClient, DirectExecutionConfig};
+use bankai_terminal::execution::nonce::NonceManager;
 use bankai_terminal::execution::orchestrator::{
     ExecutionOrchestrator, ExecutionOrchestratorConfig,
 };
+use bankai_terminal::execution::order_sweeper::{
+    order_lifecycle_channel, OrderLifecycleEvent, OrderLifecycleHandle, OrderSweeper,
+    OrderSweeperConfig,
+};
 use bankai_terminal::execution::payload_builder::PolymarketPayloadBuilder;
-use bankai_terminal::execution::relayer::{RelayerClient, RelayerConfig};
 use bankai_terminal::execution::signer::Eip712Signer;
-use bankai_terminal::oracle::allora::{AlloraConsumerTopic, AlloraOracle, AlloraOracleConfig};
+use bankai_terminal::execution::tx_monitor::{self, DirectTxMonitor, TxMonitorConfig};
+use bankai_terminal::oracle::allora::{
+    AlloraConsumerTopic, AlloraOracle, AlloraOracleConfig, AlloraSignatureStats,
+    AlloraSignatureVerifier,
+};
+use bankai_terminal::oracle::binance::{BinanceOracle, BinanceOracleConfig};
 use bankai_terminal::oracle::chainlink::{ChainlinkOracle, ChainlinkOracleConfig};
 use bankai_terminal::oracle::polymarket_discovery::{
     PolymarketDiscovery, PolymarketDiscoveryConfig,
@@ -55,13 +71,17 @@ use bankai_terminal::oracle::polymarket_rtds::{PolymarketRtds, PolymarketRtdsCon
 use bankai_terminal::oracle::polymarket_user_ws::{
     PolymarketUserAuth, PolymarketUserWs, PolymarketUserWsConfig,
 };
-use bankai_terminal::security::{self, DEFAULT_SECRETS_PATH};
+use bankai_terminal::security::provider::{ProviderChain, SecretsProvider};
+use bankai_terminal::security::{self, SecretsFile};
 use bankai_terminal::storage::orderbook::OrderBookStore;
+use bankai_terminal::storage::recorder::{
+    spawn_market_tap, FeedRecorder, FeedRecorderConfig, RecorderHandle,
+};
 use bankai_terminal::storage::redis::RedisManager;
 use bankai_terminal::telemetry::health::HealthMonitor;
 use bankai_terminal::telemetry::{logging, metrics, preflight};
 use bankai_terminal::ui;
-use secrecy::ExposeSecret;
+use bankai_terminal::ui::admin::AdminServer;
 use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::Duration;
@@ -70,7 +90,6 @@ use tokio::sync::{broadcast, mpsc};
 #[tokio::main]
 async fn main() -> Result<()> {
     logging::init_tracing();
-    metrics::init_metrics();
     tracing::info!("bankai terminal booting");
     let mut preflight_ok = true;
 
@@ -80,8 +99,16 @@ async fn main() -> Result<()> {
     let config = config_manager.current();
 
     tracing::info!(?config, "config loaded");
-    let secrets = security::load_secrets_with_env_or_prompt(DEFAULT_SECRETS_PATH)?;
-    tracing::info!("secrets loaded");
+    if let Err(error) = metrics::init_metrics(&config.telemetry) {
+        tracing::warn!(
+            ?error,
+            "metrics exporter unavailable; continuing without /metrics"
+        );
+    }
+    let secrets_provider = ProviderChain::from_config(&config.secrets)?;
+    let secrets = secrets_provider.load()?;
+    tracing::info!(providers = %secrets_provider.names(), "secrets loaded");
+    let clob = build_clob_client(&config, &secrets, secrets_provider.secrets_file()).await?;
 
     if config.preflight.enabled {
         let report = preflight::run(&config).await?;
@@ -104,7 +131,7 @@ async fn main() -> Result<()> {
     let mut bankroll_ready = false;
     if let Ok(redis_url) = std::env::var("REDIS_URL") {
         match RedisManager::new(&redis_url).await {
-            Ok(redis) => match StartupRecovery::from_env(&config, &secrets, redis) {
+            Ok(redis) => match StartupRecovery::from_env(&config, &secrets, redis, &clob) {
                 Ok(Some(recovery)) => match recovery.run().await {
                     Ok(report) => {
                         bankroll_ready = report.collateral_balance > 0.0;
@@ -152,38 +179,74 @@ async fn main() -> Result<()> {
     let wallet_key_for_ui = Eip712Signer::from_secrets(&secrets, chain_id)
         .map(|signer| format!("{}", signer.address()).to_ascii_lowercase())
         .ok();
-    let tui_handle = spawn_tui_if_enabled(
-        config_state.clone(),
-        risk.clone(),
-        market_tx.clone(),
-        wallet_key_for_ui,
-    )
-    .await
-    .ok()
-    .flatten();
-    let engine = EngineCore::new(config_state.clone(), risk.clone());
+    let (control_tx, control_rx) = mpsc::unbounded_channel();
+    let allora_signature_stats = Arc::new(AlloraSignatureStats::new());
+    let tui_enabled = ui::is_tui_enabled();
+    let snapshot_feed = if tui_enabled || config.admin.enabled {
+        let ui_redis = connect_ui_redis().await;
+        let feed = ui::spawn_snapshot_feed(
+            config_state.clone(),
+            risk.clone(),
+            market_tx.subscribe(),
+            ui_redis.clone(),
+            wallet_key_for_ui.clone(),
+            allora_signature_stats.clone(),
+        );
+        Some((feed, ui_redis))
+    } else {
+        None
+    };
+    let tui_handle = match snapshot_feed.as_ref() {
+        Some((feed, _)) if tui_enabled => spawn_tui(feed.clone(), control_tx.clone()),
+        _ => None,
+    };
+    if let Some((feed, ui_redis)) = snapshot_feed {
+        spawn_admin_server(
+            &config,
+            feed,
+            ui_redis,
+            wallet_key_for_ui,
+            control_tx,
+            config_manager.clone(),
+        )?;
+    }
+    let source_statuses = Arc::new(SourceStatusMap::new());
+    let engine = EngineCore::new(config_state.clone(), risk.clone())
+        .with_source_statuses(source_statuses.clone());
     let _engine_handle = engine.spawn(market_tx.subscribe());
 
+    let recorder = spawn_feed_recorder(&config, &market_tx)?;
     spawn_chainlink_oracle(&config, market_tx.clone()).await?;
-    spawn_allora_oracle(&config, market_tx.clone())?;
-    spawn_polymarket_oracles(&config).await?;
-    let user_ws_enabled = spawn_polymarket_user_ws(&config, &secrets).await?;
-    spawn_execution_pipeline(
+    spawn_binance_oracle(&config, market_tx.clone()).await?;
+    spawn_allora_oracle(&config, market_tx.clone(), allora_signature_stats)?;
+    let orderbook = spawn_polymarket_oracles(&config, recorder.clone(), &clob).await?;
+    let (order_lifecycle, order_lifecycle_rx) = order_lifecycle_channel();
+    let user_ws_enabled =
+        spawn_polymarket_user_ws(&config, &secrets, &clob, recorder, order_lifecycle.clone())
+            .await?;
+    let control = spawn_execution_pipeline(
         &config,
         config_state.clone(),
         risk.clone(),
+        source_statuses,
         &secrets,
         market_tx,
         user_ws_enabled,
+        (order_lifecycle, order_lifecycle_rx),
+        ControlPlane::new(risk.clone(), config_manager.clone()),
+        orderbook.clone(),
+        &clob,
     )
     .await?;
+    let _control_handle = control.spawn(control_rx);
     spawn_allowance_manager(&config, &secrets).await?;
     spawn_bankroll_refresher(&config, &secrets).await?;
-    spawn_trade_reconciler(&config, &secrets, user_ws_enabled).await?;
-    spawn_open_orders_refresher(&config, &secrets).await?;
-    spawn_pnl_monitor(&config, &secrets).await?;
+    spawn_trade_reconciler(&config, &secrets, user_ws_enabled, &clob).await?;
+    spawn_open_orders_refresher(&config, &secrets, &clob).await?;
+    spawn_pnl_monitor(&config, &secrets, orderbook).await?;
     spawn_redemption_listener(&config, &secrets).await?;
     spawn_no_money(&config, config_state.clone()).await?;
+    spawn_meta_brain(&config, config_manager.clone()).await?;
 
     tracing::info!("engine running");
     tokio::signal::ctrl_c().await?;
@@ -224,9 +287,44 @@ async fn spawn_chainlink_oracle(
     Ok(())
 }
 
+async fn spawn_binance_oracle(
+    config: &Arc<Config>,
+    sender: broadcast::Sender<MarketUpdate>,
+) -> Result<()> {
+    if !config.binance.enabled {
+        tracing::warn!("binance oracle disabled; chainlink divergence checks off");
+        return Ok(());
+    }
+    let symbols = derive_binance_symbols(config);
+    let redis = match std::env::var("REDIS_URL") {
+        Ok(url) => match RedisManager::new(&url).await {
+            Ok(manager) => Some(manager),
+            Err(error) => {
+                tracing::warn!(
+                    ?error,
+                    "redis unavailable; binance window alignment disabled"
+                );
+                None
+            }
+        },
+        Err(_) => None,
+    };
+    let binance_config = BinanceOracleConfig {
+        endpoint: config.endpoints.binance_ws.clone(),
+        symbols,
+        candle_interval: Duration::from_secs(60),
+        window_refresh_interval: Duration::from_secs(5),
+        redis,
+    };
+    let oracle = BinanceOracle::new(binance_config);
+    let _handle = oracle.spawn(sender);
+    Ok(())
+}
+
 fn spawn_allora_oracle(
     config: &Arc<Config>,
     sender: broadcast::Sender<MarketUpdate>,
+    signature_stats: Arc<AlloraSignatureStats>,
 ) -> Result<()> {
     let Some(allora) = config.allora_consumer.as_ref() else {
         tracing::warn!("allora consumer config missing; allora oracle disabled");
@@ -253,26 +351,52 @@ fn spawn_allora_oracle(
             .clone()
             .or_else(|| std::env::var("ALLORA_API_KEY").ok()),
     };
-    let oracle = AlloraOracle::new(oracle_config)?;
+    let verifier = AlloraSignatureVerifier::from_config(&allora.signature, &allora.chain)?;
+    if let Some(verifier) = verifier.as_ref() {
+        tracing::info!(mode = ?verifier.mode(), "allora signature verification enabled");
+    }
+    let oracle =
+        AlloraOracle::new(oracle_config)?.with_signature_verifier(verifier, signature_stats);
     let _handle = oracle.spawn(sender);
     Ok(())
 }
 
-async fn spawn_polymarket_oracles(config: &Arc<Config>) -> Result<()> {
+fn spawn_feed_recorder(
+    config: &Arc<Config>,
+    sender: &broadcast::Sender<MarketUpdate>,
+) -> Result<Option<RecorderHandle>> {
+    if !config.recorder.enabled {
+        tracing::info!("feed recorder disabled");
+        return Ok(None);
+    }
+    let (recorder, handle) = FeedRecorder::new(FeedRecorderConfig::from_config(&config.recorder))?;
+    let _recorder_handle = recorder.spawn();
+    let _tap_handle = spawn_market_tap(handle.clone(), sender.subscribe());
+    tracing::info!(directory = %config.recorder.directory, "feed recorder enabled");
+    Ok(Some(handle))
+}
+
+async fn spawn_polymarket_oracles(
+    config: &Arc<Config>,
+    recorder: Option<RecorderHandle>,
+    clob: &ClobClient,
+) -> Result<Option<OrderBookStore>> {
     let redis_url = match std::env::var("REDIS_URL") {
         Ok(value) => value,
         Err(_) => {
             tracing::warn!("REDIS_URL not set; polymarket oracles disabled");
-            return Ok(());
+            return Ok(None);
         }
     };
 
     let redis = RedisManager::new(&redis_url).await?;
-    let discovery_config = PolymarketDiscoveryConfig::new(
-        config.endpoints.polymarket_gamma.clone(),
-        config.endpoints.relayer_http.clone(),
-    );
-    let discovery = PolymarketDiscovery::new(discovery_config, redis.clone())?;
+    let mut discovery_config =
+        PolymarketDiscoveryConfig::new(config.endpoints.polymarket_gamma.clone());
+    discovery_config.markets = config.polymarket.markets.clone();
+    let mut discovery = PolymarketDiscovery::new(discovery_config, redis.clone(), clob.clone())?;
+    if let Some(recorder) = recorder.clone() {
+        discovery = discovery.with_recorder(recorder);
+    }
     let _discovery_handle = discovery.spawn();
 
     let mut rtds_config = PolymarketRtdsConfig::new(
@@ -288,15 +412,24 @@ async fn spawn_polymarket_oracles(config: &Arc<Config>) -> Result<()> {
     rtds_config.reconnect_delay = Duration::from_secs(config.polymarket.reconnect_delay_secs);
     rtds_config.snapshot_timeout = Duration::from_millis(config.polymarket.snapshot_timeout_ms);
 
-    let orderbook = OrderBookStore::new(redis);
-    let rtds = PolymarketRtds::new(rtds_config, orderbook)?;
+    let orderbook = OrderBookStore::new(redis).with_local_book();
+    let _mirror_handle = orderbook.spawn_mirror(Duration::from_millis(
+        config.polymarket.book_mirror_interval_ms.max(10),
+    ));
+    let mut rtds = PolymarketRtds::new(rtds_config, orderbook.clone())?;
+    if let Some(recorder) = recorder {
+        rtds = rtds.with_recorder(recorder);
+    }
     let _rtds_handle = rtds.spawn();
-    Ok(())
+    Ok(Some(orderbook))
 }
 
 async fn spawn_polymarket_user_ws(
     config: &Arc<Config>,
     secrets: &security::Secrets,
+    clob: &ClobClient,
+    recorder: Option<RecorderHandle>,
+    order_lifecycle: OrderLifecycleHandle,
 ) -> Result<bool> {
     let redis_url = match std::env::var("REDIS_URL") {
         Ok(value) => value,
@@ -305,26 +438,9 @@ async fn spawn_polymarket_user_ws(
             return Ok(false);
         }
     };
-    let api_key = match secrets.polymarket_api_key.as_ref() {
-        Some(value) => value.expose_secret().to_string(),
-        None => {
-            tracing::warn!("polymarket api key missing; user ws disabled");
-            return Ok(false);
-        }
-    };
-    let api_secret = match secrets.polymarket_api_secret.as_ref() {
-        Some(value) => value.expose_secret().to_string(),
-        None => {
-            tracing::warn!("polymarket api secret missing; user ws disabled");
-            return Ok(false);
-        }
-    };
-    let api_passphrase = match secrets.polymarket_api_passphrase.as_ref() {
-        Some(value) => value.expose_secret().to_string(),
-        None => {
-            tracing::warn!("polymarket api passphrase missing; user ws disabled");
-            return Ok(false);
-        }
+    let Some(credentials) = clob.credentials() else {
+        tracing::warn!("polymarket api credentials missing; user ws disabled");
+        return Ok(false);
     };
 
     let chain_id = read_env_u64("POLYGON_CHAIN_ID").unwrap_or(137);
@@ -347,21 +463,25 @@ async fn spawn_polymarket_user_ws(
                 "polymarket user ws endpoint missing".to_string(),
             )
         })?;
-    let user_ws = PolymarketUserWs::new(
+    let mut user_ws = PolymarketUserWs::new(
         PolymarketUserWsConfig {
             ws_endpoint,
             ping_interval: Duration::from_secs(10),
             reconnect_delay: Duration::from_secs(3),
             auth: PolymarketUserAuth {
-                api_key,
-                api_secret,
-                api_passphrase,
+                api_key: credentials.api_key.clone(),
+                api_secret: credentials.api_secret.clone(),
+                api_passphrase: credentials.api_passphrase.clone(),
             },
             markets: Vec::new(),
         },
         redis,
         wallet_key,
-    );
+    )
+    .with_order_lifecycle(order_lifecycle);
+    if let Some(recorder) = recorder {
+        user_ws = user_ws.with_recorder(recorder);
+    }
     let _handle = user_ws.spawn();
     Ok(true)
 }
@@ -384,61 +504,72 @@ fn derive_chainlink_symbols(config: &Arc<Config>) -> Vec<String> {
     let mut symbols = Vec::new();
     let mut seen = HashSet::new();
 
-    if let Some(allora) = config.allora_consumer.as_ref() {
-        for topic in &allora.topics {
-            if let Some(symbol) = asset_to_chainlink_symbol(&topic.asset) {
-                if seen.insert(symbol.clone()) {
-                    symbols.push(symbol);
-                }
+    let topic_assets = config
+        .allora_consumer
+        .iter()
+        .flat_map(|allora| allora.topics.iter().map(|topic| topic.asset.clone()));
+    for asset in topic_assets.chain(config.polymarket.market_assets()) {
+        if let Some(symbol) = asset_to_chainlink_symbol(&asset) {
+            if seen.insert(symbol.clone()) {
+                symbols.push(symbol);
             }
         }
     }
 
-    if symbols.is_empty() {
-        let defaults = ["btc/usd", "eth/usd", "sol/usd"];
-        for symbol in defaults {
-            symbols.push(symbol.to_string());
-        }
-    }
-
     symbols
 }
 
+fn derive_binance_symbols(config: &Arc<Config>) -> Vec<String> {
+    derive_chainlink_symbols(config)
+        .iter()
+        .filter_map(|symbol| symbol.split_once('/'))
+        .map(|(base, _)| format!("{}USDT", base.to_ascii_uppercase()))
+        .collect()
+}
+
 fn asset_to_chainlink_symbol(asset: &str) -> Option<String> {
-    match asset.trim().to_ascii_uppercase().as_str() {
-        "BTC" => Some("btc/usd".to_string()),
-        "ETH" => Some("eth/usd".to_string()),
-        "SOL" => Some("sol/usd".to_string()),
-        _ => None,
+    let asset = asset.trim().to_ascii_lowercase();
+    if asset.is_empty() || !asset.chars().all(|ch| ch.is_ascii_alphanumeric()) {
+        return None;
     }
+    Some(format!("{asset}/usd"))
 }
 
 async fn spawn_execution_pipeline(
     config: &Arc<Config>,
     config_state: Arc<ArcSwap<Config>>,
     risk: Arc<RiskState>,
+    source_statuses: Arc<SourceStatusMap>,
     secrets: &security::Secrets,
     market_tx: broadcast::Sender<MarketUpdate>,
     user_ws_enabled: bool,
-) -> Result<()> {
+    order_lifecycle: (
+        OrderLifecycleHandle,
+        mpsc::UnboundedReceiver<OrderLifecycleEvent>,
+    ),
+    control: ControlPlane,
+    orderbook: Option<OrderBookStore>,
+    clob: &ClobClient,
+) -> Result<ControlPlane> {
     let redis_url = match std::env::var("REDIS_URL") {
         Ok(value) => value,
         Err(_) => {
             tracing::warn!("REDIS_URL not set; execution pipeline disabled");
-            return Ok(());
+            return Ok(control);
         }
     };
     let exchange_address = match std::env::var("POLYMARKET_EXCHANGE_ADDRESS") {
         Ok(value) => value,
         Err(_) => {
             tracing::warn!("POLYMARKET_EXCHANGE_ADDRESS missing; execution pipeline disabled");
-            return Ok(());
+            return Ok(control);
         }
     };
 
     let redis = RedisManager::new(&redis_url).await?;
-    let orderbook = OrderBookStore::new(redis.clone());
+    let orderbook = orderbook.unwrap_or_else(|| OrderBookStore::new(redis.clone()));
     let (intent_tx, intent_rx) = mpsc::channel(256);
+    let mut control = control.with_activity_log(redis.clone());
 
     let chain_id = read_env_u64("POLYGON_CHAIN_ID").unwrap_or(137);
     let wallet_key = match Eip712Signer::from_secrets(secrets, chain_id) {
@@ -452,26 +583,14 @@ async fn spawn_execution_pipeline(
         }
     };
 
-    let trading_engine = TradingEngine::new(
-        config_state.clone(),
-        risk,
-        redis.clone(),
-        orderbook.clone(),
-        intent_tx,
-        wallet_key.clone(),
-    );
-    let _trading_handle = trading_engine.spawn(market_tx.subscribe());
-
-    let exchange_address = parse_address(&exchange_address)?;
-    let builder = PolymarketPayloadBuilder::new(
-        config_state,
-        redis.clone(),
-        orderbook,
-        secrets,
-        exchange_address,
-        chain_id,
-    )?;
-    let relayer = RelayerClient::new(RelayerConfig::new(config.endpoints.relayer_http.clone()))?;
+    if let Some(wallet_key) = wallet_key.clone() {
+        control = control.with_flatten(
+            redis.clone(),
+            orderbook.clone(),
+            intent_tx.clone(),
+            wallet_key,
+        );
+    }
 
     let database = match resolve_timescale_url() {
         Some(url) => {
@@ -489,19 +608,26 @@ async fn spawn_execution_pipeline(
         None => None,
     };
 
-    let cancel_client = wallet_key.as_ref().and_then(|address| {
-        let config = bankai_terminal::execution::cancel::CancelClientConfig::from_env(
-            config.endpoints.relayer_http.clone(),
-        );
-        match bankai_terminal::execution::cancel::CancelClient::from_env(config, secrets, address) {
-            Ok(value) => value,
-            Err(error) => {
-                tracing::warn!(?error, "cancel client disabled");
-                None
-            }
-        }
-    });
+    let exchange_address_parsed = parse_address(&exchange_address)?;
+    if !clob.has_credentials() {
+        return Err(bankai_terminal::error::BankaiError::InvalidArgument(
+            "polymarket api credentials missing; relayer orders need L2 auth".to_string(),
+        ));
+    }
+    let builder = PolymarketPayloadBuilder::new(
+        config_state.clone(),
+        redis.clone(),
+        orderbook.clone(),
+        secrets,
+        clob.clone(),
+        exchange_address_parsed,
+        chain_id,
+    )?;
+    control = control.with_cancel_client(clob.clone());
 
+    let (direct, nonce_manager) =
+        spawn_direct_rail(config, secrets, redis.clone(), &exchange_address, chain_id).await;
+    let (lifecycle_handle, lifecycle_rx) = order_lifecycle;
     let orchestrator = ExecutionOrchestrator::new(
         ExecutionOrchestratorConfig {
             prefer_ws_reconcile: config.execution.prefer_ws_reconcile && user_ws_enabled,
@@ -511,21 +637,117 @@ async fn spawn_execution_pipeline(
             idempotency_ttl_secs: config.execution.idempotency_ttl_secs,
             cancel_before_replace: config.execution.cancel_before_replace,
             no_money_mode: config.execution.no_money_mode,
+            ladder_max_age_ms: config.execution.ladder_max_age_secs.saturating_mul(1000),
             ..Default::default()
         },
-        relayer,
-        None,
-        cancel_client,
-        database,
-        None,
-        Some(redis),
-        wallet_key,
+        clob.clone(),
+        direct,
+        Some(clob.clone()),
+        database.clone(),
+        nonce_manager,
+        Some(redis.clone()),
+        wallet_key.clone(),
         Arc::new(builder),
-        Some(PaperSimConfig::from_config(&config)),
-    )?;
+        Some(PaperSimConfig::from_config(config)),
+    )?
+    .with_order_lifecycle(lifecycle_handle)
+    .with_live_config(config_state.clone());
+
+    // Every fallible step is done; start the long-running tasks.
+    let mut trading_engine = TradingEngine::new(
+        config_state.clone(),
+        risk.clone(),
+        redis.clone(),
+        orderbook,
+        intent_tx,
+        wallet_key,
+    )
+    .with_source_statuses(source_statuses);
+    if config.python_strategy.enabled {
+        match PythonStrategyBackend::spawn(&config.python_strategy).await {
+            Ok(python) => {
+                tracing::info!(
+                    module = %config.python_strategy.module,
+                    timeout_ms = config.python_strategy.timeout_ms,
+                    "python strategy backend enabled"
+                );
+                trading_engine = trading_engine.with_python_backend(python);
+            }
+            Err(error) => {
+                tracing::warn!(
+                    ?error,
+                    "python strategy backend unavailable; using rust model"
+                );
+            }
+        }
+    }
+    if let Some(database) = database {
+        trading_engine = trading_engine.with_regret_tracking();
+        let _regret_handle = RegretTracker::new(redis.clone(), database).spawn();
+    }
+    let _trading_handle = trading_engine.spawn(market_tx.subscribe());
+
+    let _sweeper_handle = OrderSweeper::new(
+        OrderSweeperConfig::from_config(config),
+        lifecycle_rx,
+        clob.clone(),
+        redis,
+        risk,
+    )
+    .with_live_config(config_state)
+    .spawn();
     let _exec_handle = orchestrator.spawn(intent_rx);
 
-    Ok(())
+    Ok(control)
+}
+
+/// Builds the Rail B client and nonce manager, seeds the nonce from the chain, and starts
+/// the receipt/RBF monitor. Any failure leaves the relayer as the only rail.
+async fn spawn_direct_rail(
+    config: &Arc<Config>,
+    secrets: &security::Secrets,
+    redis: RedisManager,
+    exchange_address: &str,
+    chain_id: u64,
+) -> (Option<DirectExecutionClient>, Option<NonceManager>) {
+    if !config.direct.enabled || config.execution.no_money_mode {
+        return (None, None);
+    }
+    let direct_config =
+        DirectExecutionConfig::from_config(config, exchange_address.to_string(), chain_id);
+    let direct = match DirectExecutionClient::new(direct_config, secrets) {
+        Ok(client) => client,
+        Err(error) => {
+            tracing::warn!(?error, "direct execution client disabled");
+            return (None, None);
+        }
+    };
+    let address = format!("{}", direct.wallet_address()).to_ascii_lowercase();
+    let nonce_manager =
+        match NonceManager::new(redis, address, tx_monitor::nonce_manager_config(config)) {
+            Ok(manager) => manager,
+            Err(error) => {
+                tracing::warn!(?error, "nonce manager disabled; direct rail disabled");
+                return (None, None);
+            }
+        };
+    match tx_monitor::initialize_nonce(&direct, &nonce_manager).await {
+        Ok(next_nonce) => tracing::info!(next_nonce, "direct rail nonce initialized"),
+        Err(error) => {
+            tracing::warn!(
+                ?error,
+                "direct rail nonce init failed; direct rail disabled"
+            );
+            return (None, None);
+        }
+    }
+    let monitor = DirectTxMonitor::new(
+        TxMonitorConfig::from_config(config),
+        direct.clone(),
+        nonce_manager.clone(),
+    );
+    let _monitor_handle = monitor.spawn();
+    (Some(direct), Some(nonce_manager))
 }
 
 async fn spawn_allowance_manager(config: &Arc<Config>, secrets: &security::Secrets) -> Result<()> {
@@ -562,6 +784,37 @@ async fn spawn_no_money(config: &Arc<Config>, config_state: Arc<ArcSwap<Config>>
     Ok(())
 }
 
+async fn spawn_meta_brain(config: &Arc<Config>, config_manager: ConfigManager) -> Result<()> {
+    if !config.meta_brain.enabled {
+        return Ok(());
+    }
+    let redis_url = match std::env::var("REDIS_URL") {
+        Ok(value) => value,
+        Err(_) => {
+            tracing::warn!("REDIS_URL not set; meta-brain disabled");
+            return Ok(());
+        }
+    };
+    let redis = RedisManager::new(&redis_url).await?;
+    let database = match resolve_timescale_url() {
+        Some(url) => {
+            match bankai_terminal::storage::database::DatabaseManager::new(&url, 2).await {
+                Ok(db) => Some(db),
+                Err(error) => {
+                    tracing::warn!(
+                        ?error,
+                        "meta-brain database unavailable; using paper results only"
+                    );
+                    None
+                }
+            }
+        }
+        None => None,
+    };
+    let _handle = MetaBrain::new(config_manager, redis, database).spawn();
+    Ok(())
+}
+
 async fn spawn_bankroll_refresher(config: &Arc<Config>, secrets: &security::Secrets) -> Result<()> {
     let redis_url = match std::env::var("REDIS_URL") {
         Ok(value) => value,
@@ -582,6 +835,7 @@ async fn spawn_trade_reconciler(
     config: &Arc<Config>,
     secrets: &security::Secrets,
     user_ws_enabled: bool,
+    clob: &ClobClient,
 ) -> Result<()> {
     if config.execution.prefer_ws_reconcile && user_ws_enabled {
         tracing::info!("user ws enabled; trade reconciler disabled");
@@ -595,7 +849,7 @@ async fn spawn_trade_reconciler(
         }
     };
     let redis = RedisManager::new(&redis_url).await?;
-    let Some(reconciler) = TradeReconciler::from_env(config, secrets, redis)? else {
+    let Some(reconciler) = TradeReconciler::from_env(config, secrets, redis, clob)? else {
         return Ok(());
     };
     let _handle = reconciler.spawn();
@@ -605,6 +859,7 @@ async fn spawn_trade_reconciler(
 async fn spawn_open_orders_refresher(
     config: &Arc<Config>,
     secrets: &security::Secrets,
+    clob: &ClobClient,
 ) -> Result<()> {
     let redis_url = match std::env::var("REDIS_URL") {
         Ok(value) => value,
@@ -614,14 +869,18 @@ async fn spawn_open_orders_refresher(
         }
     };
     let redis = RedisManager::new(&redis_url).await?;
-    let Some(refresher) = OpenOrdersRefresher::from_env(config, secrets, redis)? else {
+    let Some(refresher) = OpenOrdersRefresher::from_env(config, secrets, redis, clob)? else {
         return Ok(());
     };
     let _handle = refresher.spawn();
     Ok(())
 }
 
-async fn spawn_pnl_monitor(config: &Arc<Config>, secrets: &security::Secrets) -> Result<()> {
+async fn spawn_pnl_monitor(
+    config: &Arc<Config>,
+    secrets: &security::Secrets,
+    orderbook: Option<OrderBookStore>,
+) -> Result<()> {
     let redis_url = match std::env::var("REDIS_URL") {
         Ok(value) => value,
         Err(_) => {
@@ -644,7 +903,7 @@ async fn spawn_pnl_monitor(config: &Arc<Config>, secrets: &security::Secrets) ->
         return Ok(());
     };
     let redis = RedisManager::new(&redis_url).await?;
-    let orderbook = OrderBookStore::new(redis.clone());
+    let orderbook = orderbook.unwrap_or_else(|| OrderBookStore::new(redis.clone()));
     let interval = Duration::from_secs(config.execution.trade_reconcile_interval_secs.max(3));
     let monitor = PnlMonitor::new(redis, orderbook, wallet_key, interval);
     let _handle = monitor.spawn();
@@ -704,6 +963,56 @@ async fn spawn_redemption_listener(
     Ok(())
 }
 
+/// Builds the one CLOB client shared by every caller so they draw from the same rate limits.
+/// With `clob.auto_derive_credentials`, missing API credentials are derived from the wallet
+/// and saved to the secrets file (when a file provider is configured), and a 401 re-derives
+/// them at runtime.
+async fn build_clob_client(
+    config: &Config,
+    secrets: &security::Secrets,
+    secrets_file: Option<&SecretsFile>,
+) -> Result<ClobClient> {
+    let chain_id = read_env_u64("POLYGON_CHAIN_ID").unwrap_or(137);
+    let signer = Eip712Signer::from_secrets(secrets, chain_id).ok();
+    let credentials = signer
+        .as_ref()
+        .and_then(|signer| ClobCredentials::from_secrets(secrets, &signer.checksum_address()));
+    let clob = ClobClient::new(ClobClientConfig::from_config(config), credentials)?;
+
+    let Some(signer) = signer.filter(|_| config.clob.auto_derive_credentials) else {
+        if !clob.has_credentials() {
+            tracing::warn!("polymarket api credentials missing; authenticated clob calls disabled");
+        }
+        return Ok(clob);
+    };
+    let nonce = config.clob.api_key_nonce;
+    if !clob.has_credentials() {
+        match clob.derive_or_create_api_key(&signer, nonce).await {
+            Ok(credentials) => {
+                match secrets_file.map(|file| file.update(|payload| credentials.write_to(payload)))
+                {
+                    Some(Ok(())) => tracing::info!("polymarket api credentials derived and saved"),
+                    Some(Err(error)) => tracing::warn!(
+                        ?error,
+                        "polymarket api credentials derived but not saved to secrets file"
+                    ),
+                    None => tracing::info!(
+                        "polymarket api credentials derived; no secrets file provider to save them"
+                    ),
+                }
+                clob.set_credentials(credentials);
+            }
+            Err(error) => {
+                tracing::warn!(
+                    ?error,
+                    "polymarket api credential derivation failed; authenticated clob calls disabled"
+                );
+            }
+        }
+    }
+    Ok(clob.with_credential_refresh(signer, nonce, secrets_file.cloned()))
+}
+
 fn parse_address(value: &str) -> Result<ethers_core::types::Address> {
     use std::str::FromStr;
     ethers_core::types::Address::from_str(value.trim()).map_err(|_| {
@@ -737,32 +1046,47 @@ fn resolve_timescale_url() -> Option<String> {
         })
 }
 
-async fn spawn_tui_if_enabled(
-    config: Arc<arc_swap::ArcSwap<Config>>,
-    risk: Arc<RiskState>,
-    sender: broadcast::Sender<MarketUpdate>,
-    wallet_key: Option<String>,
-) -> Result<Option<ui::TuiHandle>> {
-    if !ui::is_tui_enabled() {
-        return Ok(None);
+async fn connect_ui_redis() -> Option<RedisManager> {
+    let url = std::env::var("REDIS_URL").ok()?;
+    match RedisManager::new(&url).await {
+        Ok(manager) => Some(manager),
+        Err(error) => {
+            tracing::warn!(?error, "redis unavailable; ui bankroll disabled");
+            None
+        }
     }
+}
 
-    let redis = match std::env::var("REDIS_URL") {
-        Ok(url) => match RedisManager::new(&url).await {
-            Ok(manager) => Some(manager),
-            Err(error) => {
-                tracing::warn!(?error, "redis unavailable; ui bankroll disabled");
-                None
-            }
-        },
-        Err(_) => None,
-    };
-
-    match ui::spawn_tui(config, risk, sender.subscribe(), redis, wallet_key) {
-        Ok(handle) => Ok(Some(handle)),
+fn spawn_tui(
+    feed: ui::SnapshotFeed,
+    control: mpsc::UnboundedSender<ControlCommand>,
+) -> Option<ui::TuiHandle> {
+    match ui::spawn_tui(feed, Some(control)) {
+        Ok(handle) => Some(handle),
         Err(error) => {
             tracing::warn!(?error, "failed to start tui");
-            Ok(None)
+            None
         }
     }
 }
+
+fn spawn_admin_server(
+    config: &Arc<Config>,
+    feed: ui::SnapshotFeed,
+    redis: Option<RedisManager>,
+    wallet_key: Option<String>,
+    control: mpsc::UnboundedSender<ControlCommand>,
+    config_manager: ConfigManager,
+) -> Result<()> {
+    if !config.admin.enabled {
+        return Ok(());
+    }
+    let mut server = AdminServer::new(&config.admin, feed)?
+        .with_control(control)
+        .with_config_manager(config_manager);
+    if let Some(redis) = redis {
+        server = server.with_redis(redis, wallet_key);
+    }
+    let _handle = server.spawn();
+    Ok(())
+}
@@ -5,18 +5,35 @@
  * @dependencies
  * - reqwest: HTTP client for REST calls
  * - serde_json: JSON parsing
+ * - ethers-core: signer recovery for inference signatures
  *
  * @notes
  * - Implements the consumer API flow described in docs/allora_documentation.md.
  * - Emits MarketUpdate::Allora for each topic.
+ * - Confidence intervals prefer the `_normalized` arrays; raw percentiles are 1e18-scaled
+ *   and raw values use `token_decimals`. Malformed or mismatched arrays are dropped.
+ * - Signature verification mirrors the consumer contract's `getMessage`: keccak256 of
+ *   abi.encodePacked(chainId, consumer, topicId, timestamp, extraData, networkInference,
+ *   percentiles[], values[]) with every integer as uint256, signed as an EIP-191 message.
+ *   The signer must be one of the configured data providers.
+ * - In `tag` mode every update is broadcast with `signature_verified` set; in `reject`
+ *   mode unsigned/invalid updates are dropped. Counters feed the TUI health panel.
  */
+use ethers_core::types::{Address, Signature, U256};
+use ethers_core::utils::{hex, keccak256};
 use reqwest::{header, Client};
+use serde::Serialize;
 use serde_json::Value;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tokio::sync::broadcast;
 
+use crate::config::{AlloraSignatureConfig, AlloraSignatureMode};
 use crate::engine::types::{AlloraMarketUpdate, MarketUpdate};
 use crate::error::{BankaiError, Result};
+use crate::telemetry::metrics;
 
 #[derive(Debug, Clone)]
 pub struct AlloraOracleConfig {
@@ -35,9 +52,174 @@ pub struct AlloraConsumerTopic {
     pub topic_id: Option<u64>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureCheck {
+    Verified,
+    Unsigned,
+    Invalid,
+}
+
+#[derive(Debug, Clone)]
+pub struct AlloraSignatureVerifier {
+    mode: AlloraSignatureMode,
+    signers: Vec<Address>,
+    consumer_contract: Address,
+    chain_id: u64,
+}
+
+impl AlloraSignatureVerifier {
+    /// Builds the verifier from config; `None` when verification is off.
+    pub fn from_config(config: &AlloraSignatureConfig, chain: &str) -> Result<Option<Self>> {
+        if config.mode == AlloraSignatureMode::Off {
+            return Ok(None);
+        }
+        let signers = config
+            .signers
+            .iter()
+            .map(|signer| signer.trim())
+            .filter(|signer| !signer.is_empty())
+            .map(|signer| {
+                Address::from_str(signer).map_err(|_| {
+                    BankaiError::InvalidArgument(format!("invalid allora signer {signer}"))
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        if signers.is_empty() {
+            return Err(BankaiError::InvalidArgument(
+                "allora signature verification requires signers".to_string(),
+            ));
+        }
+        let consumer_contract = config
+            .consumer_contract
+            .as_deref()
+            .map(str::trim)
+            .ok_or_else(|| {
+                BankaiError::InvalidArgument("allora consumer_contract missing".to_string())
+            })
+            .and_then(|contract| {
+                Address::from_str(contract).map_err(|_| {
+                    BankaiError::InvalidArgument(format!(
+                        "invalid allora consumer_contract {contract}"
+                    ))
+                })
+            })?;
+        let chain_id = config
+            .chain_id
+            .or_else(|| chain_id_from_chain(chain))
+            .ok_or_else(|| {
+                BankaiError::InvalidArgument(format!(
+                    "allora chain id unknown for chain {chain}; set signature.chain_id"
+                ))
+            })?;
+        Ok(Some(Self {
+            mode: config.mode,
+            signers,
+            consumer_contract,
+            chain_id,
+        }))
+    }
+
+    pub fn mode(&self) -> AlloraSignatureMode {
+        self.mode
+    }
+
+    /// Checks `data.signature` against the raw `data.inference_data` payload.
+    pub fn verify(&self, data: &Value) -> SignatureCheck {
+        let Some(signature) = data
+            .get("signature")
+            .and_then(|value| value.as_str())
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+        else {
+            return SignatureCheck::Unsigned;
+        };
+        let Ok(signature) = Signature::from_str(signature) else {
+            return SignatureCheck::Invalid;
+        };
+        let Some(message) = data
+            .get("inference_data")
+            .and_then(|inference| self.message_hash(inference))
+        else {
+            return SignatureCheck::Invalid;
+        };
+        match signature.recover(&message[..]) {
+            Ok(signer) if self.signers.contains(&signer) => SignatureCheck::Verified,
+            _ => SignatureCheck::Invalid,
+        }
+    }
+
+    fn message_hash(&self, inference: &Value) -> Option<[u8; 32]> {
+        let topic_id = parse_u256(inference.get("topic_id")?)?;
+        let timestamp = parse_u256(inference.get("timestamp")?)?;
+        let network_inference = parse_u256(inference.get("network_inference")?)?;
+        let extra_data = parse_extra_data(inference.get("extra_data"))?;
+        let percentiles = parse_u256_array(inference.get("confidence_interval_percentiles"))?;
+        let values = parse_u256_array(inference.get("confidence_interval_values"))?;
+
+        let mut packed = Vec::with_capacity(32 * (5 + percentiles.len() + values.len()));
+        push_u256(&mut packed, U256::from(self.chain_id));
+        packed.extend_from_slice(self.consumer_contract.as_bytes());
+        push_u256(&mut packed, topic_id);
+        push_u256(&mut packed, timestamp);
+        packed.extend_from_slice(&extra_data);
+        push_u256(&mut packed, network_inference);
+        for value in percentiles.into_iter().chain(values) {
+            push_u256(&mut packed, value);
+        }
+        Some(keccak256(packed))
+    }
+}
+
+/// Verification outcome counters shared with the TUI.
+#[derive(Debug, Default)]
+pub struct AlloraSignatureStats {
+    verified: AtomicU64,
+    unsigned: AtomicU64,
+    invalid: AtomicU64,
+    rejected: AtomicU64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct AlloraSignatureCounts {
+    pub verified: u64,
+    pub unsigned: u64,
+    pub invalid: u64,
+    pub rejected: u64,
+}
+
+impl AlloraSignatureStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn snapshot(&self) -> AlloraSignatureCounts {
+        AlloraSignatureCounts {
+            verified: self.verified.load(Ordering::Relaxed),
+            unsigned: self.unsigned.load(Ordering::Relaxed),
+            invalid: self.invalid.load(Ordering::Relaxed),
+            rejected: self.rejected.load(Ordering::Relaxed),
+        }
+    }
+
+    fn record(&self, check: SignatureCheck, rejected: bool) {
+        let (counter, outcome) = match check {
+            SignatureCheck::Verified => (&self.verified, "verified"),
+            SignatureCheck::Unsigned => (&self.unsigned, "unsigned"),
+            SignatureCheck::Invalid => (&self.invalid, "invalid"),
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        metrics::increment_allora_signature(outcome);
+        if rejected {
+            self.rejected.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
 pub struct AlloraOracle {
     config: AlloraOracleConfig,
     client: Client,
+    verifier: Option<AlloraSignatureVerifier>,
+    signature_stats: Arc<AlloraSignatureStats>,
 }
 
 impl AlloraOracle {
@@ -58,7 +240,22 @@ impl AlloraOracle {
             client_builder = client_builder.default_headers(headers);
         }
         let client = client_builder.build()?;
-        Ok(Self { config, client })
+        Ok(Self {
+            config,
+            client,
+            verifier: None,
+            signature_stats: Arc::new(AlloraSignatureStats::new()),
+        })
+    }
+
+    pub fn with_signature_verifier(
+        mut self,
+        verifier: Option<AlloraSignatureVerifier>,
+        stats: Arc<AlloraSignatureStats>,
+    ) -> Self {
+        self.verifier = verifier;
+        self.signature_stats = stats;
+        self
     }
 
     pub fn spawn(self, sender: broadcast::Sender<MarketUpdate>) -> tokio::task::JoinHandle<()> {
@@ -79,9 +276,11 @@ impl AlloraOracle {
         loop {
             for topic in &self.config.topics {
                 match self.fetch_topic(topic).await {
-                    Ok(update) => {
+                    Ok(Some(update)) => {
+                        metrics::increment_oracle_message("allora");
                         let _ = sender.send(MarketUpdate::Allora(update));
                     }
+                    Ok(None) => {}
                     Err(error) => {
                         tracing::warn!(
                             ?error,
@@ -96,7 +295,8 @@ impl AlloraOracle {
         }
     }
 
-    async fn fetch_topic(&self, topic: &AlloraConsumerTopic) -> Result<AlloraMarketUpdate> {
+    /// Fetches one topic; `None` when the update was rejected by signature verification.
+    async fn fetch_topic(&self, topic: &AlloraConsumerTopic) -> Result<Option<AlloraMarketUpdate>> {
         let url = build_consumer_url(&self.config.base_url, &self.config.chain, topic)?;
         let response = self.client.get(url).send().await?.error_for_status()?;
         let parsed: Value = response.json().await?;
@@ -117,7 +317,32 @@ impl AlloraOracle {
             BankaiError::InvalidArgument("consumer inference_data missing".to_string())
         })?;
 
+        let signature_verified = match self.verifier.as_ref() {
+            Some(verifier) => {
+                let check = verifier.verify(data);
+                let reject = check != SignatureCheck::Verified
+                    && verifier.mode() == AlloraSignatureMode::Reject;
+                self.signature_stats.record(check, reject);
+                if check != SignatureCheck::Verified {
+                    tracing::warn!(
+                        ?check,
+                        asset = %topic.asset,
+                        timeframe = %topic.timeframe,
+                        rejected = reject,
+                        "allora inference signature not verified"
+                    );
+                }
+                if reject {
+                    return Ok(None);
+                }
+                Some(check == SignatureCheck::Verified)
+            }
+            None => None,
+        };
+
         let parsed_inference = parse_inference_value(inference, data)?;
+        let (confidence_percentiles, confidence_intervals) =
+            parse_confidence_intervals(inference, parsed_inference.token_decimals);
         let topic_id = parse_topic_id(inference)?;
         let signal_timestamp_ms = parse_timestamp_ms(inference.get("timestamp"))?;
         let received_at_ms = now_ms()?;
@@ -130,19 +355,21 @@ impl AlloraOracle {
             .and_then(|value| value.as_str())
             .map(|value| value.to_string());
 
-        Ok(AlloraMarketUpdate {
+        Ok(Some(AlloraMarketUpdate {
             topic_id,
             inference_value: parsed_inference.normalized,
             inference_raw: parsed_inference.raw_value,
             token_decimals: parsed_inference.token_decimals,
             signature,
+            signature_verified,
             request_id,
-            confidence_intervals: Vec::new(),
+            confidence_intervals,
+            confidence_percentiles,
             signal_timestamp_ms,
             received_at_ms,
             asset: topic.asset.clone(),
             timeframe: topic.timeframe.clone(),
-        })
+        }))
     }
 }
 
@@ -225,6 +452,113 @@ fn parse_inference_value(inference: &Value, data: &Value) -> Result<ParsedInfere
     })
 }
 
+const RAW_PERCENTILE_SCALE: f64 = 1e18;
+
+/// Returns `(percentiles, values)`, or two empty vectors when the intervals are unusable.
+fn parse_confidence_intervals(
+    inference: &Value,
+    token_decimals: Option<u32>,
+) -> (Vec<f64>, Vec<f64>) {
+    let normalized = (
+        parse_number_array(inference.get("confidence_interval_percentiles_normalized")),
+        parse_number_array(inference.get("confidence_interval_values_normalized")),
+    );
+    let (percentiles, values) = match normalized {
+        (Some(percentiles), Some(values)) => (percentiles, values),
+        _ => {
+            let raw_percentiles =
+                parse_number_array(inference.get("confidence_interval_percentiles"));
+            let raw_values = parse_number_array(inference.get("confidence_interval_values"));
+            let (Some(raw_percentiles), Some(raw_values), Some(decimals)) =
+                (raw_percentiles, raw_values, token_decimals)
+            else {
+                return (Vec::new(), Vec::new());
+            };
+            let divisor = 10_f64.powi(decimals as i32);
+            (
+                raw_percentiles
+                    .into_iter()
+                    .map(|value| value / RAW_PERCENTILE_SCALE)
+                    .collect(),
+                raw_values
+                    .into_iter()
+                    .map(|value| value / divisor)
+                    .collect(),
+            )
+        }
+    };
+    let valid = !percentiles.is_empty()
+        && percentiles.len() == values.len()
+        && percentiles
+            .iter()
+            .all(|value| *value > 0.0 && *value < 100.0)
+        && percentiles.windows(2).all(|pair| pair[0] < pair[1])
+        && values.windows(2).all(|pair| pair[0] <= pair[1]);
+    if !valid {
+        return (Vec::new(), Vec::new());
+    }
+    (percentiles, values)
+}
+
+fn parse_number_array(value: Option<&Value>) -> Option<Vec<f64>> {
+    value?
+        .as_array()?
+        .iter()
+        .map(|item| {
+            let parsed = match item {
+                Value::String(text) => text.trim().parse::<f64>().ok(),
+                Value::Number(number) => number.as_f64(),
+                _ => None,
+            };
+            parsed.filter(|value| value.is_finite())
+        })
+        .collect()
+}
+
+/// Numeric suffix of chains like "ethereum-11155111".
+fn chain_id_from_chain(chain: &str) -> Option<u64> {
+    chain
+        .trim_matches('/')
+        .rsplit(['-', '/'])
+        .next()?
+        .parse()
+        .ok()
+}
+
+fn parse_u256(value: &Value) -> Option<U256> {
+    match value {
+        Value::String(text) => U256::from_dec_str(text.trim()).ok(),
+        Value::Number(number) => number.as_u64().map(U256::from),
+        _ => None,
+    }
+}
+
+/// Missing arrays encode as empty, matching an inference without confidence intervals.
+fn parse_u256_array(value: Option<&Value>) -> Option<Vec<U256>> {
+    match value {
+        None | Some(Value::Null) => Some(Vec::new()),
+        Some(value) => value.as_array()?.iter().map(parse_u256).collect(),
+    }
+}
+
+/// `extra_data` is hex (`0x..`) when it carries bytes and plain text otherwise.
+fn parse_extra_data(value: Option<&Value>) -> Option<Vec<u8>> {
+    let text = match value {
+        None | Some(Value::Null) => return Some(Vec::new()),
+        Some(value) => value.as_str()?,
+    };
+    match text.strip_prefix("0x") {
+        Some(encoded) => hex::decode(encoded).ok(),
+        None => Some(text.as_bytes().to_vec()),
+    }
+}
+
+fn push_u256(out: &mut Vec<u8>, value: U256) {
+    let mut buf = [0u8; 32];
+    value.to_big_endian(&mut buf);
+    out.extend_from_slice(&buf);
+}
+
 fn parse_topic_id(inference: &Value) -> Result<u64> {
     let raw = inference
         .get("topic_id")
@@ -265,3 +599,76 @@ fn now_ms() -> Result<u64> {
     let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
     Ok(now.as_millis() as u64)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn parses_raw_and_normalized_confidence_intervals() {
+        let raw = json!({
+            "confidence_interval_percentiles": ["2280000000000000000", "50000000000000000000", "97720000000000000000"],
+            "confidence_interval_values": ["2492000", "2611000", "2682000"],
+        });
+        let (percentiles, values) = parse_confidence_intervals(&raw, Some(3));
+        assert_eq!(percentiles, vec![2.28, 50.0, 97.72]);
+        assert_eq!(values, vec![2492.0, 2611.0, 2682.0]);
+
+        let normalized = json!({
+            "confidence_interval_percentiles_normalized": ["15.87", "50", "84.13"],
+            "confidence_interval_values_normalized": ["99", "100", "101"],
+            "confidence_interval_percentiles": ["1"],
+        });
+        let (percentiles, values) = parse_confidence_intervals(&normalized, None);
+        assert_eq!(percentiles, vec![15.87, 50.0, 84.13]);
+        assert_eq!(values, vec![99.0, 100.0, 101.0]);
+
+        let unsorted = json!({
+            "confidence_interval_percentiles_normalized": ["50", "15.87"],
+            "confidence_interval_values_normalized": ["100", "99"],
+        });
+        assert!(parse_confidence_intervals(&unsorted, None).0.is_empty());
+    }
+
+    #[test]
+    fn verifies_inference_signature_against_signers() {
+        use ethers_core::utils::hash_message;
+        use ethers_signers::{LocalWallet, Signer};
+
+        let wallet = LocalWallet::from_str(
+            "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318",
+        )
+        .expect("wallet");
+        let config = AlloraSignatureConfig {
+            mode: AlloraSignatureMode::Reject,
+            signers: vec![format!("{:?}", wallet.address())],
+            consumer_contract: Some("0x4341a3F0a350C2428184a727BAb86e16D4ba7018".to_string()),
+            chain_id: None,
+        };
+        let verifier = AlloraSignatureVerifier::from_config(&config, "ethereum-11155111")
+            .expect("config")
+            .expect("enabled");
+        assert_eq!(verifier.chain_id, 11_155_111);
+
+        let inference = json!({
+            "topic_id": "14",
+            "timestamp": 1719866777,
+            "extra_data": "",
+            "network_inference": "3365485208027959000000",
+            "confidence_interval_percentiles": ["2280000000000000000", "97720000000000000000"],
+            "confidence_interval_values": ["3016256807053656000000", "3278333171848616500000"],
+        });
+        let message = verifier.message_hash(&inference).expect("message");
+        let signature = wallet.sign_hash(hash_message(message)).expect("sign");
+
+        let mut data = json!({ "signature": signature.to_string(), "inference_data": inference });
+        assert_eq!(verifier.verify(&data), SignatureCheck::Verified);
+
+        data["inference_data"]["network_inference"] = json!("3365485208027959000001");
+        assert_eq!(verifier.verify(&data), SignatureCheck::Invalid);
+
+        data.as_object_mut().expect("object").remove("signature");
+        assert_eq!(verifier.verify(&data), SignatureCheck::Unsigned);
+    }
+}
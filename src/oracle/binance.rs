@@ -10,6 +10,8 @@
  * @notes
  * - Volatility uses 1-minute rolling stddev of returns.
  * - DFO aligns candle start to epoch-based interval boundaries.
+ * - Emits MarketUpdate::Binance keyed by canonical asset (BTC, ETH, ...) for cross-checks
+ *   against Chainlink; start prices stay Chainlink-owned, so nothing is written to Redis.
  */
 use futures_util::{SinkExt, StreamExt};
 use serde_json::Value;
@@ -21,8 +23,10 @@ use tokio_tungstenite::tungstenite::Message;
 use crate::engine::types::{ChainlinkMarketUpdate, MarketUpdate};
 use crate::error::{BankaiError, Result};
 use crate::storage::redis::{AssetWindow, RedisManager};
+use crate::telemetry::metrics;
 
 const STREAM_ID: u64 = 1;
+const MIN_EMIT_INTERVAL_MS: u64 = 250;
 
 fn compute_jitter(max_ms: u64) -> Result<Duration> {
     if max_ms == 0 {
@@ -33,11 +37,6 @@ fn compute_jitter(max_ms: u64) -> Result<Duration> {
     Ok(Duration::from_millis(jitter))
 }
 
-fn now_ms() -> Result<u64> {
-    let duration = SystemTime::now().duration_since(UNIX_EPOCH)?;
-    Ok(duration.as_millis() as u64)
-}
-
 #[derive(Debug, Clone)]
 pub struct BinanceOracleConfig {
     pub endpoint: String,
@@ -58,8 +57,17 @@ impl BinanceOracle {
 
     pub fn spawn(self, sender: broadcast::Sender<MarketUpdate>) -> tokio::task::JoinHandle<()> {
         tokio::spawn(async move {
-            if let Err(error) = self.run(sender).await {
-                tracing::error!(?error, "binance oracle stopped");
+            let config = self.config;
+            loop {
+                let oracle = BinanceOracle {
+                    config: config.clone(),
+                };
+                if let Err(error) = oracle.run(sender.clone()).await {
+                    tracing::error!(?error, "binance oracle stopped; restarting");
+                } else {
+                    tracing::warn!("binance oracle exited; restarting");
+                }
+                tokio::time::sleep(Duration::from_secs(1)).await;
             }
         })
     }
@@ -74,7 +82,13 @@ impl BinanceOracle {
         let mut endpoint_index = 0usize;
         let mut primary_failures = 0u32;
 
+        let mut first_connect = true;
         loop {
+            if first_connect {
+                first_connect = false;
+            } else {
+                metrics::increment_oracle_reconnect("binance");
+            }
             let endpoint = endpoints
                 .get(endpoint_index)
                 .cloned()
@@ -131,26 +145,24 @@ impl BinanceOracle {
                     message = reader.next() => {
                         match message {
                             Some(Ok(Message::Text(text))) => {
-                                if let Some(event) = parse_event(&text)? {
-                                    let asset = event.symbol().to_string();
-                                    let asset_key = canonical_asset(&asset);
-                                    let state = states
-                                        .entry(asset.clone())
-                                        .or_insert_with(|| AssetState::new(self.config.candle_interval));
-                                    if let Some(window) = asset_windows.get(&asset_key) {
-                                        state.set_window(window);
-                                        if let (Some(redis), Some((start_ms, price))) =
-                                            (self.config.redis.as_ref(), state.take_start_price_if_ready(window))
-                                        {
-                                            let now = now_ms().unwrap_or(0);
-                                            let _ = redis
-                                                .set_asset_start_price(&asset_key, start_ms, price, now)
-                                                .await;
-                                        }
-                                    }
-                                    if let Some(update) = state.apply_event(event)? {
-                                        let _ = sender.send(MarketUpdate::Chainlink(update));
+                                metrics::increment_oracle_message("binance");
+                                let event = match parse_event(&text) {
+                                    Ok(Some(event)) => event,
+                                    Ok(None) => continue,
+                                    Err(error) => {
+                                        tracing::warn!(?error, "binance ws message parse failed");
+                                        continue;
                                     }
+                                };
+                                let asset_key = canonical_asset(event.symbol());
+                                let state = states
+                                    .entry(asset_key.clone())
+                                    .or_insert_with(|| AssetState::new(self.config.candle_interval));
+                                if let Some(window) = asset_windows.get(&asset_key) {
+                                    state.set_window(window);
+                                }
+                                if let Some(update) = state.apply_event(&asset_key, event) {
+                                    let _ = sender.send(MarketUpdate::Binance(update));
                                 }
                             }
                             Some(Ok(Message::Ping(payload))) => {
@@ -267,7 +279,7 @@ struct AssetState {
     candle_open_price: Option<f64>,
     candle_interval_ms: u64,
     anchor_start_ms: Option<u64>,
-    recorded_start_ms: Option<u64>,
+    last_emit_ms: Option<u64>,
 }
 
 impl AssetState {
@@ -282,7 +294,7 @@ impl AssetState {
             candle_open_price: None,
             candle_interval_ms: candle_interval.as_millis() as u64,
             anchor_start_ms: None,
-            recorded_start_ms: None,
+            last_emit_ms: None,
         }
     }
 
@@ -299,32 +311,32 @@ impl AssetState {
             self.candle_start_ms = None;
             self.candle_open_price = None;
             self.volatility.reset(Duration::from_millis(interval_ms));
-            self.recorded_start_ms = None;
         }
     }
 
-    fn take_start_price_if_ready(&mut self, window: &AssetWindow) -> Option<(u64, f64)> {
-        let start_ms = window.start_time_ms;
-        if self.recorded_start_ms == Some(start_ms) {
-            return None;
-        }
-        if self.candle_start_ms != Some(start_ms) {
-            return None;
-        }
-        let price = self.candle_open_price?;
-        if price <= 0.0 {
-            return None;
+    /// Trades drive price and volatility; book tickers only fill in a mid until the first trade.
+    /// Emission is throttled so the shared market channel is not flooded.
+    fn apply_event(&mut self, asset: &str, event: BinanceEvent) -> Option<ChainlinkMarketUpdate> {
+        let update = self.update_from_event(asset, event)?;
+        if let Some(last) = self.last_emit_ms {
+            if update.event_time_ms.saturating_sub(last) < MIN_EMIT_INTERVAL_MS {
+                return None;
+            }
         }
-        self.recorded_start_ms = Some(start_ms);
-        Some((start_ms, price))
+        self.last_emit_ms = Some(update.event_time_ms);
+        Some(update)
     }
 
-    fn apply_event(&mut self, event: BinanceEvent) -> Result<Option<ChainlinkMarketUpdate>> {
+    fn update_from_event(
+        &mut self,
+        asset: &str,
+        event: BinanceEvent,
+    ) -> Option<ChainlinkMarketUpdate> {
         match event {
             BinanceEvent::AggTrade {
-                symbol,
                 price,
                 event_time_ms,
+                ..
             } => {
                 self.update_candle(event_time_ms, price);
                 self.last_price = Some(price);
@@ -333,40 +345,45 @@ impl AssetState {
                 }
                 let dfo = self.candle_open_price.map(|open| (price - open) / open);
 
-                Ok(Some(ChainlinkMarketUpdate {
-                    asset: symbol,
-                    best_bid: self.best_bid,
-                    best_ask: self.best_ask,
+                Some(ChainlinkMarketUpdate {
+                    asset: asset.to_string(),
                     last_price: Some(price),
                     volatility_1m: self.last_volatility,
                     dfo,
                     event_time_ms,
-                }))
+                })
             }
             BinanceEvent::BookTicker {
-                symbol,
                 best_bid,
                 best_ask,
                 event_time_ms,
+                ..
             } => {
                 self.best_bid = Some(best_bid);
                 self.best_ask = Some(best_ask);
+                if self.last_price.is_some() {
+                    return None;
+                }
+                let price = self.mid_price()?;
 
-                Ok(Some(ChainlinkMarketUpdate {
-                    asset: symbol,
-                    best_bid: self.best_bid,
-                    best_ask: self.best_ask,
-                    last_price: self.last_price,
+                Some(ChainlinkMarketUpdate {
+                    asset: asset.to_string(),
+                    last_price: Some(price),
                     volatility_1m: self.last_volatility,
-                    dfo: self
-                        .last_price
-                        .and_then(|price| self.candle_open_price.map(|open| (price - open) / open)),
+                    dfo: self.candle_open_price.map(|open| (price - open) / open),
                     event_time_ms,
-                }))
+                })
             }
         }
     }
 
+    fn mid_price(&self) -> Option<f64> {
+        match (self.best_bid, self.best_ask) {
+            (Some(bid), Some(ask)) if bid > 0.0 && ask > 0.0 => Some((bid + ask) / 2.0),
+            _ => None,
+        }
+    }
+
     fn update_candle(&mut self, timestamp_ms: u64, price: f64) {
         if self.candle_interval_ms == 0 {
             return;
@@ -9,7 +9,8 @@
  *
  * @notes
  * - Volatility uses a rolling window of returns.
- * - Start price snapshots align to the active market window in Redis.
+ * - Start price snapshots align to the active market window in Redis; candles adopt
+ *   the window's duration, so start/end capture works for any catalogue window length.
  */
 use futures_util::{SinkExt, StreamExt};
 use serde_json::Value;
@@ -21,6 +22,7 @@ use tokio_tungstenite::tungstenite::Message;
 use crate::engine::types::{ChainlinkMarketUpdate, MarketUpdate};
 use crate::error::{BankaiError, Result};
 use crate::storage::redis::{AssetWindow, RedisManager};
+use crate::telemetry::metrics;
 
 const TOPIC: &str = "crypto_prices_chainlink";
 const DEFAULT_PING_INTERVAL: Duration = Duration::from_secs(5);
@@ -87,7 +89,13 @@ impl ChainlinkOracle {
         let idle_timeout = Duration::from_secs(30);
         let price_stale_timeout = DEFAULT_PRICE_STALE_TIMEOUT;
 
+        let mut first_connect = true;
         loop {
+            if first_connect {
+                first_connect = false;
+            } else {
+                metrics::increment_oracle_reconnect("chainlink");
+            }
             let endpoint = self.config.endpoint.clone();
             tracing::warn!(endpoint = %endpoint, "chainlink ws connecting");
 
@@ -191,6 +199,7 @@ impl ChainlinkOracle {
                     message = reader.next() => {
                         match message {
                             Some(Ok(Message::Text(text))) => {
+                                metrics::increment_oracle_message("chainlink");
                                 last_msg_ms = now_ms().unwrap_or(0);
                                 let event = match parse_event(&text) {
                                     Ok(value) => value,
@@ -406,7 +415,7 @@ impl AssetState {
     }
 
     fn set_window(&mut self, window: &AssetWindow) {
-        let interval_ms = window.end_time_ms.saturating_sub(window.start_time_ms);
+        let interval_ms = window.duration_ms();
         if interval_ms == 0 {
             return;
         }
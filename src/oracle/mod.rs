@@ -1,4 +1,5 @@
 pub mod allora;
+pub mod binance;
 /**
  * @description
  * Oracle modules for market data ingestion.
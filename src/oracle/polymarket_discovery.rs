@@ -4,11 +4,14 @@
  *
  * @dependencies
  * - reqwest: HTTP client for Gamma API polling
+ * - execution::clob: fee-rate lookups
  * - serde_json: response parsing
  *
  * @notes
  * - Filters out augmented negative risk markets (any with "Other" outcomes).
- * - Stores feeRateBps, minTickSize, and 15m time windows in Redis for eligible markets.
+ * - Stores feeRateBps, minTickSize, and time windows in Redis for eligible markets.
+ * - Assets, tags, slug patterns and window lengths come from `polymarket.markets`; a window
+ *   is anchored on the slug epoch (or `endDate`) and confirmed by the title range or `endDate`.
  */
 use chrono::offset::LocalResult;
 use chrono::{
@@ -17,14 +20,17 @@ use chrono::{
 use chrono_tz::America::New_York;
 use regex::Regex;
 use reqwest::Client;
-use serde::Deserialize;
 use serde_json::Value;
 use std::collections::{HashMap, HashSet};
 use std::sync::OnceLock;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+use crate::config::{MarketCatalogueEntry, PolymarketConfig};
+use crate::engine::backtest::{ReplayEvent, ReplayMarket};
 use crate::engine::types::MarketWindow;
 use crate::error::Result;
+use crate::execution::clob::ClobClient;
+use crate::storage::recorder::RecorderHandle;
 use crate::storage::redis::{OutcomeTokenIds, RedisManager};
 
 const DEFAULT_LIMIT: usize = 200;
@@ -37,22 +43,22 @@ const WINDOW_CACHE_PRUNE_LAG_MS: u64 = 30 * 60 * 1_000;
 #[derive(Debug, Clone)]
 pub struct PolymarketDiscoveryConfig {
     pub base_url: String,
-    pub fee_rate_base_url: String,
     pub poll_interval: Duration,
     pub jitter_ms: u64,
     pub min_liquidity: f64,
     pub limit: usize,
+    pub markets: Vec<MarketCatalogueEntry>,
 }
 
 impl PolymarketDiscoveryConfig {
-    pub fn new(base_url: String, fee_rate_base_url: String) -> Self {
+    pub fn new(base_url: String) -> Self {
         Self {
             base_url,
-            fee_rate_base_url,
             poll_interval: DEFAULT_POLL_INTERVAL,
             jitter_ms: DEFAULT_JITTER_MS,
             min_liquidity: DEFAULT_MIN_LIQUIDITY,
             limit: DEFAULT_LIMIT,
+            markets: PolymarketConfig::default().markets,
         }
     }
 }
@@ -60,23 +66,38 @@ impl PolymarketDiscoveryConfig {
 pub struct PolymarketDiscovery {
     config: PolymarketDiscoveryConfig,
     client: Client,
+    clob: ClobClient,
     redis: RedisManager,
     logged_markets: HashSet<String>,
-    last_asset_windows: HashMap<AssetSymbol, MarketTimeWindow>,
+    last_asset_windows: HashMap<String, MarketTimeWindow>,
+    recorder: Option<RecorderHandle>,
+    recorded_markets: HashSet<String>,
 }
 
 impl PolymarketDiscovery {
-    pub fn new(config: PolymarketDiscoveryConfig, redis: RedisManager) -> Result<Self> {
+    pub fn new(
+        config: PolymarketDiscoveryConfig,
+        redis: RedisManager,
+        clob: ClobClient,
+    ) -> Result<Self> {
         let client = Client::builder().timeout(Duration::from_secs(10)).build()?;
         Ok(Self {
             config,
             client,
+            clob,
             redis,
             logged_markets: HashSet::new(),
             last_asset_windows: HashMap::new(),
+            recorder: None,
+            recorded_markets: HashSet::new(),
         })
     }
 
+    pub fn with_recorder(mut self, recorder: RecorderHandle) -> Self {
+        self.recorder = Some(recorder);
+        self
+    }
+
     pub fn spawn(self) -> tokio::task::JoinHandle<()> {
         tokio::spawn(async move {
             if let Err(error) = self.run().await {
@@ -98,10 +119,19 @@ impl PolymarketDiscovery {
     async fn refresh_markets(&mut self) -> Result<()> {
         let mut scanned = 0usize;
         let mut accepted = 0usize;
-        let mut windows_by_asset: HashMap<AssetSymbol, Vec<MarketCandidate>> = HashMap::new();
+        let mut windows_by_asset: HashMap<String, Vec<MarketCandidate>> = HashMap::new();
 
         let now_ms = now_ms().unwrap_or(0);
-        for tag in TARGET_TAGS {
+        let markets_config = self.config.markets.clone();
+        let mut tags: Vec<&str> = Vec::new();
+        for entry in &markets_config {
+            for tag in &entry.tag_ids {
+                if !tags.contains(&tag.as_str()) {
+                    tags.push(tag.as_str());
+                }
+            }
+        }
+        for tag in tags {
             let mut offset = 0usize;
             loop {
                 let markets = self.fetch_markets(tag, offset).await?;
@@ -113,11 +143,13 @@ impl PolymarketDiscovery {
                     if !is_open_market(market) {
                         continue;
                     }
-                    let Some(asset_symbol) = target_asset(market).or_else(|| asset_from_tag(tag))
+                    let Some(entry) = target_asset(market, &markets_config)
+                        .or_else(|| asset_from_tag(tag, &markets_config))
                     else {
                         continue;
                     };
-                    if let Some(time_window) = is_target_market(market) {
+                    let asset_symbol = entry.asset.to_ascii_uppercase();
+                    if let Some(time_window) = is_target_market(market, entry) {
                         if time_window.end_time_ms <= now_ms {
                             continue;
                         }
@@ -146,43 +178,50 @@ impl PolymarketDiscovery {
                                 .redis
                                 .set_token_market(&outcome_tokens.down, &metadata.market_id)
                                 .await;
-                            if let Some(fee_rate) =
-                                self.fetch_fee_rate_bps(&outcome_tokens.up).await?
-                            {
+                            let up_fee_rate = self.fetch_fee_rate_bps(&outcome_tokens.up).await?;
+                            if let Some(fee_rate) = up_fee_rate {
                                 let _ = self
                                     .redis
                                     .set_fee_rate_bps(&outcome_tokens.up, fee_rate, now_ms)
                                     .await;
                             }
-                            if let Some(fee_rate) =
-                                self.fetch_fee_rate_bps(&outcome_tokens.down).await?
-                            {
+                            let down_fee_rate =
+                                self.fetch_fee_rate_bps(&outcome_tokens.down).await?;
+                            if let Some(fee_rate) = down_fee_rate {
                                 let _ = self
                                     .redis
                                     .set_fee_rate_bps(&outcome_tokens.down, fee_rate, now_ms)
                                     .await;
                             }
+                            self.record_market_if_new(
+                                &asset_symbol,
+                                &metadata,
+                                &outcome_tokens,
+                                up_fee_rate,
+                                down_fee_rate,
+                            );
                             accepted += 1;
                             let label = market_label(market);
                             let _ = self
                                 .redis
                                 .add_asset_window_cache(
-                                    asset_symbol.as_str(),
+                                    &asset_symbol,
                                     &metadata.market_id,
                                     metadata.start_time_ms,
                                 )
                                 .await;
-                            windows_by_asset.entry(asset_symbol).or_default().push(
-                                MarketCandidate {
+                            windows_by_asset
+                                .entry(asset_symbol.clone())
+                                .or_default()
+                                .push(MarketCandidate {
                                     window: time_window,
                                     market_id: metadata.market_id.clone(),
                                     label: label.clone(),
                                     asset_ids: vec![outcome_tokens.up, outcome_tokens.down],
-                                },
-                            );
+                                });
                             self.log_market_if_new(
                                 &metadata.market_id,
-                                asset_symbol,
+                                &asset_symbol,
                                 &time_window,
                                 &label,
                             )
@@ -225,48 +264,48 @@ impl PolymarketDiscovery {
     }
 
     async fn fetch_fee_rate_bps(&self, token_id: &str) -> Result<Option<f64>> {
-        let base = self.config.fee_rate_base_url.trim_end_matches('/');
-        if base.is_empty() || token_id.trim().is_empty() {
-            return Ok(None);
-        }
-        let url = format!("{base}/fee-rate?token_id={token_id}");
-        let mut last_error = None;
-        let mut response = None;
-        for _ in 0..3 {
-            match self.client.get(&url).send().await {
-                Ok(resp) if resp.status().is_success() => {
-                    response = Some(resp);
-                    break;
-                }
-                Ok(resp) => last_error = Some(format!("status {}", resp.status())),
-                Err(err) => last_error = Some(err.to_string()),
+        match self.clob.fee_rate_bps(token_id).await {
+            Ok(fee_rate_bps) => Ok(fee_rate_bps),
+            Err(error) => {
+                tracing::warn!(token_id = %token_id, ?error, "fee rate fetch failed");
+                Ok(None)
             }
-            tokio::time::sleep(Duration::from_millis(150)).await;
         }
-        let Some(response) = response else {
-            tracing::warn!(token_id = %token_id, error = last_error.unwrap_or_else(|| "unknown".to_string()), "fee rate fetch failed");
-            return Ok(None);
-        };
-        let body: FeeRateResponse = match response.json().await {
-            Ok(parsed) => parsed,
-            Err(err) => {
-                tracing::warn!(token_id = %token_id, error = %err, "fee rate parse failed");
-                return Ok(None);
-            }
+    }
+
+    fn record_market_if_new(
+        &mut self,
+        asset: &str,
+        metadata: &MarketMetadata,
+        outcome_tokens: &OutcomeTokenIds,
+        up_fee_rate_bps: Option<f64>,
+        down_fee_rate_bps: Option<f64>,
+    ) {
+        let Some(recorder) = self.recorder.as_ref() else {
+            return;
         };
-        if let Some(fee_rate_bps) = body.fee_rate_bps {
-            return Ok(Some(fee_rate_bps));
-        }
-        if body.base_fee.is_some() {
-            return Ok(Some(0.0));
+        if !self.recorded_markets.insert(metadata.market_id.clone()) {
+            return;
         }
-        Ok(None)
+        recorder.record(ReplayEvent::Market(ReplayMarket {
+            market_id: metadata.market_id.clone(),
+            asset: asset.to_string(),
+            start_time_ms: metadata.start_time_ms,
+            end_time_ms: metadata.end_time_ms,
+            up_token_id: outcome_tokens.up.clone(),
+            down_token_id: outcome_tokens.down.clone(),
+            fee_rate_bps: Some(metadata.fee_rate_bps),
+            up_fee_rate_bps,
+            down_fee_rate_bps,
+            min_tick_size: Some(metadata.min_tick_size),
+            min_order_size: metadata.min_order_size,
+        }));
     }
 
     async fn log_market_if_new(
         &mut self,
         market_id: &str,
-        asset: AssetSymbol,
+        asset: &str,
         window: &MarketTimeWindow,
         label: &str,
     ) {
@@ -301,7 +340,7 @@ impl PolymarketDiscovery {
 
     async fn update_asset_windows(
         &mut self,
-        windows_by_asset: HashMap<AssetSymbol, Vec<MarketCandidate>>,
+        windows_by_asset: HashMap<String, Vec<MarketCandidate>>,
         asset_ids: &mut HashSet<String>,
     ) {
         let now_ms = now_ms().unwrap_or(0);
@@ -316,7 +355,7 @@ impl PolymarketDiscovery {
             let label = candidate.label.as_str();
             let _ = self
                 .redis
-                .set_asset_window(asset.as_str(), to_market_window(window), market_id, now_ms)
+                .set_asset_window(&asset, to_market_window(window), market_id, now_ms)
                 .await;
             for asset_id in &candidate.asset_ids {
                 asset_ids.insert(asset_id.clone());
@@ -331,7 +370,7 @@ impl PolymarketDiscovery {
                     let _ = self
                         .redis
                         .set_asset_window_next(
-                            asset.as_str(),
+                            &asset,
                             to_market_window(next_window.window),
                             &next_window.market_id,
                             now_ms,
@@ -341,18 +380,15 @@ impl PolymarketDiscovery {
                         asset_ids.insert(asset_id.clone());
                     }
                 } else {
-                    let _ = self.redis.clear_asset_window_next(asset.as_str()).await;
+                    let _ = self.redis.clear_asset_window_next(&asset).await;
                 }
             } else {
-                let _ = self.redis.clear_asset_window_next(asset.as_str()).await;
+                let _ = self.redis.clear_asset_window_next(&asset).await;
             }
 
             if now_ms > WINDOW_CACHE_PRUNE_LAG_MS {
                 let cutoff = now_ms - WINDOW_CACHE_PRUNE_LAG_MS;
-                let _ = self
-                    .redis
-                    .prune_asset_window_cache(asset.as_str(), cutoff)
-                    .await;
+                let _ = self.redis.prune_asset_window_cache(&asset, cutoff).await;
             }
             let changed = self
                 .last_asset_windows
@@ -391,31 +427,6 @@ struct MarketCandidate {
     asset_ids: Vec<String>,
 }
 
-#[derive(Debug, Deserialize)]
-struct FeeRateResponse {
-    #[serde(rename = "fee_rate_bps")]
-    fee_rate_bps: Option<f64>,
-    #[serde(rename = "base_fee")]
-    base_fee: Option<f64>,
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-enum AssetSymbol {
-    Btc,
-    Eth,
-    Sol,
-}
-
-impl AssetSymbol {
-    fn as_str(self) -> &'static str {
-        match self {
-            AssetSymbol::Btc => "BTC",
-            AssetSymbol::Eth => "ETH",
-            AssetSymbol::Sol => "SOL",
-        }
-    }
-}
-
 fn extract_market_metadata(
     market: &Value,
     min_liquidity: f64,
@@ -451,20 +462,8 @@ fn is_open_market(market: &Value) -> bool {
     !closed
 }
 
-// Match Tag Logic
-// BTC: 235, ETH: 39, SOL: 968
-const BTC_TAG: &str = "235";
-const ETH_TAG: &str = "39";
-const SOL_TAG: &str = "968";
-const TARGET_TAGS: [&str; 3] = [BTC_TAG, ETH_TAG, SOL_TAG];
-
-fn is_target_market(market: &Value) -> Option<MarketTimeWindow> {
-    // 1. Tag Check
-    if !has_target_tag(market) {
-        return None;
-    }
-
-    // 2. Title/Question Check
+fn is_target_market(market: &Value, entry: &MarketCatalogueEntry) -> Option<MarketTimeWindow> {
+    // 1. Title/Question Check
     let question = market
         .get("question")
         .and_then(|v| v.as_str())
@@ -476,24 +475,23 @@ fn is_target_market(market: &Value) -> Option<MarketTimeWindow> {
         return None;
     }
 
-    // 3. Outcome Check: Must be ["Up", "Down"]
+    // 2. Outcome Check: Must be ["Up", "Down"]
     if !has_valid_outcomes(market) {
         return None;
     }
 
-    // 4. 15m Classification: require 15m slug and validate time window.
-    if !has_15m_slug(market) {
+    // 3. Window Classification: require a catalogue slug pattern and validate the window.
+    if !matches_slug_pattern(market, entry) {
         return None;
     }
 
-    extract_time_window(market, title, question)
+    extract_time_window(market, title, question, entry.window_ms())
 }
 
-fn has_target_tag(market: &Value) -> bool {
-    target_asset(market).is_some()
-}
-
-fn target_asset(market: &Value) -> Option<AssetSymbol> {
+fn target_asset<'a>(
+    market: &Value,
+    markets: &'a [MarketCatalogueEntry],
+) -> Option<&'a MarketCatalogueEntry> {
     if let Some(tags) = market.get("tags").and_then(|v| v.as_array()) {
         for tag in tags {
             if let Some(id_val) = tag.get("id") {
@@ -504,16 +502,16 @@ fn target_asset(market: &Value) -> Option<AssetSymbol> {
                 } else {
                     continue;
                 };
-                if let Some(asset) = asset_from_tag(&id_str) {
-                    return Some(asset);
+                if let Some(entry) = asset_from_tag(&id_str, markets) {
+                    return Some(entry);
                 }
             }
         }
     }
 
     let slug = market.get("slug").and_then(|v| v.as_str()).unwrap_or("");
-    if let Some(asset) = asset_from_slug(slug) {
-        return Some(asset);
+    if let Some(entry) = asset_from_slug(slug, markets) {
+        return Some(entry);
     }
 
     let question = market
@@ -521,44 +519,40 @@ fn target_asset(market: &Value) -> Option<AssetSymbol> {
         .and_then(|v| v.as_str())
         .unwrap_or("");
     let title = market.get("title").and_then(|v| v.as_str()).unwrap_or("");
-    asset_from_text(question).or_else(|| asset_from_text(title))
+    asset_from_text(question, markets).or_else(|| asset_from_text(title, markets))
 }
 
-fn asset_from_tag(tag_id: &str) -> Option<AssetSymbol> {
-    match tag_id {
-        BTC_TAG => Some(AssetSymbol::Btc),
-        ETH_TAG => Some(AssetSymbol::Eth),
-        SOL_TAG => Some(AssetSymbol::Sol),
-        _ => None,
-    }
+fn asset_from_tag<'a>(
+    tag_id: &str,
+    markets: &'a [MarketCatalogueEntry],
+) -> Option<&'a MarketCatalogueEntry> {
+    markets
+        .iter()
+        .find(|entry| entry.tag_ids.iter().any(|tag| tag == tag_id))
 }
 
-fn asset_from_slug(slug: &str) -> Option<AssetSymbol> {
+fn asset_from_slug<'a>(
+    slug: &str,
+    markets: &'a [MarketCatalogueEntry],
+) -> Option<&'a MarketCatalogueEntry> {
     let lower = slug.to_ascii_lowercase();
-    if lower.starts_with("btc") || lower.starts_with("bitcoin") {
-        return Some(AssetSymbol::Btc);
-    }
-    if lower.starts_with("eth") || lower.starts_with("ethereum") {
-        return Some(AssetSymbol::Eth);
-    }
-    if lower.starts_with("sol") || lower.starts_with("solana") {
-        return Some(AssetSymbol::Sol);
-    }
-    None
+    markets.iter().find(|entry| {
+        entry
+            .names()
+            .any(|name| !name.is_empty() && lower.starts_with(&name.to_ascii_lowercase()))
+    })
 }
 
-fn asset_from_text(text: &str) -> Option<AssetSymbol> {
+fn asset_from_text<'a>(
+    text: &str,
+    markets: &'a [MarketCatalogueEntry],
+) -> Option<&'a MarketCatalogueEntry> {
     let lower = text.to_ascii_lowercase();
-    if lower.contains("bitcoin") || lower.contains("btc") {
-        return Some(AssetSymbol::Btc);
-    }
-    if lower.contains("ethereum") || lower.contains("eth") {
-        return Some(AssetSymbol::Eth);
-    }
-    if lower.contains("solana") || lower.contains("sol") {
-        return Some(AssetSymbol::Sol);
-    }
-    None
+    markets.iter().find(|entry| {
+        entry
+            .names()
+            .any(|name| !name.is_empty() && lower.contains(&name.to_ascii_lowercase()))
+    })
 }
 
 fn market_label(market: &Value) -> String {
@@ -596,43 +590,87 @@ fn matches_up_down(arr: &[Value]) -> bool {
     arr[0].as_str() == Some("Up") && arr[1].as_str() == Some("Down")
 }
 
-fn extract_time_window(market: &Value, title: &str, question: &str) -> Option<MarketTimeWindow> {
-    let slug_window = parse_slug_time_window(market)?;
+fn extract_time_window(
+    market: &Value,
+    title: &str,
+    question: &str,
+    window_ms: u64,
+) -> Option<MarketTimeWindow> {
+    let end_date_ms = parse_end_date_ms(market);
+    let anchor = parse_slug_time_window(market, window_ms).or_else(|| {
+        let end_time_ms = end_date_ms?;
+        Some(MarketTimeWindow {
+            start_time_ms: end_time_ms.checked_sub(window_ms)?,
+            end_time_ms,
+        })
+    })?;
     let reference_et = New_York.from_utc_datetime(
-        &Utc.timestamp_millis_opt(slug_window.start_time_ms as i64)
+        &Utc.timestamp_millis_opt(anchor.start_time_ms as i64)
             .single()?
             .naive_utc(),
     );
-    let parsed_window = parse_time_window_from_text(title, reference_et)
-        .or_else(|| parse_time_window_from_text(question, reference_et));
-
-    if let Some(parsed) = parsed_window {
-        if !windows_match(slug_window, parsed) {
-            return None;
+    let parsed_window = parse_time_window_from_text(title, reference_et, window_ms)
+        .or_else(|| parse_time_window_from_text(question, reference_et, window_ms));
+
+    let confirmed = match parsed_window {
+        Some(parsed) => windows_match(anchor, parsed),
+        // Hourly and longer titles carry no time range; fall back to the API timestamps.
+        None => {
+            let end_matches = end_date_ms
+                .map(|end_ms| within_tolerance(end_ms, anchor.end_time_ms))
+                .unwrap_or(false);
+            let start_matches = parse_event_start_ms(market)
+                .map(|start_ms| within_tolerance(start_ms, anchor.start_time_ms))
+                .unwrap_or(true);
+            end_matches && start_matches
         }
-    } else {
+    };
+    if !confirmed {
         return None;
     }
 
-    Some(slug_window)
+    Some(anchor)
 }
 
-fn has_15m_slug(market: &Value) -> bool {
-    let slug = market.get("slug").and_then(|v| v.as_str()).unwrap_or("");
-    slug.to_ascii_lowercase().contains("15m")
+fn matches_slug_pattern(market: &Value, entry: &MarketCatalogueEntry) -> bool {
+    if entry.slug_patterns.is_empty() {
+        return true;
+    }
+    let slug = market
+        .get("slug")
+        .and_then(|v| v.as_str())
+        .unwrap_or("")
+        .to_ascii_lowercase();
+    entry
+        .slug_patterns
+        .iter()
+        .any(|pattern| slug.contains(&pattern.to_ascii_lowercase()))
 }
 
-fn parse_slug_time_window(market: &Value) -> Option<MarketTimeWindow> {
+fn parse_slug_time_window(market: &Value, window_ms: u64) -> Option<MarketTimeWindow> {
     let slug = market.get("slug").and_then(|v| v.as_str()).unwrap_or("");
     let epoch_seconds = parse_slug_epoch_seconds(slug)?;
     let start_time_ms = epoch_seconds.checked_mul(1_000)?;
-    let end_time_ms = start_time_ms.checked_add(15 * 60 * 1_000)?;
+    let end_time_ms = start_time_ms.checked_add(window_ms)?;
     Some(MarketTimeWindow {
         start_time_ms,
         end_time_ms,
     })
 }
 
+fn parse_end_date_ms(market: &Value) -> Option<u64> {
+    parse_rfc3339_ms(market.get("endDate")?.as_str()?)
+}
+
+fn parse_event_start_ms(market: &Value) -> Option<u64> {
+    parse_rfc3339_ms(market.get("eventStartTime")?.as_str()?)
+}
+
+fn parse_rfc3339_ms(value: &str) -> Option<u64> {
+    let parsed = chrono::DateTime::parse_from_rfc3339(value).ok()?;
+    u64::try_from(parsed.timestamp_millis()).ok()
+}
+
 fn parse_slug_epoch_seconds(slug: &str) -> Option<u64> {
     let last = slug.rsplit('-').next()?;
     if last.len() < 9 || last.len() > 12 {
@@ -645,15 +683,19 @@ fn parse_slug_epoch_seconds(slug: &str) -> Option<u64> {
 }
 
 fn windows_match(a: MarketTimeWindow, b: MarketTimeWindow) -> bool {
-    const TOLERANCE_MS: i64 = 60_000;
-    let start_delta = a.start_time_ms as i64 - b.start_time_ms as i64;
-    let end_delta = a.end_time_ms as i64 - b.end_time_ms as i64;
-    start_delta.abs() <= TOLERANCE_MS && end_delta.abs() <= TOLERANCE_MS
+    within_tolerance(a.start_time_ms, b.start_time_ms)
+        && within_tolerance(a.end_time_ms, b.end_time_ms)
+}
+
+fn within_tolerance(a_ms: u64, b_ms: u64) -> bool {
+    const TOLERANCE_MS: u64 = 60_000;
+    a_ms.abs_diff(b_ms) <= TOLERANCE_MS
 }
 
 fn parse_time_window_from_text(
     text: &str,
     reference_et: chrono::DateTime<chrono_tz::Tz>,
+    window_ms: u64,
 ) -> Option<MarketTimeWindow> {
     let captures = time_range_regex().captures(text)?;
     let month = parse_month(captures.get(1)?.as_str())?;
@@ -694,7 +736,7 @@ fn parse_time_window_from_text(
     let start_utc = start_et.with_timezone(&Utc);
     let end_utc = end_et.with_timezone(&Utc);
     let duration = end_utc - start_utc;
-    if duration.num_minutes() != 15 {
+    if duration.num_milliseconds() != window_ms as i64 {
         return None;
     }
 
@@ -1016,6 +1058,9 @@ fn compute_jitter(max_ms: u64) -> Result<Duration> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serde_json::json;
+
+    const WINDOW_15M_MS: u64 = 15 * 60 * 1_000;
 
     #[test]
     fn parses_valid_15m_window_from_title() {
@@ -1024,7 +1069,7 @@ mod tests {
             .single()
             .unwrap();
         let title = "Bitcoin Up or Down - January 24, 5:45PM-6:00PM ET";
-        let window = parse_time_window_from_text(title, reference).expect("window");
+        let window = parse_time_window_from_text(title, reference, WINDOW_15M_MS).expect("window");
         let start_et = New_York
             .with_ymd_and_hms(2026, 1, 24, 17, 45, 0)
             .single()
@@ -1050,7 +1095,7 @@ mod tests {
             .single()
             .unwrap();
         let title = "Ethereum Up or Down - January 24, 5:00PM-6:00PM ET";
-        assert!(parse_time_window_from_text(title, reference).is_none());
+        assert!(parse_time_window_from_text(title, reference, WINDOW_15M_MS).is_none());
     }
 
     #[test]
@@ -1060,7 +1105,7 @@ mod tests {
             .single()
             .unwrap();
         let title = "Solana Up or Down - January 1, 12:00AM-12:15AM ET";
-        let window = parse_time_window_from_text(title, reference).expect("window");
+        let window = parse_time_window_from_text(title, reference, WINDOW_15M_MS).expect("window");
         let start_et = New_York
             .with_ymd_and_hms(2026, 1, 1, 0, 0, 0)
             .single()
@@ -1086,7 +1131,7 @@ mod tests {
             .single()
             .unwrap();
         let title = "BTC Up or Down - Jan 24, 9:15AM - 9:30AM ET";
-        let window = parse_time_window_from_text(title, reference).expect("window");
+        let window = parse_time_window_from_text(title, reference, WINDOW_15M_MS).expect("window");
         let start_et = New_York
             .with_ymd_and_hms(2026, 1, 24, 9, 15, 0)
             .single()
@@ -1104,4 +1149,41 @@ mod tests {
             end_et.with_timezone(&Utc).timestamp_millis() as u64
         );
     }
+
+    #[test]
+    fn classifies_hourly_market_from_catalogue() {
+        let markets = vec![MarketCatalogueEntry {
+            asset: "XRP".to_string(),
+            tag_ids: vec!["101".to_string()],
+            aliases: vec!["ripple".to_string()],
+            slug_patterns: vec!["up-or-down".to_string()],
+            window_secs: 3_600,
+            signal_timeframe: "1h".to_string(),
+            alignment_max_secs: None,
+        }];
+        let market = json!({
+            "slug": "ripple-up-or-down-january-24-5pm-et",
+            "question": "Ripple Up or Down - January 24, 5PM ET",
+            "outcomes": "[\"Up\", \"Down\"]",
+            "endDate": "2026-01-24T23:00:00Z",
+            "eventStartTime": "2026-01-24T22:00:00Z",
+        });
+        let entry = target_asset(&market, &markets).expect("asset");
+        assert_eq!(entry.asset, "XRP");
+        let window = is_target_market(&market, entry).expect("window");
+        assert_eq!(window.end_time_ms - window.start_time_ms, 3_600_000);
+        assert_eq!(
+            window.start_time_ms,
+            parse_rfc3339_ms("2026-01-24T22:00:00Z").unwrap()
+        );
+
+        let mismatched = json!({
+            "slug": "ripple-up-or-down-january-24-5pm-et",
+            "question": "Ripple Up or Down - January 24, 5PM ET",
+            "outcomes": "[\"Up\", \"Down\"]",
+            "endDate": "2026-01-24T23:00:00Z",
+            "eventStartTime": "2026-01-24T22:45:00Z",
+        });
+        assert!(is_target_market(&mismatched, entry).is_none());
+    }
 }
@@ -10,16 +10,25 @@
  * @notes
  * - Seeds Redis with REST snapshots before streaming price_change updates.
  * - Uses MARKET channel subscription for asset IDs (token IDs).
+ * - Full `book` events replace the token's book, like a REST snapshot, and clear a degraded
+ *   flag when the result is healthy.
+ * - Every touched book is validated (uncrossed, non-negative sizes, feed-reported top of
+ *   book). Failures mark the token degraded and spawn a REST resnapshot so the stream loop
+ *   never waits on HTTP; rebuilds that fail are retried on the asset refresh tick.
  */
 use futures_util::{SinkExt, StreamExt};
 use reqwest::Client;
 use serde_json::{json, Value};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tokio_tungstenite::tungstenite::Message;
 
+use crate::engine::backtest::ReplayEvent;
 use crate::error::{BankaiError, Result};
-use crate::storage::orderbook::{BookSide, OrderBookLevel, OrderBookStore};
+use crate::storage::orderbook::{BookIntegrityIssue, BookSide, OrderBookLevel, OrderBookStore};
+use crate::storage::recorder::RecorderHandle;
+use crate::telemetry::metrics;
 
 const DEFAULT_PING_INTERVAL: Duration = Duration::from_secs(10);
 const DEFAULT_RECONNECT_DELAY: Duration = Duration::from_secs(3);
@@ -67,6 +76,11 @@ pub struct PolymarketRtds {
     config: PolymarketRtdsConfig,
     client: Client,
     orderbook: OrderBookStore,
+    recorder: Option<RecorderHandle>,
+    /// Tokens with a REST rebuild in flight.
+    rebuilding: Mutex<HashSet<String>>,
+    /// Tokens whose last rebuild failed; retried on the asset refresh tick.
+    pending_rebuilds: Mutex<HashSet<String>>,
 }
 
 impl PolymarketRtds {
@@ -76,18 +90,27 @@ impl PolymarketRtds {
             config,
             client,
             orderbook,
+            recorder: None,
+            rebuilding: Mutex::new(HashSet::new()),
+            pending_rebuilds: Mutex::new(HashSet::new()),
         })
     }
 
+    pub fn with_recorder(mut self, recorder: RecorderHandle) -> Self {
+        self.recorder = Some(recorder);
+        self
+    }
+
     pub fn spawn(self) -> tokio::task::JoinHandle<()> {
+        let rtds = Arc::new(self);
         tokio::spawn(async move {
-            if let Err(error) = self.run().await {
+            if let Err(error) = rtds.run().await {
                 tracing::error!(?error, "polymarket rtds stopped");
             }
         })
     }
 
-    async fn run(self) -> Result<()> {
+    async fn run(self: &Arc<Self>) -> Result<()> {
         loop {
             let asset_ids = self.resolve_asset_ids().await?;
             if asset_ids.is_empty() {
@@ -109,6 +132,7 @@ impl PolymarketRtds {
                     tracing::warn!(?error, "polymarket rtds stream error");
                 }
             }
+            metrics::increment_oracle_reconnect("polymarket_market");
 
             tokio::time::sleep(self.config.reconnect_delay).await;
         }
@@ -128,22 +152,98 @@ impl PolymarketRtds {
 
     async fn seed_snapshots(&self, asset_ids: &[String]) -> Result<()> {
         for asset_id in asset_ids {
-            match self.fetch_snapshot(asset_id).await {
-                Ok(snapshot) => {
-                    self.orderbook
-                        .apply_snapshot(asset_id, &snapshot.bids, &snapshot.asks)
-                        .await?;
-                }
-                Err(error) => {
-                    tracing::warn!(
-                        ?error,
-                        asset_id = %asset_id,
-                        "failed to fetch polymarket snapshot"
-                    );
+            self.rebuild_book(asset_id).await;
+        }
+        Ok(())
+    }
+
+    /// Replaces a token's book with a REST snapshot; returns whether it is healthy again.
+    async fn rebuild_book(&self, asset_id: &str) -> bool {
+        let snapshot = match self.fetch_snapshot(asset_id).await {
+            Ok(snapshot) => snapshot,
+            Err(error) => {
+                tracing::warn!(
+                    ?error,
+                    asset_id = %asset_id,
+                    "failed to fetch polymarket snapshot"
+                );
+                return false;
+            }
+        };
+        let result = async {
+            self.orderbook
+                .apply_snapshot(asset_id, &snapshot.bids, &snapshot.asks)
+                .await?;
+            let issue = self.orderbook.check_integrity(asset_id, None).await?;
+            if issue.is_none() {
+                self.orderbook.clear_degraded(asset_id).await?;
+            }
+            Ok::<_, BankaiError>(issue)
+        }
+        .await;
+        match result {
+            Ok(None) => true,
+            Ok(Some(issue)) => {
+                tracing::warn!(
+                    asset_id = %asset_id,
+                    issue = issue.label(),
+                    "polymarket snapshot failed integrity check"
+                );
+                false
+            }
+            Err(error) => {
+                tracing::warn!(?error, asset_id = %asset_id, "failed to apply polymarket snapshot");
+                false
+            }
+        }
+    }
+
+    async fn handle_integrity_issue(self: &Arc<Self>, asset_id: &str, issue: BookIntegrityIssue) {
+        metrics::increment_book_integrity_failure(issue.label());
+        tracing::warn!(
+            asset_id = %asset_id,
+            issue = issue.label(),
+            "order book integrity failure; resnapshotting"
+        );
+        if let Err(error) = self.orderbook.mark_degraded(asset_id, issue).await {
+            tracing::warn!(?error, asset_id = %asset_id, "failed to mark book degraded");
+        }
+        self.spawn_rebuild(asset_id);
+    }
+
+    /// Rebuilds a token's book off the stream loop; one rebuild per token at a time.
+    fn spawn_rebuild(self: &Arc<Self>, asset_id: &str) {
+        let started = self
+            .rebuilding
+            .lock()
+            .map(|mut rebuilding| rebuilding.insert(asset_id.to_string()))
+            .unwrap_or(false);
+        if !started {
+            return;
+        }
+        let rtds = self.clone();
+        let asset_id = asset_id.to_string();
+        tokio::spawn(async move {
+            let healthy = rtds.rebuild_book(&asset_id).await;
+            if let Ok(mut rebuilding) = rtds.rebuilding.lock() {
+                rebuilding.remove(&asset_id);
+            }
+            if !healthy {
+                if let Ok(mut pending) = rtds.pending_rebuilds.lock() {
+                    pending.insert(asset_id);
                 }
             }
+        });
+    }
+
+    fn retry_pending_rebuilds(self: &Arc<Self>) {
+        let pending = match self.pending_rebuilds.lock() {
+            Ok(mut pending) => std::mem::take(&mut *pending),
+            Err(_) => return,
+        };
+        for asset_id in pending {
+            self.spawn_rebuild(&asset_id);
         }
-        Ok(())
     }
 
     async fn fetch_snapshot(&self, asset_id: &str) -> Result<OrderBookSnapshot> {
@@ -153,11 +253,19 @@ impl PolymarketRtds {
             asset_id
         );
         let response = self.client.get(url).send().await?.error_for_status()?;
-        let parsed: Value = response.json().await?;
-        parse_snapshot(&parsed)
+        let mut parsed: Value = response.json().await?;
+        let snapshot = parse_snapshot(&parsed)?;
+        if let Some(recorder) = self.recorder.as_ref() {
+            if let Some(map) = parsed.as_object_mut() {
+                map.entry("asset_id")
+                    .or_insert_with(|| Value::String(asset_id.to_string()));
+            }
+            recorder.record(ReplayEvent::BookSnapshot(parsed));
+        }
+        Ok(snapshot)
     }
 
-    async fn connect_and_stream(&self, asset_ids: &[String]) -> Result<StreamOutcome> {
+    async fn connect_and_stream(self: &Arc<Self>, asset_ids: &[String]) -> Result<StreamOutcome> {
         let (ws_stream, _) = tokio_tungstenite::connect_async(&self.config.ws_endpoint).await?;
         let (mut writer, mut reader) = ws_stream.split();
         let payload = build_subscription_payload(asset_ids, self.config.auth.as_ref())?;
@@ -185,6 +293,7 @@ impl PolymarketRtds {
                         return Ok(StreamOutcome::Resubscribe);
                     }
                     current_assets = latest;
+                    self.retry_pending_rebuilds();
                     let now_ms = now_ms().unwrap_or(0);
                     for asset_id in &current_assets {
                         let last_update = self.orderbook.last_update_ms(asset_id).await?;
@@ -205,11 +314,16 @@ impl PolymarketRtds {
                 message = reader.next() => {
                     match message {
                         Some(Ok(Message::Text(text))) => {
+                            metrics::increment_oracle_message("polymarket_market");
                             match self.handle_message(&text).await {
-                                Ok(true) => {
-                                    last_event = tokio::time::Instant::now();
+                                Ok(applied) => {
+                                    if !applied.touched.is_empty() {
+                                        last_event = tokio::time::Instant::now();
+                                    }
+                                    for (asset_id, issue) in applied.issues {
+                                        self.handle_integrity_issue(&asset_id, issue).await;
+                                    }
                                 }
-                                Ok(false) => {}
                                 Err(error) => {
                                     tracing::warn!(?error, "failed to handle polymarket rtds message");
                                 }
@@ -230,31 +344,112 @@ impl PolymarketRtds {
         Ok(StreamOutcome::Backoff)
     }
 
-    async fn handle_message(&self, text: &str) -> Result<bool> {
-        let mut updated = false;
-        if let Some(changes) = parse_price_change_event(text)? {
-            for change in changes {
-                self.orderbook
-                    .apply_level(&change.asset_id, change.side, &change.price, change.size)
-                    .await?;
+    async fn handle_message(&self, text: &str) -> Result<AppliedMarketMessage> {
+        if let Some(recorder) = self.recorder.as_ref() {
+            recorder.record(ReplayEvent::MarketWs(text.to_string()));
+        }
+        let applied = apply_market_message(&self.orderbook, text).await?;
+        if !applied.touched.is_empty() {
+            if let Some(event_ms) = parse_event_timestamp_ms(text) {
+                let lag_ms = now_ms()?.saturating_sub(event_ms) as f64;
+                for asset_id in &applied.touched {
+                    metrics::record_orderbook_update_lag_ms(asset_id, lag_ms);
+                }
             }
-            updated = true;
         }
-        if let Some(trade) = parse_last_trade_event(text)? {
-            let _ = self
-                .orderbook
-                .set_last_trade_price(&trade.asset_id, trade.price, trade.timestamp_ms)
-                .await;
-            updated = true;
+        Ok(applied)
+    }
+}
+
+/// Result of applying one market-channel frame.
+#[derive(Debug, Default)]
+pub struct AppliedMarketMessage {
+    /// Token ids whose book or last trade changed.
+    pub touched: Vec<String>,
+    /// Tokens whose book failed validation, at most one issue each.
+    pub issues: Vec<(String, BookIntegrityIssue)>,
+}
+
+impl AppliedMarketMessage {
+    fn touch(&mut self, asset_id: &str) {
+        if !self.touched.iter().any(|id| id == asset_id) {
+            self.touched.push(asset_id.to_string());
+        }
+    }
+
+    fn flag(&mut self, asset_id: &str, issue: BookIntegrityIssue) {
+        if !self.is_flagged(asset_id) {
+            self.issues.push((asset_id.to_string(), issue));
         }
-        Ok(updated)
+    }
+
+    fn is_flagged(&self, asset_id: &str) -> bool {
+        self.issues.iter().any(|(id, _)| id == asset_id)
     }
 }
 
+/// Applies a raw market-channel frame to the book store and validates the books it touched.
+pub async fn apply_market_message(
+    orderbook: &OrderBookStore,
+    text: &str,
+) -> Result<AppliedMarketMessage> {
+    let mut applied = AppliedMarketMessage::default();
+    if let Some(changes) = parse_price_change_event(text)? {
+        let mut server_tops: HashMap<String, (Option<f64>, Option<f64>)> = HashMap::new();
+        let mut batches: HashMap<&str, Vec<(BookSide, &str, f64)>> = HashMap::new();
+        for change in &changes {
+            if change.size < 0.0 {
+                applied.flag(&change.asset_id, BookIntegrityIssue::NegativeSize);
+                continue;
+            }
+            batches.entry(change.asset_id.as_str()).or_default().push((
+                change.side,
+                change.price.as_str(),
+                change.size,
+            ));
+            if change.best_bid.is_some() || change.best_ask.is_some() {
+                server_tops.insert(change.asset_id.clone(), (change.best_bid, change.best_ask));
+            }
+            applied.touch(&change.asset_id);
+        }
+        for asset_id in &applied.touched {
+            if let Some(batch) = batches.get(asset_id.as_str()) {
+                orderbook.apply_levels(asset_id, batch).await?;
+            }
+        }
+        for asset_id in applied.touched.clone() {
+            if applied.is_flagged(&asset_id) {
+                continue;
+            }
+            let server_top = server_tops.get(&asset_id).copied();
+            if let Some(issue) = orderbook.check_integrity(&asset_id, server_top).await? {
+                applied.flag(&asset_id, issue);
+            }
+        }
+    }
+    if let Some(book) = parse_book_event(text)? {
+        orderbook
+            .apply_snapshot(&book.asset_id, &book.snapshot.bids, &book.snapshot.asks)
+            .await?;
+        match orderbook.check_integrity(&book.asset_id, None).await? {
+            Some(issue) => applied.flag(&book.asset_id, issue),
+            None => orderbook.clear_degraded(&book.asset_id).await?,
+        }
+        applied.touch(&book.asset_id);
+    }
+    if let Some(trade) = parse_last_trade_event(text)? {
+        let _ = orderbook
+            .set_last_trade_price(&trade.asset_id, trade.price, trade.timestamp_ms)
+            .await;
+        applied.touch(&trade.asset_id);
+    }
+    Ok(applied)
+}
+
 #[derive(Debug)]
-struct OrderBookSnapshot {
-    bids: Vec<OrderBookLevel>,
-    asks: Vec<OrderBookLevel>,
+pub struct OrderBookSnapshot {
+    pub bids: Vec<OrderBookLevel>,
+    pub asks: Vec<OrderBookLevel>,
 }
 
 #[derive(Debug)]
@@ -263,6 +458,14 @@ struct PriceChange {
     price: String,
     size: f64,
     side: BookSide,
+    best_bid: Option<f64>,
+    best_ask: Option<f64>,
+}
+
+#[derive(Debug)]
+struct BookEvent {
+    asset_id: String,
+    snapshot: OrderBookSnapshot,
 }
 
 #[derive(Debug)]
@@ -303,12 +506,48 @@ fn build_subscription_payload(
     Ok(serde_json::to_string(&payload)?)
 }
 
-fn parse_snapshot(value: &Value) -> Result<OrderBookSnapshot> {
+pub fn parse_snapshot(value: &Value) -> Result<OrderBookSnapshot> {
     let bids = parse_snapshot_levels(value.get("bids"), "snapshot bids")?;
     let asks = parse_snapshot_levels(value.get("asks"), "snapshot asks")?;
     Ok(OrderBookSnapshot { bids, asks })
 }
 
+/// Full-book frames; older feeds name the sides `buys`/`sells`.
+fn parse_book_event(text: &str) -> Result<Option<BookEvent>> {
+    let parsed: Value = serde_json::from_str(text)?;
+    let event_source = if parsed.get("event_type").is_some() {
+        &parsed
+    } else if let Some(payload) = parsed.get("payload") {
+        payload
+    } else {
+        return Ok(None);
+    };
+    if event_source
+        .get("event_type")
+        .and_then(|value| value.as_str())
+        != Some("book")
+    {
+        return Ok(None);
+    }
+    let asset_id = parse_string(event_source.get("asset_id"), "book asset_id")?;
+    let bids = parse_snapshot_levels(
+        event_source
+            .get("bids")
+            .or_else(|| event_source.get("buys")),
+        "book bids",
+    )?;
+    let asks = parse_snapshot_levels(
+        event_source
+            .get("asks")
+            .or_else(|| event_source.get("sells")),
+        "book asks",
+    )?;
+    Ok(Some(BookEvent {
+        asset_id,
+        snapshot: OrderBookSnapshot { bids, asks },
+    }))
+}
+
 fn parse_snapshot_levels(value: Option<&Value>, field: &str) -> Result<Vec<OrderBookLevel>> {
     let levels =
         value.ok_or_else(|| BankaiError::InvalidArgument(format!("snapshot missing {field}")))?;
@@ -414,12 +653,16 @@ fn parse_price_change(value: &Value, root_asset_id: Option<&str>) -> Result<Pric
     let price = parse_string(value.get("price"), "price_change price")?;
     let size = parse_numeric(value.get("size"), "price_change size")?;
     let side = parse_side(value.get("side"))?;
+    let best_bid = parse_numeric(value.get("best_bid"), "price_change best_bid").ok();
+    let best_ask = parse_numeric(value.get("best_ask"), "price_change best_ask").ok();
 
     Ok(PriceChange {
         asset_id: asset_id.to_string(),
         price,
         size,
         side,
+        best_bid,
+        best_ask,
     })
 }
 
@@ -434,6 +677,20 @@ fn parse_side(value: Option<&Value>) -> Result<BookSide> {
     }
 }
 
+/// Exchange timestamp of a market-channel frame, when it carries one.
+fn parse_event_timestamp_ms(text: &str) -> Option<u64> {
+    let parsed: Value = serde_json::from_str(text).ok()?;
+    let event_source = if parsed.get("event_type").is_some() {
+        &parsed
+    } else {
+        parsed.get("payload")?
+    };
+    parse_numeric(event_source.get("timestamp"), "timestamp")
+        .ok()
+        .filter(|value| *value > 0.0)
+        .map(|value| value.round() as u64)
+}
+
 fn parse_numeric(value: Option<&Value>, field: &str) -> Result<f64> {
     let value = value.ok_or_else(|| BankaiError::InvalidArgument(format!("{field} missing")))?;
     if let Some(number) = value.as_f64() {
@@ -468,3 +725,125 @@ fn asset_ids_changed(current: &[String], latest: &[String]) -> bool {
     latest_sorted.sort();
     current_sorted != latest_sorted
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::redis::RedisManager;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    const REST_BOOK: &str =
+        r#"{"bids":[{"price":"0.40","size":"100"}],"asks":[{"price":"0.42","size":"100"}]}"#;
+
+    /// Serves `REST_BOOK` for every request, standing in for the CLOB `/book` endpoint.
+    fn spawn_rest_book() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+        let addr = listener.local_addr().expect("addr");
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let mut request = [0u8; 2048];
+                let _ = stream.read(&mut request);
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{REST_BOOK}",
+                    REST_BOOK.len()
+                );
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+        format!("http://{addr}")
+    }
+
+    fn price_change(asset_id: &str, change: Value) -> String {
+        json!({"event_type": "price_change", "asset_id": asset_id, "price_changes": [change]})
+            .to_string()
+    }
+
+    /// Seeds a book from a WS `book` event, applies `frame`, and checks the flagged issue
+    /// degrades the token until the spawned REST rebuild restores it.
+    fn assert_degrades_and_recovers(asset_id: &str, frame: String, expected: BookIntegrityIssue) {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("runtime");
+        runtime.block_on(async {
+            let redis_url = std::env::var("BANKAI_TEST_REDIS_URL")
+                .unwrap_or_else(|_| "redis://127.0.0.1:6379/15".to_string());
+            let redis = RedisManager::new(&redis_url).await.expect("redis");
+            let orderbook = OrderBookStore::new(redis).with_local_book();
+            let config = PolymarketRtdsConfig::new(String::new(), spawn_rest_book(), Vec::new());
+            let rtds = Arc::new(PolymarketRtds::new(config, orderbook.clone()).expect("rtds"));
+
+            let book = json!({
+                "event_type": "book", "asset_id": asset_id,
+                "bids": [{"price": "0.30", "size": "10"}],
+                "asks": [{"price": "0.35", "size": "10"}]
+            });
+            let seeded = apply_market_message(&orderbook, &book.to_string())
+                .await
+                .expect("book event");
+            assert!(seeded.issues.is_empty());
+            assert_eq!(
+                orderbook.best_bid_ask(asset_id).await.unwrap(),
+                Some((0.30, 0.35))
+            );
+
+            let applied = apply_market_message(&orderbook, &frame)
+                .await
+                .expect("price change");
+            assert_eq!(applied.issues.len(), 1);
+            let (flagged, issue) = applied.issues[0].clone();
+            assert_eq!((flagged.as_str(), issue), (asset_id, expected));
+
+            rtds.handle_integrity_issue(&flagged, issue).await;
+            assert!(orderbook.is_degraded(asset_id).await.unwrap());
+            for _ in 0..100 {
+                if !orderbook.is_degraded(asset_id).await.unwrap() {
+                    break;
+                }
+                tokio::time::sleep(Duration::from_millis(20)).await;
+            }
+            assert!(!orderbook.is_degraded(asset_id).await.unwrap());
+            assert_eq!(
+                orderbook.best_bid_ask(asset_id).await.unwrap(),
+                Some((0.40, 0.42))
+            );
+        });
+    }
+
+    #[test]
+    #[ignore = "needs a scratch Redis at BANKAI_TEST_REDIS_URL"]
+    fn negative_size_degrades_and_recovers() {
+        let asset_id = "rtds-test-negative";
+        let frame = price_change(
+            asset_id,
+            json!({"price": "0.30", "size": "-1", "side": "BUY"}),
+        );
+        assert_degrades_and_recovers(asset_id, frame, BookIntegrityIssue::NegativeSize);
+    }
+
+    #[test]
+    #[ignore = "needs a scratch Redis at BANKAI_TEST_REDIS_URL"]
+    fn crossed_book_degrades_and_recovers() {
+        let asset_id = "rtds-test-crossed";
+        let frame = price_change(
+            asset_id,
+            json!({"price": "0.36", "size": "5", "side": "BUY"}),
+        );
+        assert_degrades_and_recovers(asset_id, frame, BookIntegrityIssue::Crossed);
+    }
+
+    #[test]
+    #[ignore = "needs a scratch Redis at BANKAI_TEST_REDIS_URL"]
+    fn top_of_book_mismatch_degrades_and_recovers() {
+        let asset_id = "rtds-test-top";
+        let frame = price_change(
+            asset_id,
+            json!({
+                "price": "0.29", "size": "5", "side": "BUY",
+                "best_bid": "0.31", "best_ask": "0.35"
+            }),
+        );
+        assert_degrades_and_recovers(asset_id, frame, BookIntegrityIssue::TopOfBookMismatch);
+    }
+}
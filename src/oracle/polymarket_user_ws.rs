@@ -8,6 +8,7 @@
  *
  * @notes
  * - Uses user channel with auth to reconcile fills in near real time.
+ * - Order updates are forwarded to the order lifecycle sweeper when attached.
  */
 use futures_util::{SinkExt, StreamExt};
 use serde::{Deserialize, Serialize};
@@ -17,7 +18,10 @@ use tokio_tungstenite::tungstenite::Message;
 
 use crate::accounting::keys::REALIZED_PNL_KEY;
 use crate::accounting::trade_events::{is_seen_trade, mark_seen_trade, record_realized_pnl_event};
+use crate::engine::backtest::ReplayEvent;
 use crate::error::Result;
+use crate::execution::order_sweeper::OrderLifecycleHandle;
+use crate::storage::recorder::RecorderHandle;
 use crate::storage::redis::RedisManager;
 use crate::telemetry::metrics;
 
@@ -41,6 +45,8 @@ pub struct PolymarketUserWs {
     config: PolymarketUserWsConfig,
     redis: RedisManager,
     wallet_key: String,
+    recorder: Option<RecorderHandle>,
+    lifecycle: Option<OrderLifecycleHandle>,
 }
 
 impl PolymarketUserWs {
@@ -49,9 +55,21 @@ impl PolymarketUserWs {
             config,
             redis,
             wallet_key,
+            recorder: None,
+            lifecycle: None,
         }
     }
 
+    pub fn with_recorder(mut self, recorder: RecorderHandle) -> Self {
+        self.recorder = Some(recorder);
+        self
+    }
+
+    pub fn with_order_lifecycle(mut self, lifecycle: OrderLifecycleHandle) -> Self {
+        self.lifecycle = Some(lifecycle);
+        self
+    }
+
     pub fn spawn(self) -> tokio::task::JoinHandle<()> {
         tokio::spawn(async move {
             if let Err(error) = self.run().await {
@@ -66,6 +84,7 @@ impl PolymarketUserWs {
                 Ok(_) => {}
                 Err(error) => tracing::warn!(?error, "polymarket user ws error"),
             }
+            metrics::increment_oracle_reconnect("polymarket_user");
             tokio::time::sleep(self.config.reconnect_delay).await;
         }
     }
@@ -93,6 +112,7 @@ impl PolymarketUserWs {
                 message = reader.next() => {
                     match message {
                         Some(Ok(Message::Text(text))) => {
+                            metrics::increment_oracle_message("polymarket_user");
                             if let Err(error) = self.handle_message(&text).await {
                                 tracing::warn!(?error, "failed to handle user ws message");
                             }
@@ -112,6 +132,9 @@ impl PolymarketUserWs {
     }
 
     async fn handle_message(&self, text: &str) -> Result<()> {
+        if let Some(recorder) = self.recorder.as_ref() {
+            recorder.record(ReplayEvent::UserWs(text.to_string()));
+        }
         let value: serde_json::Value = serde_json::from_str(text)?;
         let Some(event_type) = value.get("event_type").and_then(|v| v.as_str()) else {
             return Ok(());
@@ -184,6 +207,15 @@ impl PolymarketUserWs {
         } else {
             "OPEN"
         };
+        if let Some(lifecycle) = self.lifecycle.as_ref() {
+            let now = current_unix_timestamp() * 1000;
+            if matched > 0.0 {
+                lifecycle.order_matched(&order.id, matched, now);
+            }
+            if status == "CANCELLED" {
+                lifecycle.order_closed(&order.id, now);
+            }
+        }
         if status == "CANCELLED" {
             self.log_activity(format!(
                 "[ALERT] Order cancelled asset={} id={}",
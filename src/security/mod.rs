@@ -12,23 +12,36 @@
  * @notes
  * - Secrets are persisted only in encrypted form at rest.
  * - Decrypted secrets remain in memory as SecretString values and drop on exit.
+ * - `SecretsFile` keeps the path and password together so derived API credentials can be
+ *   written back without prompting again.
+ * - Version 2 files record their Argon2id parameters; version 1 files used the argon2 crate
+ *   defaults and are rewritten as version 2 (keeping those parameters) on the next update.
+ * - Files are replaced via a temporary file and rename, so a failed write never truncates them.
+ * - `provider` layers env, external-command and chained sources over the encrypted file.
  */
+pub mod provider;
+
 use aes_gcm::aead::rand_core::RngCore;
 use aes_gcm::aead::{Aead, KeyInit, OsRng};
 use aes_gcm::{Aes256Gcm, Nonce};
-use argon2::Argon2;
+use argon2::{Algorithm, Argon2, Params, Version};
 use base64::{engine::general_purpose, Engine as _};
 use secrecy::{ExposeSecret, SecretString};
 use serde::{Deserialize, Serialize};
 use std::{
-    fs,
+    fmt, fs,
     io::{self, Write},
-    path::Path,
+    path::{Path, PathBuf},
 };
 
+#[cfg(unix)]
+use std::os::unix::fs::OpenOptionsExt;
+
 use crate::error::{BankaiError, Result};
 
-const SECRETS_VERSION: u8 = 1;
+const SECRETS_VERSION: u8 = 2;
+const LEGACY_SECRETS_VERSION: u8 = 1;
+const KEY_LEN: usize = 32;
 const SALT_LEN: usize = 16;
 const NONCE_LEN: usize = 12;
 
@@ -39,12 +52,71 @@ const ENV_SECRETS_PASSWORD_FILE: &str = "BANKAI_SECRETS_PASSWORD_FILE";
 #[derive(Debug, Serialize, Deserialize)]
 pub struct EncryptedSecrets {
     version: u8,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    kdf: Option<KdfParams>,
     salt: String,
     nonce: String,
     ciphertext: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Argon2id cost parameters used to derive the file key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KdfParams {
+    pub m_cost_kib: u32,
+    pub t_cost: u32,
+    pub p_cost: u32,
+}
+
+impl KdfParams {
+    /// Parameters implied by version 1 files (argon2 crate defaults).
+    pub const LEGACY: Self = Self {
+        m_cost_kib: Params::DEFAULT_M_COST,
+        t_cost: Params::DEFAULT_T_COST,
+        p_cost: Params::DEFAULT_P_COST,
+    };
+
+    fn argon2(&self) -> Result<Argon2<'static>> {
+        let params = Params::new(self.m_cost_kib, self.t_cost, self.p_cost, Some(KEY_LEN))?;
+        Ok(Argon2::new(Algorithm::Argon2id, Version::V0x13, params))
+    }
+}
+
+impl Default for KdfParams {
+    fn default() -> Self {
+        Self {
+            m_cost_kib: 64 * 1024,
+            t_cost: 3,
+            p_cost: 1,
+        }
+    }
+}
+
+/// Format metadata readable without the password.
+#[derive(Debug, Clone, Copy)]
+pub struct SecretsFileInfo {
+    pub version: u8,
+    pub kdf: KdfParams,
+}
+
+impl SecretsFileInfo {
+    pub fn is_current(&self) -> bool {
+        self.version == SECRETS_VERSION
+    }
+}
+
+/// Field names accepted by [`SecretsPayload::field_mut`], in storage order.
+pub const SECRET_FIELDS: [&str; 5] = [
+    "polygon_private_key",
+    "polymarket_api_key",
+    "polymarket_api_secret",
+    "polymarket_api_passphrase",
+    "allora_api_key",
+];
+
+/// Fields the bot cannot start without; CLOB API credentials are re-derived from the key.
+pub const REQUIRED_SECRET_FIELDS: [&str; 1] = ["polygon_private_key"];
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SecretsPayload {
     pub polygon_private_key: Option<String>,
     pub polymarket_api_key: Option<String>,
@@ -53,6 +125,30 @@ pub struct SecretsPayload {
     pub allora_api_key: Option<String>,
 }
 
+impl SecretsPayload {
+    pub fn field(&self, name: &str) -> Option<&Option<String>> {
+        match name {
+            "polygon_private_key" => Some(&self.polygon_private_key),
+            "polymarket_api_key" => Some(&self.polymarket_api_key),
+            "polymarket_api_secret" => Some(&self.polymarket_api_secret),
+            "polymarket_api_passphrase" => Some(&self.polymarket_api_passphrase),
+            "allora_api_key" => Some(&self.allora_api_key),
+            _ => None,
+        }
+    }
+
+    pub fn field_mut(&mut self, name: &str) -> Option<&mut Option<String>> {
+        match name {
+            "polygon_private_key" => Some(&mut self.polygon_private_key),
+            "polymarket_api_key" => Some(&mut self.polymarket_api_key),
+            "polymarket_api_secret" => Some(&mut self.polymarket_api_secret),
+            "polymarket_api_passphrase" => Some(&mut self.polymarket_api_passphrase),
+            "allora_api_key" => Some(&mut self.allora_api_key),
+            _ => None,
+        }
+    }
+}
+
 pub struct Secrets {
     pub polygon_private_key: Option<SecretString>,
     pub polymarket_api_key: Option<SecretString>,
@@ -106,10 +202,28 @@ pub fn encrypt_to_file(
     password: &SecretString,
     payload: &SecretsPayload,
 ) -> Result<()> {
-    let encrypted = encrypt_payload(password, payload)?;
+    encrypt_to_file_with_kdf(path, password, payload, &KdfParams::default())
+}
+
+pub fn encrypt_to_file_with_kdf(
+    path: impl AsRef<Path>,
+    password: &SecretString,
+    payload: &SecretsPayload,
+    kdf: &KdfParams,
+) -> Result<()> {
+    let encrypted = encrypt_payload(password, payload, kdf)?;
     write_encrypted_file(path.as_ref(), &encrypted)
 }
 
+/// Reads the format version and KDF parameters without decrypting.
+pub fn read_secrets_info(path: impl AsRef<Path>) -> Result<SecretsFileInfo> {
+    let encrypted = read_encrypted_file(path.as_ref())?;
+    Ok(SecretsFileInfo {
+        version: encrypted.version,
+        kdf: kdf_params(&encrypted)?,
+    })
+}
+
 pub fn load_secrets(path: impl AsRef<Path>, password: &SecretString) -> Result<Secrets> {
     let encrypted = read_encrypted_file(path.as_ref())?;
     let payload = decrypt_payload(password, &encrypted)?;
@@ -121,13 +235,20 @@ pub fn load_secrets_interactive(path: impl AsRef<Path>) -> Result<Secrets> {
     load_secrets(path, &password)
 }
 
-/// Load encrypted secrets for runtime use.
+/// Load encrypted secrets for runtime use, resolving the password via
+/// [`resolve_secrets_password`].
+pub fn load_secrets_with_env_or_prompt(path: impl AsRef<Path>) -> Result<Secrets> {
+    let password = resolve_secrets_password()?;
+    load_secrets(path, &password)
+}
+
+/// Resolve the secrets password for runtime use.
 ///
 /// Resolution order:
 /// 1) `BANKAI_SECRETS_PASSWORD_FILE` (file content, trimmed of trailing newlines)
 /// 2) `BANKAI_SECRETS_PASSWORD` (raw value)
 /// 3) Interactive prompt on stdin/tty.
-pub fn load_secrets_with_env_or_prompt(path: impl AsRef<Path>) -> Result<Secrets> {
+pub fn resolve_secrets_password() -> Result<SecretString> {
     if let Ok(value) = std::env::var(ENV_SECRETS_PASSWORD_FILE) {
         let value = value.trim().to_string();
         if !value.is_empty() {
@@ -143,27 +264,85 @@ pub fn load_secrets_with_env_or_prompt(path: impl AsRef<Path>) -> Result<Secrets
                     "{ENV_SECRETS_PASSWORD_FILE} pointed to an empty password file"
                 )));
             }
-            return load_secrets(path, &SecretString::new(password));
+            return Ok(SecretString::new(password));
         }
     }
 
     if let Ok(value) = std::env::var(ENV_SECRETS_PASSWORD) {
         let password = value.trim().to_string();
         if !password.is_empty() {
-            return load_secrets(path, &SecretString::new(password));
+            return Ok(SecretString::new(password));
         }
     }
 
-    load_secrets_interactive(path)
+    prompt_password("Enter secrets password: ")
 }
 
-fn encrypt_payload(password: &SecretString, payload: &SecretsPayload) -> Result<EncryptedSecrets> {
+/// An encrypted secrets file paired with its password, for read-modify-write updates.
+#[derive(Clone)]
+pub struct SecretsFile {
+    path: PathBuf,
+    password: SecretString,
+}
+
+impl SecretsFile {
+    pub fn new(path: impl Into<PathBuf>, password: SecretString) -> Self {
+        Self {
+            path: path.into(),
+            password,
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn load(&self) -> Result<Secrets> {
+        load_secrets(&self.path, &self.password)
+    }
+
+    pub fn read_payload(&self) -> Result<SecretsPayload> {
+        let encrypted = read_encrypted_file(&self.path)?;
+        decrypt_payload(&self.password, &encrypted)
+    }
+
+    /// Decrypts, applies `update`, and re-encrypts under a fresh salt and nonce with the
+    /// file's existing KDF parameters, upgrading the format version if needed.
+    pub fn update(&self, update: impl FnOnce(&mut SecretsPayload)) -> Result<()> {
+        let encrypted = read_encrypted_file(&self.path)?;
+        let kdf = kdf_params(&encrypted)?;
+        let mut payload = decrypt_payload(&self.password, &encrypted)?;
+        update(&mut payload);
+        encrypt_to_file_with_kdf(&self.path, &self.password, &payload, &kdf)
+    }
+
+    /// Re-encrypts the file under `password` and `kdf`, returning the handle for the new password.
+    pub fn rotate_password(&self, password: SecretString, kdf: &KdfParams) -> Result<Self> {
+        let payload = self.read_payload()?;
+        encrypt_to_file_with_kdf(&self.path, &password, &payload, kdf)?;
+        Ok(Self::new(self.path.clone(), password))
+    }
+}
+
+impl fmt::Debug for SecretsFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SecretsFile")
+            .field("path", &self.path)
+            .finish_non_exhaustive()
+    }
+}
+
+fn encrypt_payload(
+    password: &SecretString,
+    payload: &SecretsPayload,
+    kdf: &KdfParams,
+) -> Result<EncryptedSecrets> {
     let mut salt = [0u8; SALT_LEN];
     let mut nonce_bytes = [0u8; NONCE_LEN];
     OsRng.fill_bytes(&mut salt);
     OsRng.fill_bytes(&mut nonce_bytes);
 
-    let key = derive_key(password, &salt)?;
+    let key = derive_key(password, &salt, kdf)?;
     let cipher = Aes256Gcm::new_from_slice(&key)
         .map_err(|_| BankaiError::Crypto("invalid key length".to_string()))?;
     let plaintext = serde_json::to_vec(payload)?;
@@ -173,6 +352,7 @@ fn encrypt_payload(password: &SecretString, payload: &SecretsPayload) -> Result<
 
     Ok(EncryptedSecrets {
         version: SECRETS_VERSION,
+        kdf: Some(*kdf),
         salt: general_purpose::STANDARD.encode(salt),
         nonce: general_purpose::STANDARD.encode(nonce_bytes),
         ciphertext: general_purpose::STANDARD.encode(ciphertext),
@@ -183,13 +363,7 @@ fn decrypt_payload(
     password: &SecretString,
     encrypted: &EncryptedSecrets,
 ) -> Result<SecretsPayload> {
-    if encrypted.version != SECRETS_VERSION {
-        return Err(BankaiError::InvalidArgument(format!(
-            "unsupported secrets version {}",
-            encrypted.version
-        )));
-    }
-
+    let kdf = kdf_params(encrypted)?;
     let salt = general_purpose::STANDARD.decode(&encrypted.salt)?;
     let nonce_bytes = general_purpose::STANDARD.decode(&encrypted.nonce)?;
     let ciphertext = general_purpose::STANDARD.decode(&encrypted.ciphertext)?;
@@ -205,7 +379,7 @@ fn decrypt_payload(
         ));
     }
 
-    let key = derive_key(password, &salt)?;
+    let key = derive_key(password, &salt, &kdf)?;
     let cipher = Aes256Gcm::new_from_slice(&key)
         .map_err(|_| BankaiError::Crypto("invalid key length".to_string()))?;
     let plaintext = cipher
@@ -215,9 +389,22 @@ fn decrypt_payload(
     Ok(serde_json::from_slice(&plaintext)?)
 }
 
-fn derive_key(password: &SecretString, salt: &[u8]) -> Result<[u8; 32]> {
-    let mut key = [0u8; 32];
-    Argon2::default().hash_password_into(password.expose_secret().as_bytes(), salt, &mut key)?;
+fn kdf_params(encrypted: &EncryptedSecrets) -> Result<KdfParams> {
+    match encrypted.version {
+        LEGACY_SECRETS_VERSION => Ok(KdfParams::LEGACY),
+        SECRETS_VERSION => encrypted.kdf.ok_or_else(|| {
+            BankaiError::InvalidArgument("secrets file is missing kdf parameters".to_string())
+        }),
+        version => Err(BankaiError::InvalidArgument(format!(
+            "unsupported secrets version {version}"
+        ))),
+    }
+}
+
+fn derive_key(password: &SecretString, salt: &[u8], kdf: &KdfParams) -> Result<[u8; KEY_LEN]> {
+    let mut key = [0u8; KEY_LEN];
+    kdf.argon2()?
+        .hash_password_into(password.expose_secret().as_bytes(), salt, &mut key)?;
     Ok(key)
 }
 
@@ -233,6 +420,97 @@ fn write_encrypted_file(path: &Path, encrypted: &EncryptedSecrets) -> Result<()>
             fs::create_dir_all(parent)?;
         }
     }
-    fs::write(path, serialized)?;
+    let mut temp_path = path.as_os_str().to_owned();
+    temp_path.push(".tmp");
+    let temp_path = PathBuf::from(temp_path);
+    // A leftover temp file could carry wider permissions, so always create a fresh one:
+    // owner-only from creation, and flushed to disk before it replaces the previous file.
+    match fs::remove_file(&temp_path) {
+        Err(error) if error.kind() != std::io::ErrorKind::NotFound => return Err(error.into()),
+        _ => {}
+    }
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    options.mode(0o600);
+    let mut file = options.open(&temp_path)?;
+    file.write_all(&serialized)?;
+    file.sync_all()?;
+    drop(file);
+    fs::rename(&temp_path, path)?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn payload() -> SecretsPayload {
+        SecretsPayload {
+            polygon_private_key: Some("key".to_string()),
+            polymarket_api_key: None,
+            polymarket_api_secret: None,
+            polymarket_api_passphrase: None,
+            allora_api_key: Some("allora".to_string()),
+        }
+    }
+
+    #[test]
+    fn legacy_file_is_upgraded_on_update() {
+        let path = std::env::temp_dir().join(format!("bankai-secrets-{}.enc", std::process::id()));
+        let password = SecretString::new("correct horse".to_string());
+        let mut expected = [0u8; KEY_LEN];
+        Argon2::default()
+            .hash_password_into(b"correct horse", b"bankai-salt-0001", &mut expected)
+            .unwrap();
+        let legacy_key = derive_key(&password, b"bankai-salt-0001", &KdfParams::LEGACY).unwrap();
+        assert_eq!(legacy_key, expected);
+
+        let mut legacy = encrypt_payload(&password, &payload(), &KdfParams::LEGACY).unwrap();
+        legacy.version = LEGACY_SECRETS_VERSION;
+        legacy.kdf = None;
+        write_encrypted_file(&path, &legacy).unwrap();
+
+        let file = SecretsFile::new(&path, password);
+        assert_eq!(
+            read_secrets_info(&path).unwrap().version,
+            LEGACY_SECRETS_VERSION
+        );
+        file.update(|payload| payload.polymarket_api_key = Some("api".to_string()))
+            .unwrap();
+
+        let info = read_secrets_info(&path).unwrap();
+        assert!(info.is_current());
+        assert_eq!(info.kdf, KdfParams::LEGACY);
+        let updated = file.read_payload().unwrap();
+        assert_eq!(updated.polymarket_api_key.as_deref(), Some("api"));
+        assert_eq!(updated.allora_api_key.as_deref(), Some("allora"));
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+        let _ = fs::remove_file(&path);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn leftover_temp_file_does_not_widen_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+        let path =
+            std::env::temp_dir().join(format!("bankai-secrets-tmp-{}.enc", std::process::id()));
+        let temp_path = PathBuf::from(format!("{}.tmp", path.display()));
+        fs::write(&temp_path, b"stale").unwrap();
+        fs::set_permissions(&temp_path, fs::Permissions::from_mode(0o644)).unwrap();
+
+        let password = SecretString::new("correct horse".to_string());
+        let encrypted = encrypt_payload(&password, &payload(), &KdfParams::LEGACY).unwrap();
+        write_encrypted_file(&path, &encrypted).unwrap();
+
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        assert!(!temp_path.exists());
+        let _ = fs::remove_file(&path);
+    }
+}
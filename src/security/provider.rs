@@ -0,0 +1,460 @@
+/**
+ * @description
+ * Pluggable secret sources that all produce the runtime `Secrets` struct.
+ *
+ * @dependencies
+ * - serde_json: external command output parsing
+ *
+ * @notes
+ * - `EncryptedFileProvider` wraps the AES-GCM secrets file; it is the only provider that can
+ *   persist derived API credentials.
+ * - `EnvProvider` reads one variable per field (prefix + upper-cased field name) for containers.
+ * - `CommandProvider` runs a program (e.g. a vault agent helper) that prints a JSON object keyed
+ *   by field name; stderr is never echoed because it may contain secret material.
+ * - `KeyringProvider` (feature `keyring`) reads one OS keyring entry per field.
+ * - `ProviderChain` merges field by field: the first provider that supplies a field wins, and
+ *   later providers are not consulted once the required fields are filled. A failing
+ *   provider is only fatal when the chain ends without the required fields.
+ */
+use std::io::Read;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+use crate::config::SecretsConfig;
+use crate::error::{BankaiError, Result};
+use crate::security::{
+    resolve_secrets_password, Secrets, SecretsFile, SecretsPayload, REQUIRED_SECRET_FIELDS,
+    SECRET_FIELDS,
+};
+
+const COMMAND_POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// A source of runtime secrets.
+pub trait SecretsProvider: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    /// Returns the fields this provider supplies; absent fields are `None`.
+    fn load_payload(&self) -> Result<SecretsPayload>;
+
+    fn load(&self) -> Result<Secrets> {
+        Ok(Secrets::from_payload(self.load_payload()?))
+    }
+
+    /// The writable secrets file behind this provider, if any.
+    fn secrets_file(&self) -> Option<&SecretsFile> {
+        None
+    }
+}
+
+/// The encrypted `secrets.enc` file, unlocked with the env/file/TTY password.
+pub struct EncryptedFileProvider {
+    path: PathBuf,
+    file: OnceLock<SecretsFile>,
+}
+
+impl EncryptedFileProvider {
+    pub fn new(file: SecretsFile) -> Self {
+        Self {
+            path: file.path().to_path_buf(),
+            file: OnceLock::from(file),
+        }
+    }
+
+    /// Defers [`resolve_secrets_password`] until the file is first read, so a chain that is
+    /// satisfied by earlier providers never prompts.
+    pub fn from_path(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            file: OnceLock::new(),
+        }
+    }
+
+    fn file(&self) -> Result<&SecretsFile> {
+        if let Some(file) = self.file.get() {
+            return Ok(file);
+        }
+        let password = resolve_secrets_password()?;
+        Ok(self
+            .file
+            .get_or_init(|| SecretsFile::new(self.path.clone(), password)))
+    }
+}
+
+impl SecretsProvider for EncryptedFileProvider {
+    fn name(&self) -> &'static str {
+        "file"
+    }
+
+    fn load_payload(&self) -> Result<SecretsPayload> {
+        self.file()?.read_payload()
+    }
+
+    /// Available once the file has been unlocked by `load_payload`.
+    fn secrets_file(&self) -> Option<&SecretsFile> {
+        self.file.get()
+    }
+}
+
+/// Plain environment variables named `{prefix}{FIELD}`, e.g. `BANKAI_POLYGON_PRIVATE_KEY`.
+pub struct EnvProvider {
+    prefix: String,
+}
+
+impl EnvProvider {
+    pub fn new(prefix: impl Into<String>) -> Self {
+        Self {
+            prefix: prefix.into(),
+        }
+    }
+
+    fn var_name(&self, field: &str) -> String {
+        format!("{}{}", self.prefix, field.to_ascii_uppercase())
+    }
+}
+
+impl SecretsProvider for EnvProvider {
+    fn name(&self) -> &'static str {
+        "env"
+    }
+
+    fn load_payload(&self) -> Result<SecretsPayload> {
+        let mut payload = SecretsPayload::default();
+        for field in SECRET_FIELDS {
+            let value = std::env::var(self.var_name(field))
+                .ok()
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty());
+            if let Some(slot) = payload.field_mut(field) {
+                *slot = value;
+            }
+        }
+        Ok(payload)
+    }
+}
+
+/// An external program that prints a JSON object of secret fields to stdout.
+pub struct CommandProvider {
+    program: String,
+    args: Vec<String>,
+    timeout: Duration,
+}
+
+impl CommandProvider {
+    pub fn new(program: String, args: Vec<String>, timeout: Duration) -> Self {
+        Self {
+            program,
+            args,
+            timeout,
+        }
+    }
+
+    /// Builds from `[program, args..]`.
+    pub fn from_argv(argv: &[String], timeout: Duration) -> Result<Self> {
+        let (program, args) = argv
+            .split_first()
+            .ok_or_else(|| BankaiError::InvalidArgument("secrets command is empty".to_string()))?;
+        Ok(Self::new(program.clone(), args.to_vec(), timeout))
+    }
+
+    fn run(&self) -> Result<Vec<u8>> {
+        let mut child = Command::new(&self.program)
+            .args(&self.args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|error| {
+                BankaiError::InvalidArgument(format!(
+                    "secrets command {} failed to start: {error}",
+                    self.program
+                ))
+            })?;
+        let mut stdout = child.stdout.take().ok_or_else(|| {
+            BankaiError::InvalidArgument("secrets command stdout unavailable".to_string())
+        })?;
+        let reader = std::thread::spawn(move || {
+            let mut output = Vec::new();
+            stdout.read_to_end(&mut output).map(|_| output)
+        });
+
+        let started = Instant::now();
+        let status = loop {
+            if let Some(status) = child.try_wait()? {
+                break status;
+            }
+            if started.elapsed() >= self.timeout {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(BankaiError::InvalidArgument(format!(
+                    "secrets command {} timed out after {} ms",
+                    self.program,
+                    self.timeout.as_millis()
+                )));
+            }
+            std::thread::sleep(COMMAND_POLL_INTERVAL);
+        };
+        let output = reader.join().map_err(|_| {
+            BankaiError::InvalidArgument("secrets command reader panicked".to_string())
+        })??;
+        if !status.success() {
+            return Err(BankaiError::InvalidArgument(format!(
+                "secrets command {} exited with {status}",
+                self.program
+            )));
+        }
+        Ok(output)
+    }
+}
+
+impl SecretsProvider for CommandProvider {
+    fn name(&self) -> &'static str {
+        "command"
+    }
+
+    fn load_payload(&self) -> Result<SecretsPayload> {
+        let output = self.run()?;
+        serde_json::from_slice(&output).map_err(|_| {
+            BankaiError::InvalidArgument(format!(
+                "secrets command {} did not print a JSON object of secret fields",
+                self.program
+            ))
+        })
+    }
+}
+
+/// OS keyring entries under one service, with the secret field name as the user.
+#[cfg(feature = "keyring")]
+pub struct KeyringProvider {
+    service: String,
+}
+
+#[cfg(feature = "keyring")]
+impl KeyringProvider {
+    pub fn new(service: impl Into<String>) -> Self {
+        Self {
+            service: service.into(),
+        }
+    }
+}
+
+#[cfg(feature = "keyring")]
+impl SecretsProvider for KeyringProvider {
+    fn name(&self) -> &'static str {
+        "keyring"
+    }
+
+    fn load_payload(&self) -> Result<SecretsPayload> {
+        let mut payload = SecretsPayload::default();
+        for field in SECRET_FIELDS {
+            let entry = keyring::Entry::new(&self.service, field).map_err(|error| {
+                BankaiError::InvalidArgument(format!("keyring entry {field} invalid: {error}"))
+            })?;
+            let value = match entry.get_password() {
+                Ok(value) => Some(value.trim().to_string()).filter(|value| !value.is_empty()),
+                Err(keyring::Error::NoEntry) => None,
+                Err(error) => {
+                    return Err(BankaiError::InvalidArgument(format!(
+                        "keyring read for {field} failed: {error}"
+                    )))
+                }
+            };
+            if let Some(slot) = payload.field_mut(field) {
+                *slot = value;
+            }
+        }
+        Ok(payload)
+    }
+}
+
+/// Ordered providers merged field by field; earlier providers take precedence.
+#[derive(Default)]
+pub struct ProviderChain {
+    providers: Vec<Box<dyn SecretsProvider>>,
+}
+
+impl ProviderChain {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_provider(mut self, provider: impl SecretsProvider + 'static) -> Self {
+        self.providers.push(Box::new(provider));
+        self
+    }
+
+    /// Builds the chain from `secrets.providers`; no provider is read until `load_payload`.
+    pub fn from_config(config: &SecretsConfig) -> Result<Self> {
+        if config.providers.is_empty() {
+            return Err(BankaiError::InvalidArgument(
+                "secrets.providers must list at least one provider".to_string(),
+            ));
+        }
+        let mut chain = Self::new();
+        for name in &config.providers {
+            chain = match name.trim() {
+                "file" => chain.with_provider(EncryptedFileProvider::from_path(&config.file_path)),
+                "env" => chain.with_provider(EnvProvider::new(config.env_prefix.clone())),
+                "command" => chain.with_provider(CommandProvider::from_argv(
+                    &config.command,
+                    Duration::from_millis(config.command_timeout_ms),
+                )?),
+                #[cfg(feature = "keyring")]
+                "keyring" => {
+                    chain.with_provider(KeyringProvider::new(config.keyring_service.clone()))
+                }
+                #[cfg(not(feature = "keyring"))]
+                "keyring" => {
+                    return Err(BankaiError::InvalidArgument(
+                        "secrets provider keyring requires building with --features keyring"
+                            .to_string(),
+                    ))
+                }
+                other => {
+                    return Err(BankaiError::InvalidArgument(format!(
+                        "unknown secrets provider {other}; expected file, env, command or keyring"
+                    )))
+                }
+            };
+        }
+        Ok(chain)
+    }
+
+    /// Provider names in precedence order, e.g. `command,env`.
+    pub fn names(&self) -> String {
+        self.providers
+            .iter()
+            .map(|provider| provider.name())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+impl SecretsProvider for ProviderChain {
+    fn name(&self) -> &'static str {
+        "chain"
+    }
+
+    fn load_payload(&self) -> Result<SecretsPayload> {
+        let has_required = |payload: &SecretsPayload| {
+            REQUIRED_SECRET_FIELDS
+                .iter()
+                .all(|field| payload.field(field).is_some_and(Option::is_some))
+        };
+        let mut merged = SecretsPayload::default();
+        let mut first_error = None;
+        for provider in &self.providers {
+            if has_required(&merged) {
+                break;
+            }
+            let payload = match provider.load_payload() {
+                Ok(payload) => payload,
+                Err(error) => {
+                    tracing::warn!(
+                        provider = provider.name(),
+                        ?error,
+                        "secrets provider failed; trying the next one"
+                    );
+                    first_error.get_or_insert(error);
+                    continue;
+                }
+            };
+            for field in SECRET_FIELDS {
+                let value = payload.field(field).cloned().flatten();
+                if let Some(slot) = merged.field_mut(field) {
+                    if slot.is_none() {
+                        *slot = value;
+                    }
+                }
+            }
+        }
+        match first_error {
+            Some(error) if !has_required(&merged) => Err(error),
+            _ => Ok(merged),
+        }
+    }
+
+    fn secrets_file(&self) -> Option<&SecretsFile> {
+        self.providers
+            .iter()
+            .find_map(|provider| provider.secrets_file())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn echo_provider(json: &str) -> CommandProvider {
+        CommandProvider::new(
+            "sh".to_string(),
+            vec!["-c".to_string(), format!("echo '{json}'")],
+            Duration::from_secs(5),
+        )
+    }
+
+    #[test]
+    fn chain_prefers_earlier_providers_per_field() {
+        let command = echo_provider(r#"{"allora_api_key":"from-command","unknown":"ignored"}"#);
+        let prefix = format!("BANKAI_TEST_CHAIN_PREFERS_{}_", std::process::id());
+        std::env::set_var(format!("{prefix}POLYGON_PRIVATE_KEY"), "from-env");
+        std::env::set_var(format!("{prefix}ALLORA_API_KEY"), "allora-env");
+
+        let chain = ProviderChain::new()
+            .with_provider(command)
+            .with_provider(EnvProvider::new(prefix.clone()));
+        let payload = chain.load_payload().expect("payload");
+        assert_eq!(payload.polygon_private_key.as_deref(), Some("from-env"));
+        assert_eq!(payload.allora_api_key.as_deref(), Some("from-command"));
+        assert!(payload.polymarket_api_key.is_none());
+        assert_eq!(chain.names(), "command,env");
+        assert!(chain.secrets_file().is_none());
+
+        std::env::remove_var(format!("{prefix}POLYGON_PRIVATE_KEY"));
+        std::env::remove_var(format!("{prefix}ALLORA_API_KEY"));
+    }
+
+    #[test]
+    fn chain_stops_once_required_fields_are_present() {
+        let blocking = CommandProvider::new(
+            "sleep".to_string(),
+            vec!["5".to_string()],
+            Duration::from_secs(10),
+        );
+        let chain = ProviderChain::new()
+            .with_provider(echo_provider(r#"{"polygon_private_key":"value"}"#))
+            .with_provider(blocking);
+        let started = Instant::now();
+        let payload = chain.load_payload().expect("payload");
+        assert!(started.elapsed() < Duration::from_secs(2));
+        assert_eq!(payload.polygon_private_key.as_deref(), Some("value"));
+        assert!(payload.allora_api_key.is_none());
+    }
+
+    #[test]
+    fn provider_failure_is_fatal_only_without_required_fields() {
+        let failing =
+            || CommandProvider::new("false".to_string(), Vec::new(), Duration::from_secs(5));
+        let recovered = ProviderChain::new()
+            .with_provider(failing())
+            .with_provider(echo_provider(r#"{"polygon_private_key":"value"}"#));
+        let payload = recovered.load_payload().expect("payload");
+        assert_eq!(payload.polygon_private_key.as_deref(), Some("value"));
+
+        let missing = ProviderChain::new()
+            .with_provider(echo_provider(r#"{"allora_api_key":"value"}"#))
+            .with_provider(failing());
+        assert!(missing.load_payload().is_err());
+    }
+
+    #[test]
+    fn command_provider_times_out() {
+        let command = CommandProvider::new(
+            "sleep".to_string(),
+            vec!["5".to_string()],
+            Duration::from_millis(100),
+        );
+        assert!(command.load_payload().is_err());
+    }
+}
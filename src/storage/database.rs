@@ -25,6 +25,16 @@ pub struct TradeExecutionLog {
     pub metadata: Option<Value>,
 }
 
+#[derive(Debug, Clone)]
+pub struct RegretMetricLog {
+    pub market_id: String,
+    pub strategy: String,
+    pub expected_ev: f64,
+    pub actual_pnl: Option<f64>,
+    pub regret_value: f64,
+    pub metadata: Option<Value>,
+}
+
 #[derive(Clone)]
 pub struct DatabaseManager {
     pool: PgPool,
@@ -80,4 +90,37 @@ impl DatabaseManager {
 
         Ok(())
     }
+
+    /// Persist a regret measurement to the `regret_metrics` hypertable.
+    pub async fn log_regret_metric(&self, entry: &RegretMetricLog) -> Result<()> {
+        let metadata = entry
+            .metadata
+            .as_ref()
+            .map(serde_json::to_string)
+            .transpose()?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO regret_metrics (
+                market_id,
+                strategy,
+                expected_ev,
+                actual_pnl,
+                regret_value,
+                metadata
+            )
+            VALUES ($1, $2, $3, $4, $5, $6::jsonb)
+            "#,
+        )
+        .bind(&entry.market_id)
+        .bind(&entry.strategy)
+        .bind(entry.expected_ev)
+        .bind(entry.actual_pnl)
+        .bind(entry.regret_value)
+        .bind(metadata)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
 }
@@ -10,4 +10,5 @@
  */
 pub mod database;
 pub mod orderbook;
+pub mod recorder;
 pub mod redis;
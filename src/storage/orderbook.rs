@@ -1,18 +1,55 @@
 /**
  * @description
- * Redis-backed order book maintenance for Polymarket RTDS updates.
+ * Order book maintenance for Polymarket RTDS updates, in memory with a Redis mirror.
  *
  * @dependencies
+ * - arc-swap: lock-free per-token book snapshots
  * - redis: async commands for ZSET/HASH writes
  *
  * @notes
  * - ZSETs store price ordering, depth hash stores aggregate size per price level.
  * - Levels are removed when size <= 0 to keep Redis state minimal.
+ * - With `with_local_book`, the owning process keeps BTreeMap books in memory and reads
+ *   never touch Redis; `spawn_mirror` rewrites dirty books to Redis in batched MULTI
+ *   pipelines for the UI and other processes. Without it, Redis is read and written directly.
+ * - Integrity failures mark a token degraded (in memory and in Redis, so other processes
+ *   see it) until the feed owner rebuilds the book from a fresh snapshot.
  */
+use arc_swap::ArcSwap;
 use redis::AsyncCommands;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::task::JoinHandle;
 
 use crate::error::{BankaiError, Result};
-use crate::storage::redis::RedisManager;
+use crate::storage::redis::{orderbook_ts_key, RedisManager};
+
+/// Prices are keyed in micro-units so BTreeMap ordering matches numeric ordering.
+const PRICE_TICKS_PER_UNIT: f64 = 1_000_000.0;
+const LEVEL_TOLERANCE: f64 = 1e-9;
+
+/// Why a token's book can no longer be trusted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BookIntegrityIssue {
+    /// Best bid at or above best ask.
+    Crossed,
+    /// The feed sent a negative level size.
+    NegativeSize,
+    /// Local best bid/ask differs from the `best_bid`/`best_ask` the feed reported.
+    TopOfBookMismatch,
+}
+
+impl BookIntegrityIssue {
+    pub fn label(self) -> &'static str {
+        match self {
+            BookIntegrityIssue::Crossed => "crossed",
+            BookIntegrityIssue::NegativeSize => "negative_size",
+            BookIntegrityIssue::TopOfBookMismatch => "top_of_book_mismatch",
+        }
+    }
+}
 
 #[derive(Debug, Clone, Copy)]
 pub enum BookSide {
@@ -20,7 +57,7 @@ pub enum BookSide {
     Ask,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct OrderBookLevel {
     pub price: String,
     pub size: f64,
@@ -33,14 +70,213 @@ pub struct VwapQuote {
     pub notional: f64,
 }
 
-#[derive(Clone, Debug)]
+/// One token's in-memory book; `sequence` increments on every applied change.
+#[derive(Debug, Clone, Default)]
+pub struct TokenBook {
+    bids: BTreeMap<u64, OrderBookLevel>,
+    asks: BTreeMap<u64, OrderBookLevel>,
+    pub sequence: u64,
+    pub updated_at_ms: u64,
+}
+
+impl TokenBook {
+    fn side_mut(&mut self, side: BookSide) -> &mut BTreeMap<u64, OrderBookLevel> {
+        match side {
+            BookSide::Bid => &mut self.bids,
+            BookSide::Ask => &mut self.asks,
+        }
+    }
+
+    fn set_level(&mut self, side: BookSide, ticks: u64, price: &str, size: f64) {
+        let levels = self.side_mut(side);
+        if size <= 0.0 {
+            levels.remove(&ticks);
+        } else {
+            levels.insert(
+                ticks,
+                OrderBookLevel {
+                    price: price.to_string(),
+                    size,
+                },
+            );
+        }
+    }
+
+    /// Best-first levels: bids descending, asks ascending.
+    pub fn top_levels(&self, side: BookSide, limit: usize) -> Vec<OrderBookLevel> {
+        match side {
+            BookSide::Bid => self.bids.values().rev().take(limit).cloned().collect(),
+            BookSide::Ask => self.asks.values().take(limit).cloned().collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bids.is_empty() && self.asks.is_empty()
+    }
+
+    pub fn best_price(&self, side: BookSide) -> Option<f64> {
+        let level = match side {
+            BookSide::Bid => self.bids.values().next_back(),
+            BookSide::Ask => self.asks.values().next(),
+        };
+        level.and_then(|level| level.price.parse::<f64>().ok())
+    }
+
+    /// Best bid and ask from this snapshot, when both sides are quoted.
+    pub fn best_bid_ask(&self) -> Option<(f64, f64)> {
+        quoted_pair(
+            self.best_price(BookSide::Bid),
+            self.best_price(BookSide::Ask),
+        )
+    }
+}
+
+#[derive(Default)]
+struct LocalBooks {
+    books: ArcSwap<HashMap<String, Arc<ArcSwap<TokenBook>>>>,
+    dirty: Mutex<HashSet<String>>,
+    degraded: Mutex<HashSet<String>>,
+}
+
+impl LocalBooks {
+    fn get(&self, token_id: &str) -> Option<Arc<TokenBook>> {
+        self.books.load().get(token_id).map(|book| book.load_full())
+    }
+
+    fn slot(&self, token_id: &str) -> Arc<ArcSwap<TokenBook>> {
+        if let Some(slot) = self.books.load().get(token_id) {
+            return slot.clone();
+        }
+        let slot = Arc::new(ArcSwap::from_pointee(TokenBook::default()));
+        self.books.rcu(|books| {
+            let mut next = HashMap::clone(books);
+            next.entry(token_id.to_string())
+                .or_insert_with(|| slot.clone());
+            next
+        });
+        self.books.load().get(token_id).cloned().unwrap_or(slot)
+    }
+
+    fn update(&self, token_id: &str, apply: impl Fn(&mut TokenBook)) {
+        let updated_at_ms = now_ms().unwrap_or(0);
+        self.slot(token_id).rcu(|book| {
+            let mut next = TokenBook::clone(book);
+            apply(&mut next);
+            next.sequence += 1;
+            next.updated_at_ms = updated_at_ms;
+            next
+        });
+        self.mark_dirty(token_id);
+    }
+
+    fn mark_dirty(&self, token_id: &str) {
+        if let Ok(mut dirty) = self.dirty.lock() {
+            dirty.insert(token_id.to_string());
+        }
+    }
+
+    fn take_dirty(&self) -> Vec<String> {
+        match self.dirty.lock() {
+            Ok(mut dirty) => dirty.drain().collect(),
+            Err(_) => Vec::new(),
+        }
+    }
+}
+
+#[derive(Clone)]
 pub struct OrderBookStore {
     redis: RedisManager,
+    local: Option<Arc<LocalBooks>>,
+}
+
+impl std::fmt::Debug for OrderBookStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OrderBookStore")
+            .field("redis", &self.redis)
+            .field("local", &self.local.is_some())
+            .finish()
+    }
 }
 
 impl OrderBookStore {
     pub fn new(redis: RedisManager) -> Self {
-        Self { redis }
+        Self { redis, local: None }
+    }
+
+    /// Keeps books in process memory; clones share the same books.
+    pub fn with_local_book(mut self) -> Self {
+        self.local = Some(Arc::new(LocalBooks::default()));
+        self
+    }
+
+    /// Lock-free snapshot of a token's in-memory book.
+    pub fn local_book(&self, token_id: &str) -> Option<Arc<TokenBook>> {
+        self.local.as_ref()?.get(token_id)
+    }
+
+    /// Synchronous best bid/ask from the in-memory book; `None` without a local book.
+    pub fn local_best_bid_ask(&self, token_id: &str) -> Option<(f64, f64)> {
+        self.local_book(token_id)?.best_bid_ask()
+    }
+
+    /// Periodically rewrites dirty in-memory books to Redis; a no-op without a local book.
+    pub fn spawn_mirror(&self, interval: Duration) -> JoinHandle<()> {
+        let store = self.clone();
+        tokio::spawn(async move {
+            let Some(local) = store.local.clone() else {
+                return;
+            };
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                let dirty = local.take_dirty();
+                if dirty.is_empty() {
+                    continue;
+                }
+                if let Err(error) = store.mirror_books(&local, &dirty).await {
+                    tracing::warn!(?error, tokens = dirty.len(), "order book mirror failed");
+                    for token_id in &dirty {
+                        local.mark_dirty(token_id);
+                    }
+                }
+            }
+        })
+    }
+
+    async fn mirror_books(&self, local: &LocalBooks, token_ids: &[String]) -> Result<()> {
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+        for token_id in token_ids {
+            let bids_key = bids_key(token_id);
+            let asks_key = asks_key(token_id);
+            let depth_key = depth_key(token_id);
+            pipe.del(vec![
+                bids_key.as_str(),
+                asks_key.as_str(),
+                depth_key.as_str(),
+            ])
+            .ignore();
+            let Some(book) = local.get(token_id) else {
+                continue;
+            };
+            for (key, levels) in [(&bids_key, &book.bids), (&asks_key, &book.asks)] {
+                for (ticks, level) in levels.iter() {
+                    pipe.zadd(key, &level.price, *ticks as f64 / PRICE_TICKS_PER_UNIT)
+                        .ignore();
+                    pipe.hset(&depth_key, &level.price, level.size).ignore();
+                }
+            }
+            pipe.hset(
+                orderbook_ts_key(token_id),
+                "updatedAtMs",
+                book.updated_at_ms as i64,
+            )
+            .ignore();
+        }
+        let mut conn = self.redis.connection();
+        pipe.query_async::<_, ()>(&mut conn).await?;
+        Ok(())
     }
 
     pub async fn load_polymarket_asset_ids(&self) -> Result<Vec<String>> {
@@ -48,6 +284,13 @@ impl OrderBookStore {
     }
 
     pub async fn reset_book(&self, token_id: &str) -> Result<()> {
+        if let Some(local) = self.local.as_ref() {
+            local.update(token_id, |book| {
+                book.bids.clear();
+                book.asks.clear();
+            });
+            return Ok(());
+        }
         let bids_key = bids_key(token_id);
         let asks_key = asks_key(token_id);
         let depth_key = depth_key(token_id);
@@ -63,6 +306,21 @@ impl OrderBookStore {
         bids: &[OrderBookLevel],
         asks: &[OrderBookLevel],
     ) -> Result<()> {
+        if let Some(local) = self.local.as_ref() {
+            let bids = parse_levels(bids)?;
+            let asks = parse_levels(asks)?;
+            local.update(token_id, |book| {
+                book.bids.clear();
+                book.asks.clear();
+                for (ticks, level) in &bids {
+                    book.set_level(BookSide::Bid, *ticks, &level.price, level.size);
+                }
+                for (ticks, level) in &asks {
+                    book.set_level(BookSide::Ask, *ticks, &level.price, level.size);
+                }
+            });
+            return Ok(());
+        }
         self.reset_book(token_id).await?;
 
         for level in bids {
@@ -88,6 +346,11 @@ impl OrderBookStore {
         size: f64,
     ) -> Result<()> {
         let score = parse_price_score(price)?;
+        if let Some(local) = self.local.as_ref() {
+            let ticks = price_ticks(score);
+            local.update(token_id, |book| book.set_level(side, ticks, price, size));
+            return Ok(());
+        }
         let zset_key = match side {
             BookSide::Bid => bids_key(token_id),
             BookSide::Ask => asks_key(token_id),
@@ -109,6 +372,30 @@ impl OrderBookStore {
         Ok(())
     }
 
+    /// Applies one token's level changes from a frame; the local book clones once per batch.
+    pub async fn apply_levels(
+        &self,
+        token_id: &str,
+        changes: &[(BookSide, &str, f64)],
+    ) -> Result<()> {
+        if let Some(local) = self.local.as_ref() {
+            let mut parsed = Vec::with_capacity(changes.len());
+            for (side, price, size) in changes {
+                parsed.push((*side, price_ticks(parse_price_score(price)?), *price, *size));
+            }
+            local.update(token_id, |book| {
+                for (side, ticks, price, size) in &parsed {
+                    book.set_level(*side, *ticks, price, *size);
+                }
+            });
+            return Ok(());
+        }
+        for (side, price, size) in changes {
+            self.apply_level(token_id, *side, price, *size).await?;
+        }
+        Ok(())
+    }
+
     pub async fn best_level(
         &self,
         token_id: &str,
@@ -119,18 +406,8 @@ impl OrderBookStore {
     }
 
     pub async fn best_bid_ask(&self, token_id: &str) -> Result<Option<(f64, f64)>> {
-        let bid = self
-            .best_level(token_id, BookSide::Bid)
-            .await?
-            .and_then(|level| level.price.parse::<f64>().ok());
-        let ask = self
-            .best_level(token_id, BookSide::Ask)
-            .await?
-            .and_then(|level| level.price.parse::<f64>().ok());
-        match (bid, ask) {
-            (Some(bid), Some(ask)) if bid > 0.0 && ask > 0.0 => Ok(Some((bid, ask))),
-            _ => Ok(None),
-        }
+        let (bid, ask) = self.best_prices(token_id).await?;
+        Ok(quoted_pair(bid, ask))
     }
 
     pub async fn set_last_trade_price(
@@ -157,6 +434,9 @@ impl OrderBookStore {
         if limit == 0 {
             return Ok(Vec::new());
         }
+        if let Some(book) = self.local_book(token_id) {
+            return Ok(book.top_levels(side, limit));
+        }
         let key = match side {
             BookSide::Bid => bids_key(token_id),
             BookSide::Ask => asks_key(token_id),
@@ -186,22 +466,82 @@ impl OrderBookStore {
     }
 
     pub async fn mid_price(&self, token_id: &str) -> Result<Option<f64>> {
-        let bid = self.best_level(token_id, BookSide::Bid).await?;
-        let ask = self.best_level(token_id, BookSide::Ask).await?;
-        match (bid, ask) {
-            (Some(bid), Some(ask)) => {
-                let bid = bid.price.parse::<f64>().ok();
-                let ask = ask.price.parse::<f64>().ok();
-                match (bid, ask) {
-                    (Some(bid), Some(ask)) if bid > 0.0 && ask > 0.0 => Ok(Some((bid + ask) / 2.0)),
-                    _ => Ok(None),
-                }
+        Ok(self
+            .best_bid_ask(token_id)
+            .await?
+            .map(|(bid, ask)| (bid + ask) / 2.0))
+    }
+
+    /// Checks the book is uncrossed and, when the feed reported one, matches its top of book.
+    pub async fn check_integrity(
+        &self,
+        token_id: &str,
+        server_top: Option<(Option<f64>, Option<f64>)>,
+    ) -> Result<Option<BookIntegrityIssue>> {
+        let (bid, ask) = self.best_prices(token_id).await?;
+        if let (Some(bid), Some(ask)) = (bid, ask) {
+            if bid >= ask {
+                return Ok(Some(BookIntegrityIssue::Crossed));
             }
-            _ => Ok(None),
         }
+        if let Some((server_bid, server_ask)) = server_top {
+            if !same_price(bid, server_bid) || !same_price(ask, server_ask) {
+                return Ok(Some(BookIntegrityIssue::TopOfBookMismatch));
+            }
+        }
+        Ok(None)
+    }
+
+    pub async fn mark_degraded(&self, token_id: &str, issue: BookIntegrityIssue) -> Result<()> {
+        if let Some(local) = self.local.as_ref() {
+            if let Ok(mut degraded) = local.degraded.lock() {
+                degraded.insert(token_id.to_string());
+            }
+        }
+        self.redis
+            .set_orderbook_degraded(token_id, issue.label())
+            .await
+    }
+
+    pub async fn clear_degraded(&self, token_id: &str) -> Result<()> {
+        if let Some(local) = self.local.as_ref() {
+            if let Ok(mut degraded) = local.degraded.lock() {
+                degraded.remove(token_id);
+            }
+        }
+        self.redis.clear_orderbook_degraded(token_id).await
+    }
+
+    pub async fn is_degraded(&self, token_id: &str) -> Result<bool> {
+        if let Some(local) = self.local.as_ref() {
+            return Ok(local
+                .degraded
+                .lock()
+                .map(|degraded| degraded.contains(token_id))
+                .unwrap_or(false));
+        }
+        Ok(self.redis.get_orderbook_degraded(token_id).await?.is_some())
+    }
+
+    /// Best bid and ask read together: one snapshot of the local book, else Redis.
+    async fn best_prices(&self, token_id: &str) -> Result<(Option<f64>, Option<f64>)> {
+        if let Some(book) = self.local_book(token_id) {
+            return Ok((
+                book.best_price(BookSide::Bid),
+                book.best_price(BookSide::Ask),
+            ));
+        }
+        let price =
+            |level: Option<OrderBookLevel>| level.and_then(|level| level.price.parse::<f64>().ok());
+        let bid = price(self.best_level(token_id, BookSide::Bid).await?);
+        let ask = price(self.best_level(token_id, BookSide::Ask).await?);
+        Ok((bid, ask))
     }
 
     pub async fn last_update_ms(&self, token_id: &str) -> Result<Option<u64>> {
+        if let Some(book) = self.local_book(token_id) {
+            return Ok(Some(book.updated_at_ms));
+        }
         self.redis.get_orderbook_update_ms(token_id).await
     }
 
@@ -265,6 +605,33 @@ fn parse_price_score(price: &str) -> Result<f64> {
         .map_err(|_| BankaiError::InvalidArgument("order book price not numeric".to_string()))
 }
 
+/// Feed-reported prices of zero mean an empty side.
+fn quoted_pair(bid: Option<f64>, ask: Option<f64>) -> Option<(f64, f64)> {
+    match (bid, ask) {
+        (Some(bid), Some(ask)) if bid > 0.0 && ask > 0.0 => Some((bid, ask)),
+        _ => None,
+    }
+}
+
+fn same_price(local: Option<f64>, server: Option<f64>) -> bool {
+    match (local, server.filter(|price| *price > 0.0)) {
+        (Some(local), Some(server)) => (local - server).abs() <= LEVEL_TOLERANCE,
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+fn price_ticks(price: f64) -> u64 {
+    (price.max(0.0) * PRICE_TICKS_PER_UNIT).round() as u64
+}
+
+fn parse_levels(levels: &[OrderBookLevel]) -> Result<Vec<(u64, OrderBookLevel)>> {
+    levels
+        .iter()
+        .map(|level| Ok((price_ticks(parse_price_score(&level.price)?), level.clone())))
+        .collect()
+}
+
 fn now_ms() -> Result<u64> {
     use std::time::{SystemTime, UNIX_EPOCH};
     let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
@@ -282,3 +649,48 @@ fn asks_key(token_id: &str) -> String {
 fn depth_key(token_id: &str) -> String {
     format!("book:{token_id}:depth")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token_book_orders_levels_numerically() {
+        let mut book = TokenBook::default();
+        for (price, size) in [("0.9", 5.0), ("0.45", 1.0), ("0.5", 2.0)] {
+            let ticks = price_ticks(parse_price_score(price).unwrap());
+            book.set_level(BookSide::Bid, ticks, price, size);
+            book.set_level(BookSide::Ask, ticks, price, size);
+        }
+        book.set_level(BookSide::Bid, price_ticks(0.9), "0.9", 0.0);
+
+        let bids: Vec<String> = book
+            .top_levels(BookSide::Bid, 5)
+            .into_iter()
+            .map(|level| level.price)
+            .collect();
+        let asks: Vec<String> = book
+            .top_levels(BookSide::Ask, 2)
+            .into_iter()
+            .map(|level| level.price)
+            .collect();
+        assert_eq!(bids, vec!["0.5", "0.45"]);
+        assert_eq!(asks, vec!["0.45", "0.5"]);
+    }
+
+    #[test]
+    fn local_store_reads_bid_and_ask_from_one_snapshot() {
+        let local = LocalBooks::default();
+        local.update("token", |book| {
+            book.set_level(BookSide::Bid, price_ticks(0.41), "0.41", 5.0);
+            book.set_level(BookSide::Ask, price_ticks(0.43), "0.43", 5.0);
+        });
+        let snapshot = local.get("token").expect("book");
+        local.update("token", |book| {
+            book.set_level(BookSide::Ask, price_ticks(0.43), "0.43", 0.0);
+            book.set_level(BookSide::Bid, price_ticks(0.44), "0.44", 5.0);
+        });
+        assert_eq!(snapshot.best_bid_ask(), Some((0.41, 0.43)));
+        assert_eq!(local.get("token").expect("book").best_bid_ask(), None);
+    }
+}
@@ -0,0 +1,343 @@
+/**
+ * @description
+ * Raw feed recorder that persists oracle and websocket traffic as gzip NDJSON segments.
+ *
+ * @dependencies
+ * - flate2: gzip compression for segment files
+ * - tokio: broadcast tap and blocking writer task
+ *
+ * @notes
+ * - Records use the backtest replay envelope so segments feed straight into the replay tool.
+ * - Producers never block: when the queue is full the record is dropped and counted.
+ * - IO errors drop the open segment and reopen a fresh one after a capped backoff; records
+ *   sent after the writer has exited are counted as dropped and warned about once.
+ * - Segments rotate on size or age; the oldest are pruned when max_segments is set.
+ */
+use chrono::Utc;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, SyncSender, TrySendError};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tokio::sync::broadcast;
+
+use crate::config::RecorderConfig;
+use crate::engine::backtest::{ReplayEvent, ReplayRecord};
+use crate::engine::types::MarketUpdate;
+use crate::error::Result;
+use crate::telemetry::metrics;
+
+const SEGMENT_PREFIX: &str = "feed-";
+const SEGMENT_SUFFIX: &str = ".ndjson.gz";
+const FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+const RETRY_BACKOFF_MIN: Duration = Duration::from_millis(250);
+const RETRY_BACKOFF_MAX: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone)]
+pub struct FeedRecorderConfig {
+    pub directory: PathBuf,
+    pub segment_max_bytes: u64,
+    pub segment_max_age: Duration,
+    pub max_segments: usize,
+    pub queue_capacity: usize,
+}
+
+impl FeedRecorderConfig {
+    pub fn from_config(config: &RecorderConfig) -> Self {
+        Self {
+            directory: PathBuf::from(&config.directory),
+            segment_max_bytes: config.segment_max_bytes.max(1),
+            segment_max_age: Duration::from_secs(config.segment_max_secs.max(1)),
+            max_segments: config.max_segments,
+            queue_capacity: config.queue_capacity.max(1),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RecorderHandle {
+    sender: SyncSender<ReplayRecord>,
+    disconnected_warned: Arc<AtomicBool>,
+}
+
+impl RecorderHandle {
+    pub fn record(&self, event: ReplayEvent) {
+        let record = ReplayRecord {
+            ts_ms: now_ms(),
+            event,
+        };
+        match self.sender.try_send(record) {
+            Ok(()) => {}
+            Err(TrySendError::Full(_)) => metrics::increment_recorder_dropped(),
+            Err(TrySendError::Disconnected(_)) => {
+                metrics::increment_recorder_dropped();
+                if !self.disconnected_warned.swap(true, Ordering::Relaxed) {
+                    tracing::warn!("feed recorder stopped; dropping records");
+                }
+            }
+        }
+    }
+}
+
+pub struct FeedRecorder {
+    config: FeedRecorderConfig,
+    receiver: Receiver<ReplayRecord>,
+}
+
+impl FeedRecorder {
+    pub fn new(config: FeedRecorderConfig) -> Result<(Self, RecorderHandle)> {
+        fs::create_dir_all(&config.directory)?;
+        let (sender, receiver) = mpsc::sync_channel(config.queue_capacity);
+        let handle = RecorderHandle {
+            sender,
+            disconnected_warned: Arc::new(AtomicBool::new(false)),
+        };
+        Ok((Self { config, receiver }, handle))
+    }
+
+    pub fn spawn(self) -> tokio::task::JoinHandle<()> {
+        tokio::task::spawn_blocking(move || {
+            if let Err(error) = self.run() {
+                tracing::error!(?error, "feed recorder stopped");
+            }
+        })
+    }
+
+    fn run(self) -> Result<()> {
+        let mut sequence = 0u64;
+        let mut segment: Option<Segment> = None;
+        let mut backoff = RETRY_BACKOFF_MIN;
+        loop {
+            let step = match self.receiver.recv_timeout(FLUSH_INTERVAL) {
+                Ok(record) => self.write_record(&mut segment, &mut sequence, &record),
+                Err(RecvTimeoutError::Timeout) => match segment.as_mut() {
+                    Some(current) => current.flush(),
+                    None => Ok(()),
+                },
+                Err(RecvTimeoutError::Disconnected) => {
+                    if let Some(current) = segment.take() {
+                        current.finish()?;
+                    }
+                    return Ok(());
+                }
+            };
+            match step {
+                Ok(()) => backoff = RETRY_BACKOFF_MIN,
+                Err(error) => {
+                    tracing::error!(
+                        ?error,
+                        backoff_ms = backoff.as_millis() as u64,
+                        "feed recorder write failed; reopening segment"
+                    );
+                    if let Some(current) = segment.take() {
+                        let _ = current.finish();
+                    }
+                    std::thread::sleep(backoff);
+                    backoff = (backoff * 2).min(RETRY_BACKOFF_MAX);
+                }
+            }
+        }
+    }
+
+    fn write_record(
+        &self,
+        segment: &mut Option<Segment>,
+        sequence: &mut u64,
+        record: &ReplayRecord,
+    ) -> Result<()> {
+        let mut line = serde_json::to_vec(record)?;
+        line.push(b'\n');
+        let rotate = segment
+            .as_ref()
+            .map(|current| current.should_rotate(&self.config))
+            .unwrap_or(true);
+        if rotate {
+            if let Some(current) = segment.take() {
+                current.finish()?;
+                prune_segments(&self.config.directory, self.config.max_segments)?;
+            }
+            *segment = Some(Segment::create(&self.config.directory, *sequence)?);
+            *sequence += 1;
+        }
+        if let Some(current) = segment.as_mut() {
+            current.write(&line)?;
+        }
+        Ok(())
+    }
+}
+
+/// Forwards every oracle update on the market broadcast into the recorder.
+pub fn spawn_market_tap(
+    recorder: RecorderHandle,
+    mut receiver: broadcast::Receiver<MarketUpdate>,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        loop {
+            match receiver.recv().await {
+                Ok(MarketUpdate::Chainlink(update)) => {
+                    recorder.record(ReplayEvent::Chainlink(update));
+                }
+                Ok(MarketUpdate::Binance(update)) => {
+                    recorder.record(ReplayEvent::Binance(update));
+                }
+                Ok(MarketUpdate::Allora(update)) => {
+                    recorder.record(ReplayEvent::Allora(update));
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::warn!(skipped, "feed recorder tap lagged");
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    })
+}
+
+struct Segment {
+    path: PathBuf,
+    encoder: GzEncoder<BufWriter<File>>,
+    opened_at: Instant,
+    bytes_written: u64,
+}
+
+impl Segment {
+    fn create(directory: &Path, sequence: u64) -> Result<Self> {
+        let stamp = Utc::now().format("%Y%m%dT%H%M%SZ");
+        let path = directory.join(format!(
+            "{SEGMENT_PREFIX}{stamp}-{sequence:06}{SEGMENT_SUFFIX}"
+        ));
+        let file = File::create(&path)?;
+        tracing::info!(path = %path.display(), "feed recorder segment opened");
+        Ok(Self {
+            path,
+            encoder: GzEncoder::new(BufWriter::new(file), Compression::default()),
+            opened_at: Instant::now(),
+            bytes_written: 0,
+        })
+    }
+
+    fn should_rotate(&self, config: &FeedRecorderConfig) -> bool {
+        self.bytes_written >= config.segment_max_bytes
+            || self.opened_at.elapsed() >= config.segment_max_age
+    }
+
+    fn write(&mut self, line: &[u8]) -> Result<()> {
+        self.encoder.write_all(line)?;
+        self.bytes_written += line.len() as u64;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.encoder.flush()?;
+        Ok(())
+    }
+
+    fn finish(self) -> Result<()> {
+        let mut writer = self.encoder.finish()?;
+        writer.flush()?;
+        tracing::info!(
+            path = %self.path.display(),
+            bytes = self.bytes_written,
+            "feed recorder segment closed"
+        );
+        Ok(())
+    }
+}
+
+fn prune_segments(directory: &Path, max_segments: usize) -> Result<()> {
+    if max_segments == 0 {
+        return Ok(());
+    }
+    let mut segments: Vec<PathBuf> = fs::read_dir(directory)?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .map(|name| name.starts_with(SEGMENT_PREFIX) && name.ends_with(SEGMENT_SUFFIX))
+                .unwrap_or(false)
+        })
+        .collect();
+    if segments.len() <= max_segments {
+        return Ok(());
+    }
+    segments.sort();
+    let excess = segments.len() - max_segments;
+    for path in segments.into_iter().take(excess) {
+        if let Err(error) = fs::remove_file(&path) {
+            tracing::warn!(?error, path = %path.display(), "failed to prune feed segment");
+        }
+    }
+    Ok(())
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::backtest::read_replay_file;
+
+    #[test]
+    fn segment_round_trips_through_replay_reader() {
+        let directory = std::env::temp_dir().join(format!("bankai-recorder-{}", now_ms()));
+        fs::create_dir_all(&directory).expect("create dir");
+        let mut segment = Segment::create(&directory, 0).expect("segment");
+        for (ts_ms, frame) in [(2_000u64, "b"), (1_000u64, "a")] {
+            let record = ReplayRecord {
+                ts_ms,
+                event: ReplayEvent::MarketWs(frame.to_string()),
+            };
+            let mut line = serde_json::to_vec(&record).expect("encode");
+            line.push(b'\n');
+            segment.write(&line).expect("write");
+        }
+        let path = segment.path.clone();
+        segment.finish().expect("finish");
+
+        let records = read_replay_file(&path).expect("read");
+        let _ = fs::remove_dir_all(&directory);
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].ts_ms, 1_000);
+        assert!(matches!(&records[1].event, ReplayEvent::MarketWs(frame) if frame == "b"));
+    }
+
+    #[test]
+    fn recorder_reopens_a_segment_after_an_io_error() {
+        let directory = std::env::temp_dir().join(format!("bankai-recorder-retry-{}", now_ms()));
+        let config = FeedRecorderConfig {
+            directory: directory.clone(),
+            segment_max_bytes: 1 << 20,
+            segment_max_age: Duration::from_secs(60),
+            max_segments: 0,
+            queue_capacity: 8,
+        };
+        let (recorder, handle) = FeedRecorder::new(config).expect("recorder");
+        fs::remove_dir_all(&directory).expect("remove dir");
+        let writer = std::thread::spawn(move || recorder.run());
+
+        handle.record(ReplayEvent::MarketWs("lost".to_string()));
+        std::thread::sleep(Duration::from_millis(100));
+        fs::create_dir_all(&directory).expect("recreate dir");
+        handle.record(ReplayEvent::MarketWs("kept".to_string()));
+        drop(handle);
+        writer.join().expect("join").expect("run");
+
+        let segments: Vec<PathBuf> = fs::read_dir(&directory)
+            .expect("read dir")
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .collect();
+        let records = read_replay_file(&segments[0]).expect("read");
+        let _ = fs::remove_dir_all(&directory);
+        assert_eq!(segments.len(), 1);
+        assert_eq!(records.len(), 1);
+        assert!(matches!(&records[0].event, ReplayEvent::MarketWs(frame) if frame == "kept"));
+    }
+}
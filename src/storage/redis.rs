@@ -33,6 +33,8 @@ const POLYMARKET_ASSET_IDS_KEY: &str = "polymarket:asset_ids";
 const TUI_ACTIVITY_LOG_KEY: &str = "tui:activity_log";
 const TUI_INTENT_LOG_KEY: &str = "tui:intent_log";
 const TUI_ORDER_LOG_KEY: &str = "tui:order_log";
+const TUI_WINDOW_LOG_PREFIX: &str = "tui:window_log:";
+const TUI_WINDOW_LOG_TTL_SECS: i64 = 24 * 60 * 60;
 const POSITIONS_TRACKED_PREFIX: &str = "positions:tracked:";
 const POSITIONS_ENTRY_PREFIX: &str = "positions:entry:";
 const POSITIONS_PEAK_PREFIX: &str = "positions:peak:";
@@ -43,8 +45,11 @@ const ASSET_START_PRICE_PREFIX: &str = "polymarket:start_price:";
 const ASSET_END_PRICE_PREFIX: &str = "polymarket:end_price:";
 const CHAINLINK_STATUS_KEY: &str = "oracle:chainlink:status";
 const ORDERBOOK_TS_PREFIX: &str = "polymarket:book_ts:";
+const ORDERBOOK_DEGRADED_PREFIX: &str = "polymarket:book_degraded:";
+const ORDERBOOK_DEGRADED_TTL_SECS: i64 = 300;
 const LAST_TRADE_PREFIX: &str = "polymarket:last_trade:";
 const TOKEN_MARKET_PREFIX: &str = "polymarket:token_market:";
+const TOPIC_ACCURACY_PREFIX: &str = "allora:topic_accuracy:";
 
 #[derive(Debug, Clone)]
 pub struct AssetWindow {
@@ -54,6 +59,13 @@ pub struct AssetWindow {
     pub updated_at_ms: u64,
 }
 
+impl AssetWindow {
+    /// Window length as discovered, e.g. 15m or 1h depending on the market catalogue.
+    pub fn duration_ms(&self) -> u64 {
+        self.end_time_ms.saturating_sub(self.start_time_ms)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct OutcomeTokenIds {
     pub up: String,
@@ -279,7 +291,10 @@ impl RedisManager {
     }
 
     pub async fn get_asset_window(&self, asset: &str) -> Result<Option<AssetWindow>> {
-        let now = now_ms().unwrap_or(0);
+        self.get_asset_window_at(asset, now_ms().unwrap_or(0)).await
+    }
+
+    pub async fn get_asset_window_at(&self, asset: &str, now: u64) -> Result<Option<AssetWindow>> {
         let current = self
             .read_asset_window(asset_window_key(asset).as_str())
             .await?;
@@ -371,6 +386,36 @@ impl RedisManager {
         self.get_log(TUI_ORDER_LOG_KEY, limit).await
     }
 
+    /// Per-window blocker/intent timeline for the TUI drill-down; expires after a day.
+    pub async fn push_window_log(
+        &self,
+        asset: &str,
+        window_start_ms: u64,
+        entry: &str,
+        max_len: usize,
+    ) -> Result<()> {
+        let key = window_log_key(asset, window_start_ms);
+        let mut conn = self.connection.clone();
+        let mut pipe = redis::pipe();
+        pipe.lpush(&key, entry);
+        if max_len > 0 {
+            pipe.ltrim(&key, 0, (max_len - 1) as isize);
+        }
+        pipe.expire(&key, TUI_WINDOW_LOG_TTL_SECS);
+        pipe.query_async::<_, ()>(&mut conn).await?;
+        Ok(())
+    }
+
+    pub async fn get_window_log(
+        &self,
+        asset: &str,
+        window_start_ms: u64,
+        limit: usize,
+    ) -> Result<Vec<String>> {
+        self.get_log(&window_log_key(asset, window_start_ms), limit)
+            .await
+    }
+
     pub async fn get_market_window(&self, market_id: &str) -> Result<Option<MarketWindow>> {
         let key = market_metadata_key(market_id);
         let start_time_ms = self.hget_i64(&key, "startTimeMs").await?;
@@ -473,6 +518,46 @@ impl RedisManager {
         Ok(updated_at_ms.map(|value| value as u64))
     }
 
+    /// Flags a token's book as untrustworthy until it is rebuilt (or the flag expires).
+    pub async fn set_orderbook_degraded(&self, token_id: &str, reason: &str) -> Result<()> {
+        let key = orderbook_degraded_key(token_id);
+        let mut conn = self.connection.clone();
+        conn.set_ex::<_, _, ()>(key, reason, ORDERBOOK_DEGRADED_TTL_SECS as u64)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn clear_orderbook_degraded(&self, token_id: &str) -> Result<()> {
+        self.del(&orderbook_degraded_key(token_id)).await
+    }
+
+    pub async fn get_orderbook_degraded(&self, token_id: &str) -> Result<Option<String>> {
+        self.get_string(&orderbook_degraded_key(token_id)).await
+    }
+
+    /// Directional hit/total counts for one Allora topic (`ASSET:timeframe`).
+    pub async fn get_topic_accuracy(&self, topic: &str) -> Result<Option<(f64, f64)>> {
+        let key = topic_accuracy_key(topic);
+        let hits = self.hget_float(&key, "hits").await?;
+        let total = self.hget_float(&key, "total").await?;
+        Ok(match (hits, total) {
+            (Some(hits), Some(total)) if total > 0.0 => Some((hits, total)),
+            _ => None,
+        })
+    }
+
+    pub async fn record_topic_outcome(&self, topic: &str, hit: bool) -> Result<()> {
+        let key = topic_accuracy_key(topic);
+        let mut conn = self.connection.clone();
+        redis::pipe()
+            .atomic()
+            .hincr(&key, "hits", if hit { 1.0 } else { 0.0 })
+            .hincr(&key, "total", 1.0)
+            .query_async::<_, ()>(&mut conn)
+            .await?;
+        Ok(())
+    }
+
     pub async fn set_asset_start_price(
         &self,
         asset: &str,
@@ -739,6 +824,11 @@ impl RedisManager {
         Ok(self.hget_float(&key, asset_id).await?.unwrap_or(0.0))
     }
 
+    /// All tracked positions for a wallet, keyed by token id.
+    pub async fn get_tracked_positions(&self, wallet_key: &str) -> Result<HashMap<String, f64>> {
+        self.hgetall_f64(&tracked_positions_key(wallet_key)).await
+    }
+
     pub async fn set_tracked_position(
         &self,
         wallet_key: &str,
@@ -829,7 +919,22 @@ fn asset_end_price_key(asset: &str) -> String {
     format!("{ASSET_END_PRICE_PREFIX}{asset}")
 }
 
-fn orderbook_ts_key(token_id: &str) -> String {
+fn topic_accuracy_key(topic: &str) -> String {
+    format!("{TOPIC_ACCURACY_PREFIX}{topic}")
+}
+
+fn window_log_key(asset: &str, window_start_ms: u64) -> String {
+    format!(
+        "{TUI_WINDOW_LOG_PREFIX}{}:{window_start_ms}",
+        asset.to_ascii_uppercase()
+    )
+}
+
+fn orderbook_degraded_key(token_id: &str) -> String {
+    format!("{ORDERBOOK_DEGRADED_PREFIX}{token_id}")
+}
+
+pub(crate) fn orderbook_ts_key(token_id: &str) -> String {
     format!("{ORDERBOOK_TS_PREFIX}{token_id}")
 }
 
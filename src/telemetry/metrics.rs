@@ -1,21 +1,153 @@
 /**
  * @description
- * Metrics helpers for critical KPIs (latency, fill rate, rail failovers).
+ * Metrics helpers for critical KPIs and the Prometheus `/metrics` exporter.
  *
  * @dependencies
  * - metrics: recorder-agnostic metric macros
+ * - metrics-exporter-prometheus: HTTP scrape endpoint
  *
  * @notes
- * - Exporters must be configured elsewhere to collect these metrics.
+ * - `init_metrics` installs the exporter when `telemetry.metrics_enabled` is set; without
+ *   it every helper below is a no-op.
+ * - Rates (oracle messages, reconnects, blockers) are counters; derive per-second rates
+ *   with `rate()` in Prometheus.
+ * - Per-token and per-blocker labels are bounded by the market catalogue and the trader's
+ *   blocker keys.
  */
+use metrics_exporter_prometheus::PrometheusBuilder;
+use std::net::SocketAddr;
+
+use crate::config::TelemetryConfig;
+use crate::error::{BankaiError, Result};
+
 const LATENCY_MS: &str = "latency_ms";
 const ORDER_FILL_RATE: &str = "order_fill_rate";
 const RAIL_FAILOVER_COUNT: &str = "rail_failover_count";
+const RECORDER_DROPPED_COUNT: &str = "recorder_dropped_count";
+const SOURCE_DIVERGENCE_COUNT: &str = "source_divergence_count";
+const DIRECT_TX_REPLACED_COUNT: &str = "direct_tx_replaced_count";
+const PYTHON_FALLBACK_COUNT: &str = "python_fallback_count";
+const BOOK_INTEGRITY_FAILURE_COUNT: &str = "book_integrity_failure_count";
+const ALLORA_SIGNATURE_COUNT: &str = "allora_signature_count";
+const ORACLE_MESSAGE_COUNT: &str = "oracle_message_count";
+const ORACLE_RECONNECT_COUNT: &str = "oracle_reconnect_count";
+const ORDERBOOK_UPDATE_LAG_MS: &str = "orderbook_update_lag_ms";
+const TRADE_BLOCKER_COUNT: &str = "trade_blocker_count";
+const RELAYER_LATENCY_MS: &str = "relayer_latency_ms";
+const PAPER_WIN_RATE_PCT: &str = "paper_win_rate_pct";
+const BANKROLL_USDC: &str = "bankroll_usdc";
+const UNREALIZED_PNL_USDC: &str = "unrealized_pnl_usdc";
+const KILL_SWITCH_HALTED: &str = "kill_switch_halted";
+const KILL_SWITCH_TRIP_COUNT: &str = "kill_switch_trip_count";
+const CLOB_REQUEST_LATENCY_MS: &str = "clob_request_latency_ms";
+const CLOB_REQUEST_COUNT: &str = "clob_request_count";
+const CLOB_RETRY_COUNT: &str = "clob_retry_count";
+const CLOB_RATE_LIMITED_COUNT: &str = "clob_rate_limited_count";
+const CLOB_CREDENTIAL_REFRESH_COUNT: &str = "clob_credential_refresh_count";
+
+/// Installs the Prometheus exporter (when enabled) and registers metric descriptions.
+pub fn init_metrics(config: &TelemetryConfig) -> Result<()> {
+    if config.metrics_enabled {
+        let addr: SocketAddr = config.metrics_listen_addr.parse().map_err(|_| {
+            BankaiError::InvalidArgument(format!(
+                "invalid telemetry.metrics_listen_addr {}",
+                config.metrics_listen_addr
+            ))
+        })?;
+        PrometheusBuilder::new()
+            .with_http_listener(addr)
+            .install()
+            .map_err(|error| {
+                BankaiError::InvalidArgument(format!("prometheus exporter failed: {error}"))
+            })?;
+        tracing::info!(%addr, "prometheus metrics exporter listening");
+    }
+    describe_metrics();
+    Ok(())
+}
 
-pub fn init_metrics() {
+fn describe_metrics() {
     metrics::describe_histogram!(LATENCY_MS, "End-to-end latency in milliseconds.");
     metrics::describe_gauge!(ORDER_FILL_RATE, "Order fill rate percentage.");
     metrics::describe_counter!(RAIL_FAILOVER_COUNT, "Count of rail failovers.");
+    metrics::describe_counter!(
+        RECORDER_DROPPED_COUNT,
+        "Count of feed records dropped because the recorder queue was full or the writer stopped."
+    );
+    metrics::describe_counter!(
+        SOURCE_DIVERGENCE_COUNT,
+        "Count of assets entering Chainlink/Binance price divergence."
+    );
+    metrics::describe_counter!(
+        DIRECT_TX_REPLACED_COUNT,
+        "Count of stuck Rail B transactions replaced with bumped fees."
+    );
+    metrics::describe_counter!(
+        PYTHON_FALLBACK_COUNT,
+        "Count of Python strategy calls that fell back to the Rust model."
+    );
+    metrics::describe_counter!(
+        BOOK_INTEGRITY_FAILURE_COUNT,
+        "Count of order book integrity failures that forced a resnapshot, by reason."
+    );
+    metrics::describe_counter!(
+        ALLORA_SIGNATURE_COUNT,
+        "Count of Allora inference signature checks, by outcome."
+    );
+    metrics::describe_counter!(
+        ORACLE_MESSAGE_COUNT,
+        "Count of messages received from each oracle feed, by source."
+    );
+    metrics::describe_counter!(
+        ORACLE_RECONNECT_COUNT,
+        "Count of oracle websocket reconnects, by source."
+    );
+    metrics::describe_histogram!(
+        ORDERBOOK_UPDATE_LAG_MS,
+        "Delay between a Polymarket book event timestamp and local receipt, by token."
+    );
+    metrics::describe_counter!(
+        TRADE_BLOCKER_COUNT,
+        "Count of trader intents suppressed by a blocker, by reason."
+    );
+    metrics::describe_histogram!(
+        RELAYER_LATENCY_MS,
+        "Relayer order post latency in milliseconds, by status class."
+    );
+    metrics::describe_gauge!(PAPER_WIN_RATE_PCT, "Paper trading win rate percentage.");
+    metrics::describe_gauge!(BANKROLL_USDC, "Bankroll in USDC, by mode (live or paper).");
+    metrics::describe_gauge!(
+        UNREALIZED_PNL_USDC,
+        "Unrealized PnL of tracked positions at book mid, in USDC."
+    );
+    metrics::describe_gauge!(
+        KILL_SWITCH_HALTED,
+        "1 while the kill switch halts trading, 0 otherwise."
+    );
+    metrics::describe_counter!(
+        KILL_SWITCH_TRIP_COUNT,
+        "Count of kill switch halts, by reason."
+    );
+    metrics::describe_histogram!(
+        CLOB_REQUEST_LATENCY_MS,
+        "CLOB request latency in milliseconds, by endpoint and outcome."
+    );
+    metrics::describe_counter!(
+        CLOB_REQUEST_COUNT,
+        "Count of CLOB requests, by endpoint and outcome."
+    );
+    metrics::describe_counter!(
+        CLOB_RETRY_COUNT,
+        "Count of CLOB request retries, by endpoint."
+    );
+    metrics::describe_counter!(
+        CLOB_RATE_LIMITED_COUNT,
+        "Count of CLOB requests delayed by the local rate limiter, by endpoint."
+    );
+    metrics::describe_counter!(
+        CLOB_CREDENTIAL_REFRESH_COUNT,
+        "Count of CLOB API credential re-derivations after a 401, by outcome."
+    );
 }
 
 pub fn record_latency_ms(value_ms: f64) {
@@ -29,3 +161,112 @@ pub fn record_order_fill_rate(rate_pct: f64) {
 pub fn increment_rail_failover() {
     metrics::counter!(RAIL_FAILOVER_COUNT, 1);
 }
+
+pub fn increment_recorder_dropped() {
+    metrics::counter!(RECORDER_DROPPED_COUNT, 1);
+}
+
+pub fn increment_source_divergence() {
+    metrics::counter!(SOURCE_DIVERGENCE_COUNT, 1);
+}
+
+pub fn increment_direct_tx_replaced() {
+    metrics::counter!(DIRECT_TX_REPLACED_COUNT, 1);
+}
+
+pub fn increment_python_fallback() {
+    metrics::counter!(PYTHON_FALLBACK_COUNT, 1);
+}
+
+pub fn increment_book_integrity_failure(reason: &'static str) {
+    metrics::counter!(BOOK_INTEGRITY_FAILURE_COUNT, 1, "reason" => reason);
+}
+
+pub fn increment_allora_signature(outcome: &'static str) {
+    metrics::counter!(ALLORA_SIGNATURE_COUNT, 1, "outcome" => outcome);
+}
+
+pub fn increment_oracle_message(source: &'static str) {
+    metrics::counter!(ORACLE_MESSAGE_COUNT, 1, "source" => source);
+}
+
+pub fn increment_oracle_reconnect(source: &'static str) {
+    metrics::counter!(ORACLE_RECONNECT_COUNT, 1, "source" => source);
+}
+
+pub fn record_orderbook_update_lag_ms(token_id: &str, lag_ms: f64) {
+    metrics::histogram!(ORDERBOOK_UPDATE_LAG_MS, lag_ms, "token" => token_id.to_string());
+}
+
+pub fn increment_trade_blocker(reason: &str) {
+    metrics::counter!(TRADE_BLOCKER_COUNT, 1, "reason" => reason.to_string());
+}
+
+pub fn record_relayer_latency_ms(status_class: &'static str, latency_ms: f64) {
+    metrics::histogram!(RELAYER_LATENCY_MS, latency_ms, "status" => status_class);
+}
+
+pub fn record_paper_win_rate_pct(win_rate_pct: f64) {
+    metrics::gauge!(PAPER_WIN_RATE_PCT, win_rate_pct);
+}
+
+pub fn record_bankroll_usdc(mode: &'static str, bankroll_usdc: f64) {
+    metrics::gauge!(BANKROLL_USDC, bankroll_usdc, "mode" => mode);
+}
+
+pub fn record_unrealized_pnl_usdc(pnl_usdc: f64) {
+    metrics::gauge!(UNREALIZED_PNL_USDC, pnl_usdc);
+}
+
+pub fn record_kill_switch(halted: bool) {
+    metrics::gauge!(KILL_SWITCH_HALTED, if halted { 1.0 } else { 0.0 });
+}
+
+pub fn increment_kill_switch_trip(reason: &'static str) {
+    metrics::counter!(KILL_SWITCH_TRIP_COUNT, 1, "reason" => reason);
+}
+
+pub fn record_clob_request(endpoint: &'static str, outcome: &'static str, latency_ms: f64) {
+    metrics::histogram!(CLOB_REQUEST_LATENCY_MS, latency_ms, "endpoint" => endpoint, "outcome" => outcome);
+    metrics::counter!(CLOB_REQUEST_COUNT, 1, "endpoint" => endpoint, "outcome" => outcome);
+}
+
+pub fn increment_clob_retry(endpoint: &'static str) {
+    metrics::counter!(CLOB_RETRY_COUNT, 1, "endpoint" => endpoint);
+}
+
+pub fn increment_clob_rate_limited(endpoint: &'static str) {
+    metrics::counter!(CLOB_RATE_LIMITED_COUNT, 1, "endpoint" => endpoint);
+}
+
+pub fn increment_clob_credential_refresh(outcome: &'static str) {
+    metrics::counter!(CLOB_CREDENTIAL_REFRESH_COUNT, 1, "outcome" => outcome);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registered_metrics_render_for_prometheus() {
+        let handle = PrometheusBuilder::new()
+            .install_recorder()
+            .expect("prometheus recorder");
+        describe_metrics();
+
+        increment_trade_blocker("metrics_test");
+        increment_trade_blocker("metrics_test");
+        increment_clob_rate_limited("metrics_test");
+        record_clob_request("metrics_test", "ok", 12.0);
+        record_bankroll_usdc("metrics_test", 250.0);
+
+        let rendered = handle.render();
+        assert!(rendered.contains("# HELP trade_blocker_count"));
+        assert!(rendered.contains("trade_blocker_count{reason=\"metrics_test\"} 2"));
+        assert!(rendered.contains("clob_rate_limited_count{endpoint=\"metrics_test\"} 1"));
+        assert!(rendered.contains("clob_request_count{endpoint=\"metrics_test\",outcome=\"ok\"} 1"));
+        assert!(rendered
+            .contains("clob_request_latency_ms_count{endpoint=\"metrics_test\",outcome=\"ok\"} 1"));
+        assert!(rendered.contains("bankroll_usdc{mode=\"metrics_test\"} 250"));
+    }
+}
@@ -0,0 +1,459 @@
+/**
+ * @purpose
+ * Embedded HTTP/JSON admin API mirroring the TUI for headless deployments.
+ *
+ * @dependencies
+ * - hyper: HTTP/1 server
+ * - ui::SnapshotFeed: the same snapshots the TUI renders
+ * - engine::control: operator commands (halt, resume, cancel-all, ...)
+ * - config::ConfigManager: runtime config overrides
+ *
+ * @notes
+ * - Reads: GET /snapshot, /status, /health, /markets, /windows, /logs, /paper, /orders.
+ * - Writes: POST /control/{halt,resume,cancel-all,flatten}, POST /control/{no-money,trading}
+ *   with {"enabled": bool}, and PATCH /config with a JSON object of dotted keys
+ *   (e.g. {"execution.enable_trading": false}). Mode changes are explicit sets, never toggles,
+ *   so a retried request is harmless.
+ * - Writes need `Authorization: Bearer <token>` where the token comes from `admin.token_env`;
+ *   with no token configured every write is refused. Reads are open, so keep the default
+ *   loopback bind or put the server behind a proxy.
+ * - Control commands are queued like TUI keys (202); their outcome lands in the activity log.
+ */
+use hyper::header::{AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE};
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Method, Request, Response, Server, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+use super::{open_orders_key, SnapshotFeed, UiSnapshot};
+use crate::config::{AdminConfig, ConfigManager};
+use crate::engine::control::ControlCommand;
+use crate::error::{BankaiError, Result};
+use crate::storage::redis::RedisManager;
+
+const ACTIVITY_LOG_LIMIT: usize = 50;
+const MAX_BODY_BYTES: u64 = 64 * 1024;
+
+pub struct AdminServer {
+    addr: SocketAddr,
+    feed: SnapshotFeed,
+    token: Option<String>,
+    control: Option<mpsc::UnboundedSender<ControlCommand>>,
+    config_manager: Option<ConfigManager>,
+    redis: Option<RedisManager>,
+    wallet_key: Option<String>,
+}
+
+impl AdminServer {
+    pub fn new(config: &AdminConfig, feed: SnapshotFeed) -> Result<Self> {
+        let addr: SocketAddr = config.listen_addr.parse().map_err(|_| {
+            BankaiError::InvalidArgument(format!(
+                "invalid admin.listen_addr {}",
+                config.listen_addr
+            ))
+        })?;
+        let token = std::env::var(&config.token_env)
+            .ok()
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty());
+        if token.is_none() {
+            tracing::warn!(
+                env = %config.token_env,
+                "admin token missing; admin write endpoints disabled"
+            );
+        }
+        Ok(Self {
+            addr,
+            feed,
+            token,
+            control: None,
+            config_manager: None,
+            redis: None,
+            wallet_key: None,
+        })
+    }
+
+    pub fn with_control(mut self, control: mpsc::UnboundedSender<ControlCommand>) -> Self {
+        self.control = Some(control);
+        self
+    }
+
+    pub fn with_config_manager(mut self, config_manager: ConfigManager) -> Self {
+        self.config_manager = Some(config_manager);
+        self
+    }
+
+    pub fn with_redis(mut self, redis: RedisManager, wallet_key: Option<String>) -> Self {
+        self.redis = Some(redis);
+        self.wallet_key = wallet_key;
+        self
+    }
+
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            if let Err(error) = self.run().await {
+                tracing::error!(?error, "admin server stopped");
+            }
+        })
+    }
+
+    async fn run(self) -> Result<()> {
+        let addr = self.addr;
+        let state = Arc::new(self);
+        let make_service = make_service_fn(move |_| {
+            let state = state.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request| {
+                    let state = state.clone();
+                    async move { Ok::<_, Infallible>(state.handle(request).await) }
+                }))
+            }
+        });
+        let server = Server::try_bind(&addr)?.serve(make_service);
+        tracing::info!(%addr, "admin api listening");
+        server.await?;
+        Ok(())
+    }
+
+    async fn handle(&self, request: Request<Body>) -> Response<Body> {
+        let method = request.method().clone();
+        let path = request.uri().path().trim_end_matches('/').to_string();
+        let result = match (&method, path.as_str()) {
+            (&Method::GET, "/snapshot") => self.read(|snapshot| json!(snapshot)),
+            (&Method::GET, "/status") => self.read(|snapshot| json!(snapshot.status)),
+            (&Method::GET, "/health") => self.read(|snapshot| json!(snapshot.health)),
+            (&Method::GET, "/markets") => self.read(|snapshot| json!(snapshot.markets)),
+            (&Method::GET, "/windows") => self.read(|snapshot| json!(snapshot.active_windows)),
+            (&Method::GET, "/logs") => self.read(|snapshot| {
+                json!({
+                    "activity": snapshot.activity_log,
+                    "intents": snapshot.intent_log,
+                    "orders": snapshot.order_log,
+                    "execution_status": snapshot.execution_status,
+                    "orders_ok": snapshot.orders_ok,
+                    "orders_fail": snapshot.orders_fail,
+                })
+            }),
+            (&Method::GET, "/paper") => self.read(|snapshot| json!(snapshot.paper_stats)),
+            (&Method::GET, "/orders") => self.open_orders().await,
+            (&Method::POST, path) if path.starts_with("/control/") => {
+                let action = path["/control/".len()..].to_string();
+                match self.authorize(&request) {
+                    Ok(()) => self.control(&action, request).await,
+                    Err(response) => Err(response),
+                }
+            }
+            (&Method::PATCH, "/config") => match self.authorize(&request) {
+                Ok(()) => self.patch_config(request).await,
+                Err(response) => Err(response),
+            },
+            _ => Err(error_response(StatusCode::NOT_FOUND, "not found")),
+        };
+        result.unwrap_or_else(|response| response)
+    }
+
+    fn read(&self, view: impl FnOnce(&UiSnapshot) -> Value) -> HandlerResult {
+        let snapshot = self
+            .feed
+            .latest()
+            .ok_or_else(|| error_response(StatusCode::SERVICE_UNAVAILABLE, "snapshot not ready"))?;
+        Ok(json_response(StatusCode::OK, &view(&snapshot)))
+    }
+
+    async fn open_orders(&self) -> HandlerResult {
+        let snapshot = self.feed.latest();
+        let mut orders = Vec::new();
+        if let (Some(redis), Some(wallet_key)) = (self.redis.as_ref(), self.wallet_key.as_ref()) {
+            let ids = redis
+                .smembers(&open_orders_key(wallet_key))
+                .await
+                .map_err(internal_error)?;
+            let details_key = open_orders_details_key(wallet_key);
+            for id in ids {
+                let details = redis
+                    .hget_string(&details_key, &id)
+                    .await
+                    .map_err(internal_error)?
+                    .and_then(|raw| serde_json::from_str::<Value>(&raw).ok());
+                orders.push(json!({ "id": id, "details": details }));
+            }
+        }
+        let count = snapshot.as_ref().and_then(|snapshot| snapshot.open_orders);
+        let last_order_state = snapshot
+            .as_ref()
+            .and_then(|snapshot| snapshot.last_order_state.clone());
+        Ok(json_response(
+            StatusCode::OK,
+            &json!({
+                "count": count,
+                "last_order_state": last_order_state,
+                "orders": orders,
+            }),
+        ))
+    }
+
+    async fn control(&self, action: &str, request: Request<Body>) -> HandlerResult {
+        let command = match action {
+            "no-money" | "trading" => {
+                let body = read_body(request).await?;
+                let Ok(SetMode { enabled }) = serde_json::from_slice::<SetMode>(&body) else {
+                    return Err(error_response(
+                        StatusCode::BAD_REQUEST,
+                        "body must be {\"enabled\": bool}",
+                    ));
+                };
+                if action == "no-money" {
+                    ControlCommand::SetNoMoney(enabled)
+                } else {
+                    ControlCommand::SetTrading(enabled)
+                }
+            }
+            _ => control_command_for_action(action)
+                .ok_or_else(|| error_response(StatusCode::NOT_FOUND, "unknown control action"))?,
+        };
+        let control = self.control.as_ref().ok_or_else(|| {
+            error_response(StatusCode::SERVICE_UNAVAILABLE, "control plane unavailable")
+        })?;
+        control.send(command).map_err(|_| {
+            error_response(StatusCode::SERVICE_UNAVAILABLE, "control plane stopped")
+        })?;
+        tracing::info!(?command, "admin control command queued");
+        Ok(json_response(
+            StatusCode::ACCEPTED,
+            &json!({ "queued": command.label() }),
+        ))
+    }
+
+    async fn patch_config(&self, request: Request<Body>) -> HandlerResult {
+        let config_manager = self.config_manager.as_ref().ok_or_else(|| {
+            error_response(
+                StatusCode::SERVICE_UNAVAILABLE,
+                "config manager unavailable",
+            )
+        })?;
+        let body = read_body(request).await?;
+        let Ok(Value::Object(patch)) = serde_json::from_slice::<Value>(&body) else {
+            return Err(error_response(
+                StatusCode::BAD_REQUEST,
+                "body must be a JSON object of dotted config keys",
+            ));
+        };
+        if patch.is_empty() {
+            return Err(error_response(
+                StatusCode::BAD_REQUEST,
+                "empty config patch",
+            ));
+        }
+        let keys: Vec<String> = patch.keys().cloned().collect();
+        config_manager
+            .set_overrides(patch.into_iter().collect())
+            .map_err(|error| error_response(StatusCode::BAD_REQUEST, &error.to_string()))?;
+        let message = format!("[ADMIN] config patched: {}", keys.join(", "));
+        tracing::info!(%message, "admin config patch applied");
+        if let Some(redis) = self.redis.as_ref() {
+            let _ = redis.push_activity_log(&message, ACTIVITY_LOG_LIMIT).await;
+        }
+        Ok(json_response(StatusCode::OK, &json!({ "applied": keys })))
+    }
+
+    fn authorize(&self, request: &Request<Body>) -> std::result::Result<(), Response<Body>> {
+        let Some(token) = self.token.as_deref() else {
+            return Err(error_response(
+                StatusCode::FORBIDDEN,
+                "admin writes disabled; no token configured",
+            ));
+        };
+        let provided = request
+            .headers()
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .map(str::trim);
+        match provided {
+            Some(provided) if constant_time_eq(provided.as_bytes(), token.as_bytes()) => Ok(()),
+            _ => Err(error_response(
+                StatusCode::UNAUTHORIZED,
+                "invalid admin token",
+            )),
+        }
+    }
+}
+
+type HandlerResult = std::result::Result<Response<Body>, Response<Body>>;
+
+#[derive(Deserialize)]
+struct SetMode {
+    enabled: bool,
+}
+
+fn control_command_for_action(action: &str) -> Option<ControlCommand> {
+    match action {
+        "halt" => Some(ControlCommand::Halt),
+        "resume" => Some(ControlCommand::Resume),
+        "cancel-all" => Some(ControlCommand::CancelAll),
+        "flatten" => Some(ControlCommand::Flatten),
+        _ => None,
+    }
+}
+
+async fn read_body(request: Request<Body>) -> std::result::Result<Vec<u8>, Response<Body>> {
+    let too_large = request
+        .headers()
+        .get(CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<u64>().ok())
+        .is_some_and(|length| length > MAX_BODY_BYTES);
+    if too_large {
+        return Err(error_response(
+            StatusCode::PAYLOAD_TOO_LARGE,
+            "request body too large",
+        ));
+    }
+    let body = hyper::body::to_bytes(request.into_body())
+        .await
+        .map_err(|_| error_response(StatusCode::BAD_REQUEST, "failed to read body"))?;
+    if body.len() as u64 > MAX_BODY_BYTES {
+        return Err(error_response(
+            StatusCode::PAYLOAD_TOO_LARGE,
+            "request body too large",
+        ));
+    }
+    Ok(body.to_vec())
+}
+
+fn json_response<T: Serialize>(status: StatusCode, body: &T) -> Response<Body> {
+    let payload = serde_json::to_vec(body).unwrap_or_else(|_| b"null".to_vec());
+    Response::builder()
+        .status(status)
+        .header(CONTENT_TYPE, "application/json")
+        .body(Body::from(payload))
+        .unwrap_or_else(|_| Response::new(Body::empty()))
+}
+
+fn error_response(status: StatusCode, message: &str) -> Response<Body> {
+    json_response(status, &json!({ "error": message }))
+}
+
+fn internal_error(error: BankaiError) -> Response<Body> {
+    tracing::warn!(?error, "admin request failed");
+    error_response(StatusCode::INTERNAL_SERVER_ERROR, &error.to_string())
+}
+
+fn open_orders_details_key(wallet_key: &str) -> String {
+    format!("orders:details:{wallet_key}")
+}
+
+fn constant_time_eq(left: &[u8], right: &[u8]) -> bool {
+    if left.len() != right.len() {
+        return false;
+    }
+    left.iter()
+        .zip(right)
+        .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+        == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_control_actions_and_compares_tokens() {
+        assert_eq!(
+            control_command_for_action("cancel-all"),
+            Some(ControlCommand::CancelAll)
+        );
+        assert_eq!(control_command_for_action("reboot"), None);
+        assert!(constant_time_eq(b"secret", b"secret"));
+        assert!(!constant_time_eq(b"secret", b"secreT"));
+        assert!(!constant_time_eq(b"secret", b"secret2"));
+    }
+
+    fn server(token_env: &str) -> (AdminServer, mpsc::UnboundedReceiver<ControlCommand>) {
+        let (_, snapshots) = tokio::sync::watch::channel(None);
+        let feed = SnapshotFeed {
+            snapshots,
+            detail_asset: Default::default(),
+        };
+        let config = AdminConfig {
+            token_env: token_env.to_string(),
+            ..AdminConfig::default()
+        };
+        let (control, receiver) = mpsc::unbounded_channel();
+        let server = AdminServer::new(&config, feed)
+            .expect("admin server")
+            .with_control(control);
+        (server, receiver)
+    }
+
+    fn post(path: &str, token: Option<&str>, body: &str) -> Request<Body> {
+        let mut builder = Request::builder().method(Method::POST).uri(path);
+        if let Some(token) = token {
+            builder = builder.header(AUTHORIZATION, format!("Bearer {token}"));
+        }
+        builder.body(Body::from(body.to_string())).expect("request")
+    }
+
+    #[test]
+    fn control_endpoints_check_tokens_and_dispatch_commands() {
+        let token_env = format!("BANKAI_TEST_ADMIN_TOKEN_{}", std::process::id());
+        std::env::set_var(&token_env, "secret");
+        let (server, mut receiver) = server(&token_env);
+        std::env::remove_var(&token_env);
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .expect("runtime");
+        let status = |request| runtime.block_on(server.handle(request)).status();
+
+        assert_eq!(
+            status(post("/control/halt", None, "")),
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(
+            status(post("/control/halt", Some("wrong"), "")),
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(
+            status(post("/control/reboot", Some("secret"), "")),
+            StatusCode::NOT_FOUND
+        );
+        assert_eq!(
+            status(post("/control/no-money", Some("secret"), "{}")),
+            StatusCode::BAD_REQUEST
+        );
+        assert!(receiver.try_recv().is_err());
+
+        assert_eq!(
+            status(post("/control/halt", Some("secret"), "")),
+            StatusCode::ACCEPTED
+        );
+        assert_eq!(
+            status(post(
+                "/control/no-money",
+                Some("secret"),
+                r#"{"enabled":true}"#
+            )),
+            StatusCode::ACCEPTED
+        );
+        assert_eq!(receiver.try_recv().ok(), Some(ControlCommand::Halt));
+        assert_eq!(
+            receiver.try_recv().ok(),
+            Some(ControlCommand::SetNoMoney(true))
+        );
+    }
+
+    #[test]
+    fn control_endpoints_refuse_writes_without_a_configured_token() {
+        let (server, _receiver) = server("BANKAI_TEST_ADMIN_TOKEN_UNSET");
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .expect("runtime");
+        let response = runtime.block_on(server.handle(post("/control/halt", Some(""), "")));
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
@@ -8,6 +8,12 @@
  *
  * @notes
  * - Runs in a dedicated thread to avoid blocking the trading engine.
+ * - Operator keys open a confirm dialog; confirmed commands go to the async ControlPlane
+ *   over an unbounded channel so the UI thread never blocks on I/O.
+ * - The market drill-down asset is shared with the snapshot loop, which only loads
+ *   ladder and timeline data for the market currently open.
+ * - One snapshot loop publishes into a `SnapshotFeed` watch channel; the TUI and the
+ *   headless admin API (`ui::admin`) both read from it.
  */
 use arc_swap::ArcSwap;
 use crossterm::event::{self, Event, KeyCode, KeyEventKind};
@@ -17,24 +23,28 @@ use crossterm::terminal::{
 };
 use ratatui::backend::CrosstermBackend;
 use ratatui::Terminal;
-use std::collections::HashMap;
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
 use std::io::{self, IsTerminal};
-use std::sync::{mpsc, Arc};
+use std::sync::{mpsc, Arc, Mutex};
 use std::thread;
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
-use tokio::sync::broadcast;
+use tokio::sync::{broadcast, mpsc as async_mpsc, watch};
 
 use crate::accounting::keys::PNL_24H_KEY;
-use crate::config::{Config, ExecutionConfig, StrategyConfig};
-use crate::engine::analysis::snipe_threshold_bps;
+use crate::config::{AlloraSignatureMode, Config, ExecutionConfig, StrategyConfig};
+use crate::engine::analysis::{calculate_kelly, snipe_threshold_bps};
+use crate::engine::control::ControlCommand;
 use crate::engine::risk::{HaltReason, RiskState};
 use crate::engine::types::{AlloraMarketUpdate, ChainlinkMarketUpdate, MarketUpdate, MarketWindow};
 use crate::error::Result;
-use crate::storage::orderbook::{BookSide, OrderBookStore};
+use crate::oracle::allora::{AlloraSignatureCounts, AlloraSignatureStats};
+use crate::storage::orderbook::{BookSide, OrderBookLevel, OrderBookStore};
 use crate::storage::redis::RedisManager;
 use chrono::{TimeZone, Utc};
 use chrono_tz::America::New_York;
 
+pub mod admin;
 mod widgets;
 
 const BANKROLL_KEY: &str = "sys:bankroll:usdc";
@@ -45,9 +55,11 @@ const DEFAULT_POLYMARKET_REFRESH_MS: u64 = 5_000;
 const DEFAULT_ACTIVITY_LOG_LIMIT: usize = 8;
 const DEFAULT_INTENT_LOG_LIMIT: usize = 6;
 const DEFAULT_ORDER_LOG_LIMIT: usize = 6;
+const DETAIL_LADDER_DEPTH: usize = 8;
+const DETAIL_TIMELINE_LIMIT: usize = 40;
+const INFERENCE_HISTORY_LIMIT: usize = 12;
 const POLYMARKET_STALE_MS: u64 = 120_000;
 const ORACLE_ONLINE_MULTIPLIER: u64 = 3;
-const SIGNAL_HORIZON_MS: u64 = 5 * 60 * 1_000;
 const SQRT_5: f64 = 2.236_067_977_5;
 const SIGNAL_DIR_UP: i8 = 1;
 const SIGNAL_DIR_DOWN: i8 = -1;
@@ -96,7 +108,7 @@ impl Default for TuiConfig {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct StatusBarData {
     pub uptime: Duration,
     pub halted: bool,
@@ -108,7 +120,7 @@ pub struct StatusBarData {
     pub model_version: String,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct HealthPanelData {
     pub halted: bool,
     pub halt_reason: HaltReason,
@@ -116,15 +128,17 @@ pub struct HealthPanelData {
     pub clock_drift_ms: i64,
     pub consecutive_losses: u32,
     pub chainlink_window_anchor: bool,
+    /// Allora signature check counters; `None` when verification is off.
+    pub allora_signatures: Option<AlloraSignatureCounts>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct FinancialPanelData {
     pub bankroll_usdc: Option<f64>,
     pub pnl_24h: Option<f64>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct PaperStatsData {
     pub wins: f64,
     pub losses: f64,
@@ -138,14 +152,14 @@ pub struct PaperStatsData {
     pub win_rate_ci_high: f64,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct PolymarketPanelData {
     pub online: bool,
     pub asset_count: Option<usize>,
     pub last_refresh: Option<Duration>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ActiveWindowRow {
     pub asset: String,
     pub market_id: String,
@@ -155,7 +169,7 @@ pub struct ActiveWindowRow {
     pub end_time_ms: u64,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum MarketMode {
     NoSignal,
     Wait,
@@ -176,7 +190,7 @@ impl MarketMode {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct MarketRow {
     pub asset: String,
     pub price: Option<f64>,
@@ -191,7 +205,35 @@ pub struct MarketRow {
     pub last_update_ms: Option<u64>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DepthLadder {
+    pub bids: Vec<OrderBookLevel>,
+    pub asks: Vec<OrderBookLevel>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct InferenceRow {
+    pub signal_timestamp_ms: u64,
+    pub value: f64,
+    /// Alignment against the window active when the inference arrived.
+    pub alignment: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MarketDetail {
+    pub asset: String,
+    pub market_id: Option<String>,
+    pub window_et: Option<String>,
+    pub window_end_ms: Option<u64>,
+    pub start_price: Option<f64>,
+    pub price: Option<f64>,
+    pub up: DepthLadder,
+    pub down: DepthLadder,
+    pub inferences: Vec<InferenceRow>,
+    pub timeline: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub struct UiSnapshot {
     pub status: StatusBarData,
     pub health: HealthPanelData,
@@ -209,6 +251,7 @@ pub struct UiSnapshot {
     pub open_orders: Option<usize>,
     pub last_order_state: Option<String>,
     pub active_windows: Vec<ActiveWindowRow>,
+    pub detail: Option<MarketDetail>,
 }
 
 #[derive(Debug)]
@@ -255,6 +298,7 @@ struct MarketSnapshot {
     min_order_size: Option<f64>,
     last_chainlink_ms: Option<u64>,
     last_allora_ms: Option<u64>,
+    inference_history: VecDeque<InferenceRow>,
 }
 
 impl MarketSnapshot {
@@ -285,6 +329,7 @@ impl MarketSnapshot {
             min_order_size: None,
             last_chainlink_ms: None,
             last_allora_ms: None,
+            inference_history: VecDeque::new(),
         }
     }
 
@@ -299,9 +344,9 @@ impl MarketSnapshot {
         self.last_chainlink_ms = Some(event_time);
     }
 
-    fn apply_allora(&mut self, update: &AlloraMarketUpdate, horizon_ms: u64) {
-        match update.timeframe.to_ascii_lowercase().as_str() {
-            "5m" => {
+    fn apply_allora(&mut self, update: &AlloraMarketUpdate, horizon_ms: u64, timeframe: &str) {
+        match update.timeframe.trim() {
+            value if value.eq_ignore_ascii_case(timeframe) => {
                 let same_value = self
                     .inference_5m
                     .map(|value| value == update.inference_value)
@@ -322,6 +367,33 @@ impl MarketSnapshot {
                 if update.request_id.is_some() {
                     self.last_request_id = update.request_id.clone();
                 }
+                let is_new = self
+                    .inference_history
+                    .back()
+                    .map(|row| {
+                        row.signal_timestamp_ms != update.signal_timestamp_ms
+                            || row.value != update.inference_value
+                    })
+                    .unwrap_or(true);
+                if is_new {
+                    let alignment = self.window.and_then(|window| {
+                        signal_alignment(
+                            update.signal_timestamp_ms,
+                            window,
+                            now_ms().unwrap_or(0),
+                            horizon_ms,
+                            horizon_ms,
+                        )
+                    });
+                    self.inference_history.push_back(InferenceRow {
+                        signal_timestamp_ms: update.signal_timestamp_ms,
+                        value: update.inference_value,
+                        alignment,
+                    });
+                    while self.inference_history.len() > INFERENCE_HISTORY_LIMIT {
+                        self.inference_history.pop_front();
+                    }
+                }
             }
             _ => {}
         }
@@ -345,37 +417,82 @@ pub fn is_tui_enabled() -> bool {
     io::stdout().is_terminal()
 }
 
-pub fn spawn_tui(
+/// Latest dashboard snapshot, shared by the TUI and the admin API.
+#[derive(Debug, Clone)]
+pub struct SnapshotFeed {
+    snapshots: watch::Receiver<Option<Arc<UiSnapshot>>>,
+    detail_asset: Arc<Mutex<Option<String>>>,
+}
+
+impl SnapshotFeed {
+    /// `None` until the snapshot loop has published its first refresh.
+    pub fn latest(&self) -> Option<Arc<UiSnapshot>> {
+        self.snapshots.borrow().clone()
+    }
+}
+
+pub fn spawn_snapshot_feed(
     config: Arc<ArcSwap<Config>>,
     risk: Arc<RiskState>,
     receiver: broadcast::Receiver<MarketUpdate>,
     redis: Option<RedisManager>,
     wallet_key: Option<String>,
+    signature_stats: Arc<AlloraSignatureStats>,
+) -> SnapshotFeed {
+    let (publisher, snapshots) = watch::channel(None);
+    let detail_asset: Arc<Mutex<Option<String>>> = Arc::new(Mutex::new(None));
+    let loop_detail_asset = detail_asset.clone();
+    tokio::spawn(async move {
+        if let Err(error) = snapshot_loop(
+            config,
+            risk,
+            receiver,
+            redis,
+            wallet_key,
+            publisher,
+            TuiConfig::default(),
+            loop_detail_asset,
+            signature_stats,
+        )
+        .await
+        {
+            tracing::error!(?error, "ui snapshot loop stopped");
+        }
+    });
+    SnapshotFeed {
+        snapshots,
+        detail_asset,
+    }
+}
+
+pub fn spawn_tui(
+    feed: SnapshotFeed,
+    control: Option<async_mpsc::UnboundedSender<ControlCommand>>,
 ) -> Result<TuiHandle> {
     let (tx, rx) = mpsc::channel();
     let ui_config = TuiConfig::default();
-    let ui_thread_config = ui_config.clone();
+    let ui_detail_asset = feed.detail_asset.clone();
 
     let _thread_handle = thread::spawn(move || {
-        if let Err(error) = ui_loop(rx, ui_thread_config) {
+        if let Err(error) = ui_loop(rx, ui_config, control, ui_detail_asset) {
             tracing::error!(?error, "tui loop stopped");
         }
     });
 
     let snapshot_sender = tx.clone();
+    let mut snapshots = feed.snapshots;
     tokio::spawn(async move {
-        if let Err(error) = snapshot_loop(
-            config,
-            risk,
-            receiver,
-            redis,
-            wallet_key,
-            snapshot_sender,
-            ui_config,
-        )
-        .await
-        {
-            tracing::error!(?error, "tui snapshot loop stopped");
+        while snapshots.changed().await.is_ok() {
+            let latest = snapshots.borrow_and_update().clone();
+            let Some(snapshot) = latest else {
+                continue;
+            };
+            if snapshot_sender
+                .send(UiCommand::Snapshot(snapshot.as_ref().clone()))
+                .is_err()
+            {
+                break;
+            }
         }
     });
 
@@ -388,8 +505,10 @@ async fn snapshot_loop(
     mut receiver: broadcast::Receiver<MarketUpdate>,
     redis: Option<RedisManager>,
     wallet_key: Option<String>,
-    sender: mpsc::Sender<UiCommand>,
+    publisher: watch::Sender<Option<Arc<UiSnapshot>>>,
     ui_config: TuiConfig,
+    detail_asset: Arc<Mutex<Option<String>>>,
+    signature_stats: Arc<AlloraSignatureStats>,
 ) -> Result<()> {
     let mut market_state: HashMap<String, MarketSnapshot> = HashMap::new();
     let mut refresh = tokio::time::interval(ui_config.refresh_interval);
@@ -430,9 +549,11 @@ async fn snapshot_loop(
                                 .entry(key.clone())
                                 .or_insert_with(|| MarketSnapshot::new(key.clone()));
                             let config_snapshot = config.load();
-                            let horizon_ms = alignment_horizon_ms(&config_snapshot, &key);
-                            entry.apply_allora(&update, horizon_ms);
+                            let horizon_ms = config_snapshot.signal_alignment_horizon_ms(&key);
+                            let timeframe = config_snapshot.polymarket.signal_timeframe(&key);
+                            entry.apply_allora(&update, horizon_ms, timeframe);
                         }
+                        MarketUpdate::Binance(_) => {}
                     },
                     Err(broadcast::error::RecvError::Closed) => break,
                     Err(broadcast::error::RecvError::Lagged(_)) => continue,
@@ -459,10 +580,15 @@ async fn snapshot_loop(
                         }
                         Err(error) => tracing::warn!(?error, "failed to read polymarket asset ids from redis"),
                     }
-                    chainlink_window_anchor = redis.get_asset_window("BTC").await?.is_some()
-                        || redis.get_asset_window("ETH").await?.is_some()
-                        || redis.get_asset_window("SOL").await?.is_some();
-                    match load_active_windows(redis).await {
+                    let assets = config.load().polymarket.market_assets();
+                    chainlink_window_anchor = false;
+                    for asset in &assets {
+                        if redis.get_asset_window(asset).await?.is_some() {
+                            chainlink_window_anchor = true;
+                            break;
+                        }
+                    }
+                    match load_active_windows(redis, &assets).await {
                         Ok(rows) => active_windows = rows,
                         Err(error) => tracing::warn!(?error, "failed to read active windows from redis"),
                     }
@@ -567,7 +693,23 @@ async fn snapshot_loop(
                         tracing::warn!(?error, "failed to refresh market snapshots");
                     }
                 }
-                let snapshot = build_snapshot(
+                let selected = detail_asset.lock().ok().and_then(|guard| guard.clone());
+                let detail = match (selected, redis.as_ref(), orderbook.as_ref()) {
+                    (Some(asset), Some(redis), Some(orderbook)) => {
+                        match market_state.get(&asset) {
+                            Some(snapshot) => match load_market_detail(redis, orderbook, snapshot).await {
+                                Ok(detail) => Some(detail),
+                                Err(error) => {
+                                    tracing::warn!(?error, asset = %asset, "failed to load market detail");
+                                    None
+                                }
+                            },
+                            None => None,
+                        }
+                    }
+                    _ => None,
+                };
+                let mut snapshot = build_snapshot(
                     &config,
                     &risk,
                     &market_state,
@@ -585,10 +727,10 @@ async fn snapshot_loop(
                     chainlink_window_anchor,
                     active_windows.clone(),
                     paper_stats.clone(),
+                    allora_signature_counts(&config, &signature_stats),
                 );
-                if sender.send(UiCommand::Snapshot(snapshot)).is_err() {
-                    break;
-                }
+                snapshot.detail = detail;
+                publisher.send_replace(Some(Arc::new(snapshot)));
             }
         }
     }
@@ -715,7 +857,7 @@ async fn estimate_vwap_price(
     let Some(implied_mid) = implied_mid else {
         return Ok(None);
     };
-    let horizon_ms = alignment_horizon_ms(config, &snapshot.asset);
+    let horizon_ms = config.signal_alignment_horizon_ms(&snapshot.asset);
     let max_align_ms = horizon_ms;
     let alignment = alignment_factor(snapshot, now_ms, horizon_ms, max_align_ms);
     let volatility_1m = snapshot
@@ -773,17 +915,17 @@ fn estimate_order_size(
     Some(notional / price)
 }
 
-fn calculate_kelly(win_prob: f64, odds: f64) -> f64 {
-    if win_prob <= 0.0 || win_prob >= 1.0 {
-        return 0.0;
-    }
-    if odds <= 1.0 {
-        return 0.0;
-    }
-    let payout = odds - 1.0;
-    let loss_prob = 1.0 - win_prob;
-    let kelly = (win_prob * payout - loss_prob) / payout;
-    kelly.clamp(0.0, 1.0)
+fn allora_signature_counts(
+    config: &Arc<ArcSwap<Config>>,
+    stats: &AlloraSignatureStats,
+) -> Option<AlloraSignatureCounts> {
+    let config = config.load();
+    let mode = config
+        .allora_consumer
+        .as_ref()
+        .map(|allora| allora.signature.mode)
+        .unwrap_or_default();
+    (mode != AlloraSignatureMode::Off).then(|| stats.snapshot())
 }
 
 fn build_snapshot(
@@ -804,6 +946,7 @@ fn build_snapshot(
     chainlink_window_anchor: bool,
     active_windows: Vec<ActiveWindowRow>,
     paper_stats: Option<PaperStatsData>,
+    allora_signatures: Option<AlloraSignatureCounts>,
 ) -> UiSnapshot {
     let config = config.load_full();
     let risk_snapshot = risk.snapshot();
@@ -837,6 +980,7 @@ fn build_snapshot(
         clock_drift_ms: risk_snapshot.clock_drift_ms,
         consecutive_losses: risk_snapshot.consecutive_losses,
         chainlink_window_anchor,
+        allora_signatures,
     };
 
     let financials = FinancialPanelData {
@@ -887,9 +1031,58 @@ fn build_snapshot(
         open_orders,
         last_order_state,
         active_windows,
+        detail: None,
     }
 }
 
+async fn load_market_detail(
+    redis: &RedisManager,
+    orderbook: &OrderBookStore,
+    snapshot: &MarketSnapshot,
+) -> Result<MarketDetail> {
+    let up = match snapshot.up_token_id.as_deref() {
+        Some(token_id) => load_ladder(orderbook, token_id).await?,
+        None => DepthLadder::default(),
+    };
+    let down = match snapshot.down_token_id.as_deref() {
+        Some(token_id) => load_ladder(orderbook, token_id).await?,
+        None => DepthLadder::default(),
+    };
+    let timeline = match snapshot.window {
+        Some(window) => {
+            redis
+                .get_window_log(&snapshot.asset, window.start_time_ms, DETAIL_TIMELINE_LIMIT)
+                .await?
+        }
+        None => Vec::new(),
+    };
+    Ok(MarketDetail {
+        asset: snapshot.asset.clone(),
+        market_id: snapshot.market_id.clone(),
+        window_et: snapshot
+            .window
+            .map(|window| format_window_et(window.start_time_ms, window.end_time_ms)),
+        window_end_ms: snapshot.window.map(|window| window.end_time_ms),
+        start_price: snapshot.start_price,
+        price: snapshot.price,
+        up,
+        down,
+        inferences: snapshot.inference_history.iter().rev().copied().collect(),
+        timeline,
+    })
+}
+
+async fn load_ladder(orderbook: &OrderBookStore, token_id: &str) -> Result<DepthLadder> {
+    Ok(DepthLadder {
+        bids: orderbook
+            .top_levels(token_id, BookSide::Bid, DETAIL_LADDER_DEPTH)
+            .await?,
+        asks: orderbook
+            .top_levels(token_id, BookSide::Ask, DETAIL_LADDER_DEPTH)
+            .await?,
+    })
+}
+
 fn build_market_row(
     snapshot: &MarketSnapshot,
     snipe_floor_bps: f64,
@@ -911,7 +1104,7 @@ fn build_market_row(
         .implied_down_vwap
         .or_else(|| implied_up_vwap.map(|v| (1.0 - v).max(0.0)));
 
-    let horizon_ms = alignment_horizon_ms(config, &snapshot.asset);
+    let horizon_ms = config.signal_alignment_horizon_ms(&snapshot.asset);
     let max_align_ms = horizon_ms;
     let alignment = alignment_factor(snapshot, now_ms, horizon_ms, max_align_ms);
     let volatility_1m = snapshot
@@ -1175,7 +1368,12 @@ fn win_rate_confidence_interval(wins: f64, losses: f64) -> (f64, f64) {
     (low * 100.0, high * 100.0)
 }
 
-fn ui_loop(receiver: mpsc::Receiver<UiCommand>, config: TuiConfig) -> UiResult<()> {
+fn ui_loop(
+    receiver: mpsc::Receiver<UiCommand>,
+    config: TuiConfig,
+    control: Option<async_mpsc::UnboundedSender<ControlCommand>>,
+    detail_asset: Arc<Mutex<Option<String>>>,
+) -> UiResult<()> {
     let mut stdout = io::stdout();
     let _guard = TerminalGuard::enter(&mut stdout)?;
     let backend = CrosstermBackend::new(stdout);
@@ -1200,6 +1398,7 @@ fn ui_loop(receiver: mpsc::Receiver<UiCommand>, config: TuiConfig) -> UiResult<(
             clock_drift_ms: 0,
             consecutive_losses: 0,
             chainlink_window_anchor: false,
+            allora_signatures: None,
         },
         financials: FinancialPanelData {
             bankroll_usdc: None,
@@ -1222,8 +1421,11 @@ fn ui_loop(receiver: mpsc::Receiver<UiCommand>, config: TuiConfig) -> UiResult<(
         open_orders: None,
         last_order_state: None,
         active_windows: Vec::new(),
+        detail: None,
     };
 
+    let mut drill_down = DrillDown::default();
+    let mut pending: Option<ControlCommand> = None;
     loop {
         while let Ok(command) = receiver.try_recv() {
             match command {
@@ -1233,14 +1435,37 @@ fn ui_loop(receiver: mpsc::Receiver<UiCommand>, config: TuiConfig) -> UiResult<(
         }
 
         terminal.draw(|frame| {
-            widgets::render_dashboard(frame, &snapshot);
+            widgets::render_dashboard(frame, &snapshot, drill_down.selected.as_deref());
+            if drill_down.open {
+                widgets::render_market_detail(
+                    frame,
+                    drill_down.selected.as_deref(),
+                    snapshot.detail.as_ref(),
+                );
+            }
+            widgets::render_control_hints(frame, control.is_some(), drill_down.open);
+            if let Some(command) = pending {
+                widgets::render_confirm(frame, command.label());
+            }
         })?;
 
         if event::poll(config.refresh_interval)? {
             if let Event::Key(key) = event::read()? {
                 if key.kind == KeyEventKind::Press {
+                    if let Some(command) = pending.take() {
+                        if matches!(key.code, KeyCode::Char('y') | KeyCode::Enter) {
+                            if let Some(control) = control.as_ref() {
+                                let _ = control.send(command);
+                            }
+                        }
+                        continue;
+                    }
+                    if drill_down.handle_key(key.code, &snapshot.markets, &detail_asset) {
+                        continue;
+                    }
                     match key.code {
                         KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                        code if control.is_some() => pending = control_command_for_key(code),
                         _ => {}
                     }
                 }
@@ -1249,6 +1474,72 @@ fn ui_loop(receiver: mpsc::Receiver<UiCommand>, config: TuiConfig) -> UiResult<(
     }
 }
 
+/// Market table selection and whether its drill-down overlay is open.
+#[derive(Debug, Default)]
+struct DrillDown {
+    selected: Option<String>,
+    open: bool,
+}
+
+impl DrillDown {
+    /// Applies a navigation key; returns false for keys the drill-down does not own.
+    fn handle_key(
+        &mut self,
+        code: KeyCode,
+        markets: &[MarketRow],
+        detail_asset: &Mutex<Option<String>>,
+    ) -> bool {
+        match code {
+            KeyCode::Esc if self.open => {
+                self.open = false;
+                set_detail_asset(detail_asset, None);
+            }
+            KeyCode::Up | KeyCode::Down if !self.open => {
+                self.selected =
+                    step_selection(markets, self.selected.as_deref(), code == KeyCode::Down);
+            }
+            KeyCode::Enter if !self.open && self.selected.is_some() => {
+                self.open = true;
+                set_detail_asset(detail_asset, self.selected.clone());
+            }
+            _ => return false,
+        }
+        true
+    }
+}
+
+/// Moves the selection by asset so it survives the table re-sorting by edge.
+fn step_selection(markets: &[MarketRow], current: Option<&str>, down: bool) -> Option<String> {
+    if markets.is_empty() {
+        return current.map(str::to_string);
+    }
+    let index = current.and_then(|asset| markets.iter().position(|row| row.asset == asset));
+    let next = match (index, down) {
+        (None, _) => 0,
+        (Some(index), true) => (index + 1).min(markets.len() - 1),
+        (Some(index), false) => index.saturating_sub(1),
+    };
+    Some(markets[next].asset.clone())
+}
+
+fn set_detail_asset(detail_asset: &Mutex<Option<String>>, asset: Option<String>) {
+    if let Ok(mut guard) = detail_asset.lock() {
+        *guard = asset;
+    }
+}
+
+fn control_command_for_key(code: KeyCode) -> Option<ControlCommand> {
+    match code {
+        KeyCode::Char('h') => Some(ControlCommand::Halt),
+        KeyCode::Char('r') => Some(ControlCommand::Resume),
+        KeyCode::Char('n') => Some(ControlCommand::ToggleNoMoney),
+        KeyCode::Char('t') => Some(ControlCommand::ToggleTrading),
+        KeyCode::Char('c') => Some(ControlCommand::CancelAll),
+        KeyCode::Char('f') => Some(ControlCommand::Flatten),
+        _ => None,
+    }
+}
+
 struct TerminalGuard;
 
 impl TerminalGuard {
@@ -1306,6 +1597,16 @@ fn alignment_factor(
 ) -> Option<f64> {
     let signal_ts = snapshot.signal_timestamp_ms?;
     let window = snapshot.window?;
+    signal_alignment(signal_ts, window, now_ms, horizon_ms, max_alignment_ms)
+}
+
+fn signal_alignment(
+    signal_ts: u64,
+    window: MarketWindow,
+    now_ms: u64,
+    horizon_ms: u64,
+    max_alignment_ms: u64,
+) -> Option<f64> {
     if signal_ts > window.end_time_ms {
         return None;
     }
@@ -1330,27 +1631,6 @@ fn alignment_factor(
     Some(alignment.clamp(0.0, 1.0))
 }
 
-fn alignment_horizon_ms(config: &Config, asset: &str) -> u64 {
-    let base_ms = config
-        .execution
-        .signal_alignment_max_secs
-        .saturating_mul(1000);
-    let sol_ms = config
-        .execution
-        .signal_alignment_max_secs_sol
-        .saturating_mul(1000);
-    let selected = if asset.eq_ignore_ascii_case("SOL") && sol_ms > 0 {
-        sol_ms
-    } else {
-        base_ms
-    };
-    if selected > 0 {
-        selected
-    } else {
-        SIGNAL_HORIZON_MS
-    }
-}
-
 #[derive(Debug, Clone, Copy)]
 struct SignalContext {
     direction: i8,
@@ -1551,9 +1831,12 @@ fn is_polymarket_online(last_refresh: Option<Instant>) -> bool {
         .unwrap_or(false)
 }
 
-async fn load_active_windows(redis: &RedisManager) -> Result<Vec<ActiveWindowRow>> {
+async fn load_active_windows(
+    redis: &RedisManager,
+    assets: &[String],
+) -> Result<Vec<ActiveWindowRow>> {
     let mut rows = Vec::new();
-    for asset in ["BTC", "ETH", "SOL"] {
+    for asset in assets {
         match redis.get_asset_window(asset).await? {
             Some(window) => {
                 let now = now_ms().unwrap_or(0);
@@ -1602,3 +1885,74 @@ fn format_window_et(start_ms: u64, end_ms: u64) -> String {
         _ => "--".to_string(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn market(asset: &str) -> MarketRow {
+        MarketRow {
+            asset: asset.to_string(),
+            price: None,
+            implied_up: None,
+            min_order_size: None,
+            start_price: None,
+            inference_5m: None,
+            edge_bps: None,
+            side: None,
+            fee_bps: None,
+            mode: MarketMode::NoSignal,
+            last_update_ms: None,
+        }
+    }
+
+    #[test]
+    fn drill_down_opens_on_selection_and_closes_on_escape() {
+        let markets = vec![market("BTC"), market("ETH")];
+        let detail_asset = Mutex::new(None);
+        let mut drill_down = DrillDown::default();
+
+        assert!(!drill_down.handle_key(KeyCode::Enter, &markets, &detail_asset));
+        assert!(!drill_down.open);
+
+        assert!(drill_down.handle_key(KeyCode::Down, &markets, &detail_asset));
+        assert!(drill_down.handle_key(KeyCode::Down, &markets, &detail_asset));
+        assert!(drill_down.handle_key(KeyCode::Down, &markets, &detail_asset));
+        assert_eq!(drill_down.selected.as_deref(), Some("ETH"));
+
+        assert!(drill_down.handle_key(KeyCode::Enter, &markets, &detail_asset));
+        assert!(drill_down.open);
+        assert_eq!(detail_asset.lock().unwrap().as_deref(), Some("ETH"));
+
+        // Navigation is frozen while the overlay is open.
+        assert!(!drill_down.handle_key(KeyCode::Up, &markets, &detail_asset));
+        assert_eq!(drill_down.selected.as_deref(), Some("ETH"));
+
+        assert!(drill_down.handle_key(KeyCode::Esc, &markets, &detail_asset));
+        assert!(!drill_down.open);
+        assert_eq!(*detail_asset.lock().unwrap(), None);
+        // A second Esc belongs to the main loop, which quits.
+        assert!(!drill_down.handle_key(KeyCode::Esc, &markets, &detail_asset));
+
+        assert!(drill_down.handle_key(KeyCode::Up, &markets, &detail_asset));
+        assert_eq!(drill_down.selected.as_deref(), Some("BTC"));
+    }
+
+    #[test]
+    fn maps_control_keys_to_commands() {
+        assert_eq!(
+            control_command_for_key(KeyCode::Char('h')),
+            Some(ControlCommand::Halt)
+        );
+        assert_eq!(
+            control_command_for_key(KeyCode::Char('r')),
+            Some(ControlCommand::Resume)
+        );
+        assert_eq!(
+            control_command_for_key(KeyCode::Char('f')),
+            Some(ControlCommand::Flatten)
+        );
+        assert_eq!(control_command_for_key(KeyCode::Char('x')), None);
+        assert_eq!(control_command_for_key(KeyCode::Enter), None);
+    }
+}
@@ -7,21 +7,22 @@
  *
  * @notes
  * - Styling follows the "cyberpunk terminal" spec with double borders.
+ * - The market drill-down is drawn over the dashboard body, leaving the status bar visible.
  */
 use ratatui::layout::{Alignment, Constraint, Direction, Layout, Rect};
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span, Text};
-use ratatui::widgets::{Block, BorderType, Borders, Cell, Paragraph, Row, Table};
+use ratatui::widgets::{Block, BorderType, Borders, Cell, Clear, Paragraph, Row, Table};
 use ratatui::Frame;
 use serde_json::Value;
 
 use super::{
-    ActiveWindowRow, FinancialPanelData, HealthPanelData, MarketMode, MarketRow, PaperStatsData,
-    PolymarketPanelData, StatusBarData, UiSnapshot,
+    ActiveWindowRow, DepthLadder, FinancialPanelData, HealthPanelData, InferenceRow, MarketDetail,
+    MarketMode, MarketRow, PaperStatsData, PolymarketPanelData, StatusBarData, UiSnapshot,
 };
 use crate::engine::risk::HaltReason;
 
-pub fn render_dashboard(frame: &mut Frame, snapshot: &UiSnapshot) {
+pub fn render_dashboard(frame: &mut Frame, snapshot: &UiSnapshot, selected: Option<&str>) {
     let layout = Layout::default()
         .direction(Direction::Vertical)
         .constraints([Constraint::Length(2), Constraint::Min(0)])
@@ -43,7 +44,7 @@ pub fn render_dashboard(frame: &mut Frame, snapshot: &UiSnapshot) {
         ])
         .split(body[0]);
     render_window_bar(frame, left[0], &snapshot.active_windows);
-    render_markets(frame, left[1], &snapshot.markets);
+    render_markets(frame, left[1], &snapshot.markets, selected);
     render_activity_log(frame, left[2], &snapshot.activity_log);
 
     let right = if snapshot.no_money_mode {
@@ -89,6 +90,282 @@ pub fn render_dashboard(frame: &mut Frame, snapshot: &UiSnapshot) {
     }
 }
 
+/// One-line key legend on the bottom border row.
+pub fn render_control_hints(frame: &mut Frame, controls_enabled: bool, detail_open: bool) {
+    let size = frame.size();
+    if size.height == 0 {
+        return;
+    }
+    let area = Rect::new(size.x, size.y + size.height - 1, size.width, 1);
+    let navigation = if detail_open {
+        "[esc] back"
+    } else {
+        "[\u{2191}\u{2193}] select [enter] detail"
+    };
+    let text = if controls_enabled {
+        format!(" {navigation} [h]alt [r]esume [n]o-money [t]rading [c]ancel-all [f]latten [q]uit ")
+    } else {
+        format!(" {navigation} [q]uit ")
+    };
+    let hints = Paragraph::new(Line::from(Span::styled(
+        text,
+        Style::default().fg(Color::Black).bg(Color::DarkGray),
+    )))
+    .alignment(Alignment::Right);
+    frame.render_widget(hints, area);
+}
+
+/// Centered confirm dialog for an operator command.
+pub fn render_confirm(frame: &mut Frame, action: &str) {
+    let size = frame.size();
+    let width = 48.min(size.width);
+    let height = 5.min(size.height);
+    let area = Rect::new(
+        size.x + (size.width - width) / 2,
+        size.y + (size.height - height) / 2,
+        width,
+        height,
+    );
+    let block = Block::default()
+        .title(" Confirm ")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Double)
+        .style(Style::default().fg(Color::Yellow));
+    let text = Text::from(vec![
+        Line::from(Span::styled(
+            format!("{action}?"),
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from("[y/Enter] confirm   any other key cancels"),
+    ]);
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Paragraph::new(text)
+            .block(block)
+            .alignment(Alignment::Center),
+        area,
+    );
+}
+
+/// Drill-down pane for one market: depth ladders, signal history and window timeline.
+pub fn render_market_detail(frame: &mut Frame, asset: Option<&str>, detail: Option<&MarketDetail>) {
+    let size = frame.size();
+    if size.height < 4 {
+        return;
+    }
+    let area = Rect::new(size.x, size.y + 2, size.width, size.height - 3);
+    frame.render_widget(Clear, area);
+
+    let title = format!(" Market Detail: {} ", asset.unwrap_or("--"));
+    let block = Block::default()
+        .title(Span::styled(
+            title,
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Double)
+        .style(Style::default().fg(Color::Cyan));
+    let Some(detail) = detail else {
+        let paragraph = Paragraph::new(Line::from("loading market detail..."))
+            .block(block)
+            .alignment(Alignment::Left);
+        frame.render_widget(paragraph, area);
+        return;
+    };
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(2), Constraint::Min(0)])
+        .split(inner);
+    render_detail_header(frame, layout[0], detail);
+
+    let body = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(45), Constraint::Percentage(55)])
+        .split(layout[1]);
+    let ladders = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(body[0]);
+    render_ladder(frame, ladders[0], " UP Book ", &detail.up);
+    render_ladder(frame, ladders[1], " DOWN Book ", &detail.down);
+
+    let right = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+        .split(body[1]);
+    render_inference_history(frame, right[0], &detail.inferences);
+    render_window_timeline(frame, right[1], &detail.timeline);
+}
+
+fn render_detail_header(frame: &mut Frame, area: Rect, detail: &MarketDetail) {
+    let delta_bps = match (detail.start_price, detail.price) {
+        (Some(start), Some(price)) if start > 0.0 => Some((price - start) / start * 10_000.0),
+        _ => None,
+    };
+    let delta_style = match delta_bps {
+        Some(value) if value > 0.0 => Style::default().fg(Color::Green),
+        Some(value) if value < 0.0 => Style::default().fg(Color::Red),
+        _ => Style::default().fg(Color::DarkGray),
+    };
+    let remaining = detail
+        .window_end_ms
+        .map(|end| fmt_secs(end.saturating_sub(now_epoch_ms()) / 1000))
+        .unwrap_or_else(|| "--".to_string());
+    let lines = vec![
+        Line::from(format!(
+            "Market: {} | Window: {} | Left: {remaining}",
+            detail.market_id.as_deref().unwrap_or("--"),
+            detail.window_et.as_deref().unwrap_or("--"),
+        )),
+        Line::from(vec![
+            Span::raw(format!(
+                "Start: {} | Chainlink: {} | ",
+                format_optional_f64(detail.start_price, 4),
+                format_optional_f64(detail.price, 4),
+            )),
+            Span::styled(
+                format!("Delta: {} bps", format_optional_f64(delta_bps, 1)),
+                delta_style,
+            ),
+        ]),
+    ];
+    frame.render_widget(Paragraph::new(lines), area);
+}
+
+fn render_ladder(frame: &mut Frame, area: Rect, title: &str, ladder: &DepthLadder) {
+    let block = Block::default()
+        .title(title.to_string())
+        .borders(Borders::ALL)
+        .border_type(BorderType::Double)
+        .style(Style::default().fg(Color::Cyan));
+    if ladder.bids.is_empty() && ladder.asks.is_empty() {
+        let paragraph = Paragraph::new(Line::from("no book levels"))
+            .block(block)
+            .alignment(Alignment::Left);
+        frame.render_widget(paragraph, area);
+        return;
+    }
+    let header = Row::new(vec![
+        Cell::from("Bid Size"),
+        Cell::from("Bid"),
+        Cell::from("Ask"),
+        Cell::from("Ask Size"),
+    ])
+    .style(
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD),
+    );
+    let depth = ladder.bids.len().max(ladder.asks.len());
+    let rows = (0..depth).map(|index| {
+        let bid = ladder.bids.get(index);
+        let ask = ladder.asks.get(index);
+        Row::new(vec![
+            Cell::from(format_optional_f64(bid.map(|level| level.size), 2)),
+            Cell::from(Span::styled(
+                bid.map(|level| level.price.clone())
+                    .unwrap_or_else(|| "--".to_string()),
+                Style::default().fg(Color::Green),
+            )),
+            Cell::from(Span::styled(
+                ask.map(|level| level.price.clone())
+                    .unwrap_or_else(|| "--".to_string()),
+                Style::default().fg(Color::Red),
+            )),
+            Cell::from(format_optional_f64(ask.map(|level| level.size), 2)),
+        ])
+    });
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(12),
+            Constraint::Length(8),
+            Constraint::Length(8),
+            Constraint::Length(12),
+        ],
+    )
+    .header(header)
+    .block(block)
+    .column_spacing(1);
+    frame.render_widget(table, area);
+}
+
+fn render_inference_history(frame: &mut Frame, area: Rect, inferences: &[InferenceRow]) {
+    let block = Block::default()
+        .title(" Allora Signal History ")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Double)
+        .style(Style::default().fg(Color::Cyan));
+    if inferences.is_empty() {
+        let paragraph = Paragraph::new(Line::from("no inferences received"))
+            .block(block)
+            .alignment(Alignment::Left);
+        frame.render_widget(paragraph, area);
+        return;
+    }
+    let header = Row::new(vec![
+        Cell::from("Signal Age"),
+        Cell::from("Inference"),
+        Cell::from("Alignment"),
+    ])
+    .style(
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD),
+    );
+    let rows = inferences.iter().map(|row| {
+        Row::new(vec![
+            Cell::from(format_age(Some(row.signal_timestamp_ms))),
+            Cell::from(format!("{:.4}", row.value)),
+            Cell::from(format_optional_f64(row.alignment, 2)),
+        ])
+    });
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(12),
+            Constraint::Length(14),
+            Constraint::Length(10),
+        ],
+    )
+    .header(header)
+    .block(block)
+    .column_spacing(1);
+    frame.render_widget(table, area);
+}
+
+fn render_window_timeline(frame: &mut Frame, area: Rect, entries: &[String]) {
+    let lines: Vec<Line> = if entries.is_empty() {
+        vec![Line::from("no blockers or intents for this window")]
+    } else {
+        entries
+            .iter()
+            .map(|entry| {
+                let style = if entry.contains("[INTENT]") {
+                    Style::default().fg(Color::Green)
+                } else {
+                    Style::default().fg(Color::Yellow)
+                };
+                Line::from(Span::styled(entry.as_str(), style))
+            })
+            .collect()
+    };
+    let block = Block::default()
+        .title(" Window Timeline ")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Double)
+        .style(Style::default().fg(Color::Cyan));
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .alignment(Alignment::Left);
+    frame.render_widget(paragraph, area);
+}
+
 fn render_paper_stats(frame: &mut Frame, area: Rect, stats: Option<&PaperStatsData>) {
     let block = Block::default()
         .title(" Paper Stats ")
@@ -289,16 +566,34 @@ fn render_system_panel(
 
     if health.chainlink_window_anchor {
         lines.push(Line::from(Span::styled(
-            "15m anchor: locked",
+            "Window anchor: locked",
             Style::default().fg(Color::Green),
         )));
     } else {
         lines.push(Line::from(Span::styled(
-            "15m anchor: pending",
+            "Window anchor: pending",
             Style::default().fg(Color::Yellow),
         )));
     }
 
+    match health.allora_signatures {
+        Some(counts) => {
+            let style = if counts.unsigned + counts.invalid > 0 {
+                Style::default().fg(Color::Yellow)
+            } else {
+                Style::default().fg(Color::Green)
+            };
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "Allora sigs: ok {} | unsigned {} | invalid {} | rejected {}",
+                    counts.verified, counts.unsigned, counts.invalid, counts.rejected
+                ),
+                style,
+            )));
+        }
+        None => lines.push(Line::from("Allora sigs: off")),
+    }
+
     let paragraph = Paragraph::new(lines)
         .block(block)
         .alignment(Alignment::Left);
@@ -353,7 +648,7 @@ fn render_window_bar(frame: &mut Frame, area: Rect, windows: &[ActiveWindowRow])
     frame.render_widget(paragraph, area);
 }
 
-fn render_markets(frame: &mut Frame, area: Rect, markets: &[MarketRow]) {
+fn render_markets(frame: &mut Frame, area: Rect, markets: &[MarketRow], selected: Option<&str>) {
     let block = Block::default()
         .title(Span::styled(
             " Market Scanner ",
@@ -377,7 +672,7 @@ fn render_markets(frame: &mut Frame, area: Rect, markets: &[MarketRow]) {
         Cell::from("Price"),
         Cell::from("Price (Up)"),
         Cell::from("Start Price"),
-        Cell::from("Sig 5m"),
+        Cell::from("Signal"),
         Cell::from("EV"),
         Cell::from("Side"),
         Cell::from("FeeBps"),
@@ -392,6 +687,11 @@ fn render_markets(frame: &mut Frame, area: Rect, markets: &[MarketRow]) {
 
     let rows = markets.iter().map(|row| {
         let mode_style = mode_style(&row.mode);
+        let row_style = if selected == Some(row.asset.as_str()) {
+            Style::default().add_modifier(Modifier::REVERSED)
+        } else {
+            Style::default()
+        };
         Row::new(vec![
             Cell::from(row.asset.clone()),
             Cell::from(format_optional_f64(row.price, 4)),
@@ -404,6 +704,7 @@ fn render_markets(frame: &mut Frame, area: Rect, markets: &[MarketRow]) {
             Cell::from(Span::styled(row.mode.label(), mode_style)),
             Cell::from(format_age(row.last_update_ms)),
         ])
+        .style(row_style)
     });
 
     let table = Table::new(
@@ -763,6 +1064,7 @@ fn halt_reason_label(reason: HaltReason) -> &'static str {
         HaltReason::ClockDrift => "Clock",
         HaltReason::ConsecutiveLosses => "Losses",
         HaltReason::Manual => "Manual",
+        HaltReason::SourceDivergence => "Divergence",
         HaltReason::None => "None",
     }
 }
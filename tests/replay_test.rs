@@ -0,0 +1,113 @@
+/**
+ * @description
+ * End-to-end replay test: a recorded BTC window runs through the Backtester, which feeds
+ * every update into TradingEngine::handle_update on a SimulatedClock.
+ *
+ * @dependencies
+ * - tokio: async runtime for the engine and Redis
+ * - redis: scratch database shared with the engine, as in production
+ *
+ * @notes
+ * - Needs a disposable Redis at BANKAI_TEST_REDIS_URL (default redis://127.0.0.1:6379/15);
+ *   run with `cargo test --test replay_test -- --ignored`. The database is flushed.
+ */
+use bankai_terminal::config::load_config_with_overrides;
+use bankai_terminal::engine::backtest::{BacktestSettings, Backtester, ReplayRecord};
+use bankai_terminal::storage::redis::RedisManager;
+use serde_json::{json, Value};
+use std::path::Path;
+
+const WINDOW_START_MS: u64 = 1_700_000_100_000;
+const WINDOW_END_MS: u64 = WINDOW_START_MS + 900_000;
+
+fn fixture_ndjson() -> String {
+    let start = WINDOW_START_MS;
+    let records = [
+        json!({"ts_ms": start - 60_000, "source": "market", "data": {
+            "market_id": "replay-btc", "asset": "BTC",
+            "start_time_ms": start, "end_time_ms": WINDOW_END_MS,
+            "up_token_id": "replay-up", "down_token_id": "replay-down",
+            "fee_rate_bps": 0.0, "min_tick_size": 0.01, "min_order_size": 1.0
+        }}),
+        json!({"ts_ms": start, "source": "chainlink", "data": {
+            "asset": "BTC", "last_price": 100_000.0, "volatility_1m": 0.001,
+            "dfo": null, "event_time_ms": start
+        }}),
+        json!({"ts_ms": start + 559_000, "source": "book_snapshot", "data": {
+            "asset_id": "replay-up",
+            "bids": [{"price": "0.40", "size": "500"}],
+            "asks": [{"price": "0.42", "size": "500"}]
+        }}),
+        json!({"ts_ms": start + 559_000, "source": "book_snapshot", "data": {
+            "asset_id": "replay-down",
+            "bids": [{"price": "0.57", "size": "500"}],
+            "asks": [{"price": "0.59", "size": "500"}]
+        }}),
+        json!({"ts_ms": start + 560_000, "source": "allora", "data": {
+            "topic_id": 14, "inference_value": 100_600.0, "inference_raw": null,
+            "token_decimals": null, "signature": null, "request_id": null,
+            "confidence_intervals": [], "signal_timestamp_ms": start + 555_000,
+            "received_at_ms": start + 560_000, "asset": "BTC", "timeframe": "5m"
+        }}),
+        json!({"ts_ms": start + 561_000, "source": "chainlink", "data": {
+            "asset": "BTC", "last_price": 100_100.0, "volatility_1m": 0.001,
+            "dfo": null, "event_time_ms": start + 561_000
+        }}),
+        json!({"ts_ms": WINDOW_END_MS, "source": "chainlink", "data": {
+            "asset": "BTC", "last_price": 100_300.0, "volatility_1m": 0.001,
+            "dfo": null, "event_time_ms": WINDOW_END_MS
+        }}),
+    ];
+    records
+        .iter()
+        .map(Value::to_string)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[tokio::test]
+#[ignore = "needs a scratch Redis at BANKAI_TEST_REDIS_URL"]
+async fn replayed_window_fills_and_settles_with_pnl() {
+    let redis_url = std::env::var("BANKAI_TEST_REDIS_URL")
+        .unwrap_or_else(|_| "redis://127.0.0.1:6379/15".to_string());
+    let redis = RedisManager::new(&redis_url).await.expect("redis");
+    let _: () = redis::cmd("FLUSHDB")
+        .query_async(&mut redis.connection())
+        .await
+        .expect("flush scratch db");
+
+    let records = fixture_ndjson()
+        .lines()
+        .map(|line| serde_json::from_str::<ReplayRecord>(line).expect("replay record"))
+        .collect::<Vec<_>>();
+    let config = load_config_with_overrides(
+        Path::new("config/config.json"),
+        None,
+        &[("execution.no_money_mode".to_string(), json!(true))],
+    )
+    .expect("config");
+    let settings = BacktestSettings {
+        start_bankroll_usdc: 100.0,
+        slippage_bps: 0.0,
+    };
+
+    let report = Backtester::new(config, redis, settings)
+        .run(records)
+        .await
+        .expect("replay");
+
+    assert_eq!(report.records, 7);
+    let window = &report.windows[0];
+    assert_eq!(window.outcome.as_deref(), Some("UP"));
+    assert_eq!(report.fills, 1, "blockers: {:?}", report.blockers);
+    let fill = &window.fills[0];
+    assert_eq!(fill.outcome, "UP");
+    assert_eq!(fill.mode, "snipe");
+    assert!((fill.price - 0.42).abs() < 1e-9);
+    assert!(fill.size > 0.0);
+    let expected_pnl = (1.0 - fill.price) * fill.size - fill.fee;
+    assert!((report.total_pnl - expected_pnl).abs() < 1e-9);
+    assert!(report.total_pnl > 0.0);
+    assert_eq!(report.wins, 1);
+    assert!((report.end_bankroll_usdc - (100.0 + report.total_pnl)).abs() < 1e-9);
+}